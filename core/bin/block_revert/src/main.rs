@@ -0,0 +1,57 @@
+//! Tool to revert the blocks that were not executed yet.
+//!
+//! It removes all the data related to the reverted blocks from the database and, if some of
+//! these blocks were committed on Ethereum, schedules the `revertBlocks` contract call which
+//! will be sent by `eth_sender`.
+//!
+//! The server must be stopped while the tool is running, and all the components that keep
+//! the chain state in memory must be restarted afterwards.
+
+use structopt::StructOpt;
+use zksync_storage::ConnectionPool;
+use zksync_types::BlockNumber;
+
+#[derive(StructOpt)]
+#[structopt(
+    name = "Block revert",
+    author = "Matter Labs",
+    rename_all = "snake_case"
+)]
+struct Opt {
+    /// Number of the last block to keep, all the blocks after it will be reverted
+    #[structopt(long)]
+    last_block: BlockNumber,
+
+    /// Only report the data that would be removed, without changing the database
+    #[structopt(long)]
+    dry_run: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    let opt = Opt::from_args();
+
+    let connection_pool = ConnectionPool::new(Some(1));
+    let mut storage = connection_pool.access_storage().await?;
+    let mut transaction = storage.start_transaction().await?;
+
+    let summary = transaction
+        .chain()
+        .block_schema()
+        .revert_blocks(opt.last_block)
+        .await?;
+    println!("{}", serde_json::to_string_pretty(&summary)?);
+
+    if opt.dry_run {
+        log::info!("Dry run mode, changes are not applied");
+    } else {
+        transaction.commit().await?;
+        log::info!(
+            "Blocks after #{} are reverted. Restart the server to apply the changes",
+            opt.last_block
+        );
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 // External deps
+use futures::{stream, StreamExt, TryStreamExt};
 use web3::{
     contract::Contract,
     types::{H160, H256},
@@ -8,15 +9,17 @@ use web3::{
 use zksync_contracts::{governance_contract, zksync_contract};
 use zksync_crypto::Fr;
 
-use zksync_types::{AccountMap, AccountUpdate};
+use zksync_types::{block::Block, AccountMap, AccountUpdate};
 // Local deps
 use crate::storage_interactor::StorageInteractor;
 use crate::{
     contract_functions::{get_genesis_account, get_total_verified_blocks},
-    eth_tx_helpers::get_ethereum_transaction,
+    eth_tx_helpers::{get_ethereum_transaction, with_retries},
     events_state::EventsState,
     rollup_ops::RollupOpsBlock,
+    snapshot::StateSnapshot,
     tree_state::TreeState,
+    PARALLEL_REQUESTS,
 };
 use serde::export::PhantomData;
 
@@ -60,6 +63,8 @@ pub struct DataRestoreDriver<T: Transport, I> {
     pub eth_blocks_step: u64,
     /// The distance to the last ethereum block
     pub end_eth_blocks_offset: u64,
+    /// Maximum amount of concurrent requests to the Ethereum node
+    pub parallel_requests: usize,
     /// Available block chunk sizes
     pub available_block_chunk_sizes: Vec<usize>,
     /// Finite mode flag. In finite mode, driver will only work until
@@ -69,6 +74,9 @@ pub struct DataRestoreDriver<T: Transport, I> {
     /// Expected root hash to be observed after restoring process. Only
     /// available in finite mode, and intended for tests.
     pub final_hash: Option<Fr>,
+    /// Number of the block the state was restored from a snapshot at. Root hash of the
+    /// block following it is compared with the one committed on Ethereum.
+    pub snapshot_block: Option<u32>,
     phantom_data: PhantomData<I>,
 }
 
@@ -128,9 +136,11 @@ where
             tree_state,
             eth_blocks_step,
             end_eth_blocks_offset,
+            parallel_requests: PARALLEL_REQUESTS,
             available_block_chunk_sizes,
             finite_mode,
             final_hash,
+            snapshot_block: None,
             phantom_data: Default::default(),
         }
     }
@@ -197,6 +207,41 @@ where
         self.tree_state = tree_state;
     }
 
+    /// Sets the state from the snapshot.
+    /// Tree will be created from the snapshot accounts, and the events will be
+    /// fetched starting from the Ethereum block specified in the snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - State snapshot
+    ///
+    pub async fn set_snapshot_state(&mut self, interactor: &mut I, snapshot: StateSnapshot) {
+        snapshot
+            .verify()
+            .expect("Snapshot state doesn't match its root hash");
+
+        self.events_state.last_watched_eth_block_number = snapshot.last_watched_eth_block_number;
+
+        interactor.save_snapshot_state(&snapshot).await;
+
+        let tree_state = TreeState::load(
+            snapshot.block_number,
+            snapshot.account_map(),
+            snapshot.unprocessed_priority_op,
+            snapshot.fee_account,
+            self.available_block_chunk_sizes.clone(),
+        );
+
+        log::info!(
+            "Loaded snapshot of the block {}, root hash: {:?}",
+            snapshot.block_number,
+            tree_state.root_hash()
+        );
+
+        self.tree_state = tree_state;
+        self.snapshot_block = Some(snapshot.block_number);
+    }
+
     /// Stops states from storage
     pub async fn load_state_from_storage(&mut self, interactor: &mut I) -> bool {
         log::info!("Loading state from storage");
@@ -315,6 +360,8 @@ where
                 &self.governance_contract,
                 self.eth_blocks_step,
                 self.end_eth_blocks_offset,
+                self.parallel_requests,
+                self.tree_state.state.block_number,
             )
             .await
             .expect("Updating events state: cant update events state");
@@ -347,6 +394,9 @@ where
                 .tree_state
                 .update_tree_states_from_ops_block(&op_block)
                 .expect("Updating tree state: cant update tree from operations");
+            if self.snapshot_block == Some(block.block_number - 1) {
+                self.check_snapshot_continuation(&block).await;
+            }
             blocks.push(block);
             updates.push(acc_updates);
             count += 1;
@@ -360,6 +410,53 @@ where
         log::debug!("Updated state");
     }
 
+    /// Compares the root hash of the first block restored after the snapshot with the
+    /// root hash of the verified block on Ethereum. Panics if they don't match, since it
+    /// means that the snapshot state is not the actual state of the network.
+    ///
+    /// The snapshot file itself is not authenticated, this check is the only thing that
+    /// ties the snapshot state to the network state.
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - The first block restored after the snapshot
+    ///
+    async fn check_snapshot_continuation(&mut self, block: &Block) {
+        // Committed blocks can still be reverted, so only the root hash of the verified
+        // block is compared. Verification transaction doesn't contain the root hash, it's
+        // taken from the commit transaction of the verified block.
+        assert!(
+            self.events_state
+                .verified_events
+                .iter()
+                .any(|event| event.block_num == block.block_number),
+            "Cant find the verify event for the block {} after snapshot",
+            block.block_number
+        );
+        let event = self
+            .events_state
+            .get_only_verified_committed_events()
+            .into_iter()
+            .find(|event| event.block_num == block.block_number)
+            .expect("Cant find the commit event for the verified block after snapshot");
+        let verified_root_hash = RollupOpsBlock::get_committed_root_hash(&self.web3, &event)
+            .await
+            .expect("Cant get the root hash of the verified block after snapshot");
+
+        assert_eq!(
+            verified_root_hash,
+            block.get_eth_encoded_root(),
+            "Root hash of the block {} doesn't match the verified one, snapshot state is incorrect",
+            block.block_number
+        );
+        log::info!(
+            "Snapshot state is confirmed by the verified block {}",
+            block.block_number
+        );
+
+        self.snapshot_block = None;
+    }
+
     /// Gets new operations blocks from events, updates rollup operations stored state.
     /// Returns new rollup operations blocks
     async fn update_operations_state(&mut self, interactor: &mut I) -> Vec<RollupOpsBlock> {
@@ -372,21 +469,16 @@ where
         new_blocks
     }
 
-    /// Returns verified comitted operations blocks from verified op blocks events
+    /// Returns verified comitted operations blocks from verified op blocks events.
+    /// Commit transactions are fetched concurrently, the order of the blocks is preserved.
     pub async fn get_new_operation_blocks_from_events(&mut self) -> Vec<RollupOpsBlock> {
-        let mut blocks = Vec::new();
-
-        for event in self
-            .events_state
-            .get_only_verified_committed_events()
-            .iter()
-        {
-            let block = RollupOpsBlock::get_rollup_ops_block(&self.web3, &event)
-                .await
-                .expect("Cant get new operation blocks from events");
-            blocks.push(block);
-        }
-
-        blocks
+        let web3 = &self.web3;
+        let events = self.events_state.get_only_verified_committed_events();
+        stream::iter(events.iter())
+            .map(|event| with_retries(move || RollupOpsBlock::get_rollup_ops_block(web3, event)))
+            .buffered(self.parallel_requests.max(1))
+            .try_collect()
+            .await
+            .expect("Cant get new operation blocks from events")
     }
 }
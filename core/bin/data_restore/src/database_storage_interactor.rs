@@ -3,6 +3,7 @@ use std::str::FromStr;
 // Workspace deps
 use zksync_storage::{data_restore::records::NewBlockEvent, StorageProcessor};
 use zksync_types::{
+    tokens::NewTokenEvent,
     Action, Operation, Token, TokenGenesisListItem, TokenId,
     {block::Block, AccountUpdate, AccountUpdates, ZkSyncOp},
 };
@@ -12,23 +13,15 @@ use crate::storage_interactor::StoredTreeState;
 use crate::{
     data_restore_driver::StorageUpdateState,
     events::BlockEvent,
-    events_state::{EventsState, NewTokenEvent},
+    events_state::EventsState,
     rollup_ops::RollupOpsBlock,
+    snapshot::StateSnapshot,
     storage_interactor::{
         block_event_into_stored_block_event, stored_block_event_into_block_event,
         stored_ops_block_into_ops_block, StorageInteractor,
     },
 };
 
-impl From<&NewTokenEvent> for zksync_storage::data_restore::records::NewTokenEvent {
-    fn from(event: &NewTokenEvent) -> Self {
-        Self {
-            address: event.address,
-            id: event.id,
-        }
-    }
-}
-
 pub struct DatabaseStorageInteractor<'a> {
     storage: StorageProcessor<'a>,
 }
@@ -143,10 +136,9 @@ impl StorageInteractor for DatabaseStorageInteractor<'_> {
 
         let block_number = last_watched_eth_block_number.to_string();
 
-        let tokens: Vec<_> = tokens.iter().map(From::from).collect();
         self.storage
             .data_restore_schema()
-            .save_events_state(new_events.as_slice(), &tokens, &block_number)
+            .save_events_state(new_events.as_slice(), tokens, &block_number)
             .await
             .expect("Cant update events state");
     }
@@ -170,6 +162,74 @@ impl StorageInteractor for DatabaseStorageInteractor<'_> {
             .expect("Cant update genesis state");
     }
 
+    async fn save_snapshot_state(&mut self, snapshot: &StateSnapshot) {
+        let (last_committed, accounts) = self
+            .storage
+            .chain()
+            .state_schema()
+            .load_committed_state(None)
+            .await
+            .expect("Cant load comitted state");
+        assert!(
+            last_committed == 0 && accounts.is_empty(),
+            "db should be empty"
+        );
+
+        let mut transaction = self
+            .storage
+            .start_transaction()
+            .await
+            .expect("Failed initializing a DB transaction");
+
+        for token in &snapshot.tokens {
+            transaction
+                .tokens_schema()
+                .store_token(token.clone())
+                .await
+                .expect("failed to store token");
+        }
+
+        let block = snapshot.block();
+        let commit_op = Operation {
+            action: Action::Commit,
+            block: block.clone(),
+            id: None,
+        };
+        let verify_op = Operation {
+            action: Action::Verify {
+                proof: Box::new(Default::default()),
+            },
+            block,
+            id: None,
+        };
+
+        transaction
+            .chain()
+            .state_schema()
+            .commit_state_update(snapshot.block_number, &snapshot.account_updates(), 0)
+            .await
+            .expect("Cant commit snapshot state");
+        transaction
+            .data_restore_schema()
+            .save_block_operations(commit_op, verify_op)
+            .await
+            .expect("Cant save snapshot block");
+        transaction
+            .data_restore_schema()
+            .save_events_state(
+                &[],
+                &[],
+                &snapshot.last_watched_eth_block_number.to_string(),
+            )
+            .await
+            .expect("Cant update events state");
+
+        transaction
+            .commit()
+            .await
+            .expect("Unable to commit DB transaction");
+    }
+
     async fn get_block_events_state_from_storage(&mut self) -> EventsState {
         let last_watched_eth_block_number = self.get_last_watched_block_number_from_storage().await;
 
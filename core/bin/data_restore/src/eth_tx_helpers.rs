@@ -1,3 +1,5 @@
+// Built-in uses
+use std::{future::Future, time::Duration};
 // External uses
 use anyhow::{ensure, format_err};
 use web3::types::H256;
@@ -5,6 +7,10 @@ use web3::types::{Transaction, TransactionId};
 use web3::{Transport, Web3};
 
 pub const FUNC_NAME_HASH_LENGTH: usize = 4;
+/// Maximum amount of attempts for a single request to the Ethereum node.
+pub const MAX_REQUEST_ATTEMPTS: usize = 5;
+/// Delay before the first retry of the failed request, doubled on each next attempt.
+const RETRY_DELAY: Duration = Duration::from_millis(500);
 
 /// Returns Ethereum transaction input data
 ///
@@ -59,3 +65,35 @@ pub async fn get_ethereum_transaction<T: Transport>(
         .ok_or_else(|| format_err!("No tx with this hash"))?;
     Ok(web3_transaction)
 }
+
+/// Performs the request to the Ethereum node, retrying it with exponential backoff if it fails
+///
+/// # Arguments
+///
+/// * `request` - Function creating the request future
+///
+pub async fn with_retries<F, Fut, T>(mut request: F) -> Result<T, anyhow::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, anyhow::Error>>,
+{
+    let mut delay = RETRY_DELAY;
+    let mut attempt = 1;
+    loop {
+        match request().await {
+            Ok(result) => return Ok(result),
+            Err(err) if attempt < MAX_REQUEST_ATTEMPTS => {
+                log::warn!(
+                    "Ethereum request failed (attempt {} of {}): {}",
+                    attempt,
+                    MAX_REQUEST_ATTEMPTS,
+                    err
+                );
+                tokio::time::delay_for(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
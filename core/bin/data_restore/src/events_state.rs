@@ -1,34 +1,15 @@
 // External deps
 use anyhow::format_err;
+use futures::{stream, StreamExt, TryStreamExt};
 use std::convert::TryFrom;
 use web3::contract::Contract;
 use web3::types::Transaction;
 use web3::types::{BlockNumber, FilterBuilder, Log, H256, U256};
 use web3::{Transport, Web3};
 // Workspace deps
-use crate::eth_tx_helpers::get_block_number_from_ethereum_transaction;
+use crate::eth_tx_helpers::{get_block_number_from_ethereum_transaction, with_retries};
 use crate::events::{BlockEvent, EventType};
-use zksync_types::{Address, TokenId};
-
-#[derive(Debug)]
-pub struct NewTokenEvent {
-    pub address: Address,
-    pub id: TokenId,
-}
-
-impl TryFrom<Log> for NewTokenEvent {
-    type Error = anyhow::Error;
-
-    fn try_from(event: Log) -> Result<NewTokenEvent, anyhow::Error> {
-        if event.topics.len() != 3 {
-            return Err(format_err!("Failed to parse NewTokenEvent: {:#?}", event));
-        }
-        Ok(NewTokenEvent {
-            address: Address::from_slice(&event.topics[1].as_fixed_bytes()[12..]),
-            id: U256::from_big_endian(&event.topics[2].as_fixed_bytes()[..]).as_u32() as u16,
-        })
-    }
-}
+use zksync_types::tokens::NewTokenEvent;
 
 /// Rollup contract events states description
 #[derive(Debug, Clone)]
@@ -80,7 +61,11 @@ impl EventsState {
     /// * `governance_contract` - Governance contract
     /// * `eth_blocks_step` - Blocks step for watching
     /// * `end_eth_blocks_offset` - Delta between last eth block and last watched block
+    /// * `parallel_requests` - Maximum amount of blocks steps fetched concurrently
+    /// * `last_restored_block` - Last block present in the tree state, events for it and preceding
+    ///   blocks are ignored (e.g. if the state was restored from a snapshot)
     ///
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_events_state<T: Transport>(
         &mut self,
         web3: &Web3<T>,
@@ -88,6 +73,8 @@ impl EventsState {
         governance_contract: &(ethabi::Contract, Contract<T>),
         eth_blocks_step: u64,
         end_eth_blocks_offset: u64,
+        parallel_requests: usize,
+        last_restored_block: u32,
     ) -> Result<(Vec<BlockEvent>, Vec<NewTokenEvent>, u64), anyhow::Error> {
         self.remove_verified_events();
 
@@ -99,6 +86,7 @@ impl EventsState {
                 self.last_watched_eth_block_number,
                 eth_blocks_step,
                 end_eth_blocks_offset,
+                parallel_requests,
             )
             .await?;
 
@@ -107,6 +95,7 @@ impl EventsState {
         if !self.update_blocks_state(zksync_contract, &block_events) {
             return Ok((vec![], token_events, self.last_watched_eth_block_number));
         }
+        self.remove_restored_events(last_restored_block);
 
         let mut events_to_return: Vec<BlockEvent> = self.committed_events.clone();
         events_to_return.extend(self.verified_events.clone());
@@ -130,6 +119,10 @@ impl EventsState {
 
     /// Returns blocks logs, added token logs and the new last watched block number
     ///
+    /// The range between the last watched block and the latest block minus delta is split into steps,
+    /// and up to `parallel_requests` steps are fetched concurrently. Logs are returned in the order
+    /// of the steps, so the events order is preserved.
+    ///
     /// # Arguments
     ///
     /// * `web3` - Web3 provider url
@@ -138,6 +131,7 @@ impl EventsState {
     /// * `last_watched_block_number` - the current last watched eth block
     /// * `eth_blocks_step` - Ethereum blocks delta step
     /// * `end_eth_blocks_offset` - last block delta
+    /// * `parallel_requests` - Maximum amount of steps fetched concurrently
     ///
     async fn get_new_events_and_last_watched_block<T: Transport>(
         web3: &Web3<T>,
@@ -146,27 +140,93 @@ impl EventsState {
         last_watched_block_number: u64,
         eth_blocks_step: u64,
         end_eth_blocks_offset: u64,
+        parallel_requests: usize,
     ) -> Result<(Vec<Log>, Vec<NewTokenEvent>, u64), anyhow::Error> {
         let latest_eth_block_minus_delta =
-            EventsState::get_last_block_number(web3).await? - end_eth_blocks_offset;
+            with_retries(|| EventsState::get_last_block_number(web3)).await?
+                - end_eth_blocks_offset;
 
-        if latest_eth_block_minus_delta == last_watched_block_number {
+        if latest_eth_block_minus_delta <= last_watched_block_number {
             return Ok((vec![], vec![], last_watched_block_number)); // No new eth blocks
         }
 
-        let from_block_number_u64 = last_watched_block_number + 1;
+        let ranges = EventsState::split_into_steps(
+            last_watched_block_number + 1,
+            latest_eth_block_minus_delta,
+            eth_blocks_step,
+            parallel_requests,
+        );
+        let to_block_number_u64 = ranges
+            .last()
+            .map(|&(_, to)| to)
+            .unwrap_or(last_watched_block_number);
+
+        let steps_logs: Vec<(Vec<Log>, Vec<NewTokenEvent>)> = stream::iter(ranges)
+            .map(|(from, to)| {
+                with_retries(move || {
+                    EventsState::get_step_logs(web3, zksync_contract, governance_contract, from, to)
+                })
+            })
+            .buffered(parallel_requests.max(1))
+            .try_collect()
+            .await?;
 
-        let to_block_number_u64 =
-        // if (latest eth block < last watched + delta) then choose it
-        if from_block_number_u64 + eth_blocks_step >= latest_eth_block_minus_delta {
-            latest_eth_block_minus_delta
-        } else {
-            from_block_number_u64 + eth_blocks_step
-        };
+        let mut block_logs = Vec::new();
+        let mut token_logs = Vec::new();
+        for (step_block_logs, step_token_logs) in steps_logs {
+            block_logs.extend(step_block_logs);
+            token_logs.extend(step_token_logs);
+        }
 
-        let to_block_number = BlockNumber::Number(to_block_number_u64.into());
+        Ok((block_logs, token_logs, to_block_number_u64))
+    }
 
-        let from_block_number = BlockNumber::Number(from_block_number_u64.into());
+    /// Splits the range of Ethereum blocks into steps.
+    /// Returns at most `max_steps` ranges, each range bounds are inclusive
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - First block of the range
+    /// * `to` - Last block of the range
+    /// * `eth_blocks_step` - Ethereum blocks delta step
+    /// * `max_steps` - Maximum amount of steps
+    ///
+    fn split_into_steps(
+        from: u64,
+        to: u64,
+        eth_blocks_step: u64,
+        max_steps: usize,
+    ) -> Vec<(u64, u64)> {
+        let mut ranges = Vec::new();
+        let mut step_from = from;
+        while step_from <= to && ranges.len() < max_steps.max(1) {
+            // if (latest eth block < last watched + delta) then choose it
+            let step_to = std::cmp::min(step_from + eth_blocks_step, to);
+            ranges.push((step_from, step_to));
+            step_from = step_to + 1;
+        }
+        ranges
+    }
+
+    /// Returns blocks logs and added token logs for the single step
+    ///
+    /// # Arguments
+    ///
+    /// * `web3` - Web3 provider url
+    /// * `zksync_contract` - Rollup contract
+    /// * `governance_contract` - Governance contract
+    /// * `from` - From ethereum block number
+    /// * `to` - To ethereum block number
+    ///
+    async fn get_step_logs<T: Transport>(
+        web3: &Web3<T>,
+        zksync_contract: &(ethabi::Contract, Contract<T>),
+        governance_contract: &(ethabi::Contract, Contract<T>),
+        from: u64,
+        to: u64,
+    ) -> Result<(Vec<Log>, Vec<NewTokenEvent>), anyhow::Error> {
+        let from_block_number = BlockNumber::Number(from.into());
+        let to_block_number = BlockNumber::Number(to.into());
 
         let block_logs =
             EventsState::get_block_logs(web3, zksync_contract, from_block_number, to_block_number)
@@ -180,7 +240,7 @@ impl EventsState {
         )
         .await?;
 
-        Ok((block_logs, token_logs, to_block_number_u64))
+        Ok((block_logs, token_logs))
     }
 
     /// Returns new added token logs
@@ -357,6 +417,19 @@ impl EventsState {
         self.committed_events.drain(0..count_to_remove);
     }
 
+    /// Removes events for the blocks that are already present in the tree state
+    ///
+    /// # Arguments
+    ///
+    /// * `last_restored_block` - Last block present in the tree state
+    ///
+    fn remove_restored_events(&mut self, last_restored_block: u32) {
+        self.committed_events
+            .retain(|bl| bl.block_num > last_restored_block);
+        self.verified_events
+            .retain(|bl| bl.block_num > last_restored_block);
+    }
+
     /// Returns only verified committed blocks from verified
     pub fn get_only_verified_committed_events(&self) -> Vec<BlockEvent> {
         let count_to_get = self.verified_events.len();
@@ -439,4 +512,22 @@ mod test {
         assert_eq!(events_state.committed_events.len(), 16);
         assert_eq!(events_state.verified_events.len(), 11);
     }
+
+    #[test]
+    fn split_into_steps() {
+        // Range fits into a single step.
+        assert_eq!(EventsState::split_into_steps(1, 50, 100, 8), vec![(1, 50)]);
+        // Range is split into several steps, the last one is truncated.
+        assert_eq!(
+            EventsState::split_into_steps(1, 250, 100, 8),
+            vec![(1, 101), (102, 202), (203, 250)]
+        );
+        // Amount of steps is limited, the rest of the range is left for the next update.
+        assert_eq!(
+            EventsState::split_into_steps(1, 1000, 100, 2),
+            vec![(1, 101), (102, 202)]
+        );
+        // Empty range.
+        assert!(EventsState::split_into_steps(11, 10, 100, 8).is_empty());
+    }
 }
@@ -1,19 +1,21 @@
 use std::cmp::max;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use web3::types::Address;
 
+use zksync_crypto::Fr;
 use zksync_types::block::Block;
 use zksync_types::{
-    Account, AccountId, AccountMap, AccountUpdate, AccountUpdates, Action, Operation, Token,
-    TokenGenesisListItem,
+    tokens::NewTokenEvent, Account, AccountId, AccountMap, AccountUpdate, AccountUpdates, Action,
+    BlockNumber, Operation, Token, TokenGenesisListItem,
 };
 
 use crate::{
     data_restore_driver::StorageUpdateState,
     events::{BlockEvent, EventType},
-    events_state::{EventsState, NewTokenEvent},
+    events_state::EventsState,
     rollup_ops::RollupOpsBlock,
+    snapshot::StateSnapshot,
     storage_interactor::StorageInteractor,
     storage_interactor::StoredTreeState,
 };
@@ -27,6 +29,7 @@ pub struct InMemoryStorageInteractor {
     last_committed_block: u32,
     last_verified_block: u32,
     accounts: AccountMap,
+    root_hashes: BTreeMap<BlockNumber, Fr>,
 }
 
 impl Default for InMemoryStorageInteractor {
@@ -59,6 +62,8 @@ impl StorageInteractor for InMemoryStorageInteractor {
 
         self.last_committed_block = commit_op.block.block_number;
         self.last_verified_block = verify_op.block.block_number;
+        self.root_hashes
+            .insert(block.block_number, block.new_root_hash);
 
         self.commit_state_update(block.block_number, accounts_updated);
         self.storage_state = StorageUpdateState::None
@@ -105,6 +110,20 @@ impl StorageInteractor for InMemoryStorageInteractor {
         self.commit_state_update(0, vec![(0, genesis_acc_update)]);
     }
 
+    async fn save_snapshot_state(&mut self, snapshot: &StateSnapshot) {
+        for token in &snapshot.tokens {
+            self.tokens.insert(token.id, token.clone());
+        }
+        self.commit_state_update(0, snapshot.account_updates());
+
+        self.last_committed_block = snapshot.block_number;
+        self.last_verified_block = snapshot.block_number;
+        self.root_hashes
+            .insert(snapshot.block_number, snapshot.root_hash);
+        self.last_watched_block = snapshot.last_watched_eth_block_number;
+        self.storage_state = StorageUpdateState::None;
+    }
+
     async fn get_block_events_state_from_storage(&mut self) -> EventsState {
         let committed_events = self.load_committed_events_state();
 
@@ -151,9 +170,25 @@ impl InMemoryStorageInteractor {
             last_committed_block: 0,
             last_verified_block: 0,
             accounts: Default::default(),
+            root_hashes: Default::default(),
         }
     }
 
+    /// Returns the restored accounts state.
+    pub fn accounts(&self) -> &AccountMap {
+        &self.accounts
+    }
+
+    /// Returns the restored tokens.
+    pub fn tokens(&self) -> &HashMap<u16, Token> {
+        &self.tokens
+    }
+
+    /// Returns the root hashes of the restored blocks.
+    pub fn root_hashes(&self) -> &BTreeMap<BlockNumber, Fr> {
+        &self.root_hashes
+    }
+
     pub fn insert_new_account(&mut self, id: AccountId, address: &Address) {
         self.accounts
             .insert(id, Account::default_with_address(address));
@@ -6,8 +6,10 @@ pub mod events;
 pub mod events_state;
 pub mod inmemory_storage_interactor;
 pub mod rollup_ops;
+pub mod snapshot;
 pub mod storage_interactor;
 pub mod tree_state;
+pub mod verification;
 
 #[cfg(test)]
 mod tests;
@@ -18,6 +20,8 @@ use zksync_types::tokens::get_genesis_token_list;
 // How many blocks we will process at once.
 pub const ETH_BLOCKS_STEP: u64 = 10_000;
 pub const END_ETH_BLOCKS_OFFSET: u64 = 40;
+// How many requests to the Ethereum node are performed concurrently.
+pub const PARALLEL_REQUESTS: usize = 8;
 
 pub async fn add_tokens_to_storage<I: StorageInteractor>(interactor: &mut I, eth_network: &str) {
     let genesis_tokens =
@@ -8,7 +8,9 @@ use zksync_types::{Address, H256};
 
 use zksync_data_restore::{
     add_tokens_to_storage, data_restore_driver::DataRestoreDriver,
-    database_storage_interactor::DatabaseStorageInteractor, END_ETH_BLOCKS_OFFSET, ETH_BLOCKS_STEP,
+    database_storage_interactor::DatabaseStorageInteractor,
+    inmemory_storage_interactor::InMemoryStorageInteractor, snapshot::StateSnapshot,
+    verification::StateVerifier, END_ETH_BLOCKS_OFFSET, ETH_BLOCKS_STEP,
 };
 
 #[derive(StructOpt)]
@@ -41,6 +43,28 @@ struct Opt {
     /// Provides a path to the configuration file for data restore
     #[structopt(long = "config", name = "config")]
     config_path: Option<String>,
+
+    /// Restores data starting from the provided state snapshot file instead of genesis.
+    /// The snapshot file is not authenticated, so it must come from a trusted source
+    #[structopt(long = "snapshot", name = "snapshot", conflicts_with = "genesis")]
+    snapshot_path: Option<String>,
+
+    /// Exports the state snapshot of the last executed block from the storage to the provided file and exits
+    #[structopt(long = "export_snapshot", name = "export_snapshot")]
+    export_snapshot_path: Option<String>,
+
+    /// Restores the state in memory and compares it with the state stored in the database
+    /// instead of writing it. Exits with non-zero code if there are any discrepancies
+    #[structopt(long, conflicts_with_all = &["genesis", "continue", "snapshot"])]
+    verify: bool,
+
+    /// Provides a path to save the verification report to. The report is printed if not set
+    #[structopt(long = "report", name = "report", requires = "verify")]
+    report_path: Option<String>,
+
+    /// Maximum amount of concurrent requests to the Ethereum node
+    #[structopt(long = "parallel_requests", name = "parallel_requests")]
+    parallel_requests: Option<usize>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,7 +121,71 @@ async fn main() {
     } else {
         None
     };
-    let storage = connection_pool.access_storage().await.unwrap();
+    let mut storage = connection_pool.access_storage().await.unwrap();
+
+    if let Some(path) = opt.export_snapshot_path {
+        let web3 = web3::Web3::new(transport);
+        let snapshot = StateSnapshot::export_from_storage(&mut storage, &web3)
+            .await
+            .expect("Can't export the state snapshot");
+        snapshot
+            .save_to_file(&path)
+            .expect("Can't save the state snapshot");
+        log::info!(
+            "Snapshot of the block {} is saved to {}",
+            snapshot.block_number,
+            path
+        );
+        return;
+    }
+
+    if opt.verify {
+        // State is restored independently from the database, which is only used for comparison.
+        let mut interactor = InMemoryStorageInteractor::new();
+        let mut driver = DataRestoreDriver::new(
+            transport,
+            config.governance_addr,
+            config.contract_addr,
+            ETH_BLOCKS_STEP,
+            END_ETH_BLOCKS_OFFSET,
+            config.available_block_chunk_sizes,
+            true,
+            None,
+        );
+        if let Some(parallel_requests) = opt.parallel_requests {
+            driver.parallel_requests = parallel_requests;
+        }
+        add_tokens_to_storage(&mut interactor, &config.eth_network).await;
+        driver
+            .set_genesis_state(&mut interactor, config.genesis_tx_hash)
+            .await;
+        driver.run_state_update(&mut interactor).await;
+
+        let report = StateVerifier::new(&mut storage)
+            .verify(&interactor)
+            .await
+            .expect("Can't compare the restored state with the database");
+        let report_json =
+            serde_json::to_string_pretty(&report).expect("Can't serialize the report");
+        match opt.report_path {
+            Some(path) => std::fs::write(path, report_json).expect("Can't save the report"),
+            None => println!("{}", report_json),
+        }
+
+        if report.is_ok() {
+            log::info!(
+                "Restored state matches the database state at the block {}",
+                report.last_block
+            );
+        } else {
+            log::error!(
+                "Found {} discrepancies between restored and database states",
+                report.discrepancies.len()
+            );
+            std::process::exit(1);
+        }
+        return;
+    }
 
     let mut driver = DataRestoreDriver::new(
         transport,
@@ -109,6 +197,9 @@ async fn main() {
         finite_mode,
         final_hash,
     );
+    if let Some(parallel_requests) = opt.parallel_requests {
+        driver.parallel_requests = parallel_requests;
+    }
 
     let mut interactor = DatabaseStorageInteractor::new(storage);
     // If genesis is argument is present - there will be fetching contracts creation transactions to get first eth block and genesis acc address
@@ -122,6 +213,12 @@ async fn main() {
             .await;
     }
 
+    // If snapshot is provided, restoring will be started from the snapshot block
+    if let Some(path) = opt.snapshot_path {
+        let snapshot = StateSnapshot::load_from_file(&path).expect("Can't load the state snapshot");
+        driver.set_snapshot_state(&mut interactor, snapshot).await;
+    }
+
     if opt.continue_mode && driver.load_state_from_storage(&mut interactor).await {
         std::process::exit(0);
     }
@@ -1,7 +1,8 @@
 use crate::eth_tx_helpers::{get_ethereum_transaction, get_input_data_from_ethereum_transaction};
 use crate::events::BlockEvent;
+use anyhow::format_err;
 use ethabi::ParamType;
-use web3::{Transport, Web3};
+use web3::{types::H256, Transport, Web3};
 use zksync_types::operations::ZkSyncOp;
 
 /// Description of a Rollup operations block
@@ -27,35 +28,22 @@ impl RollupOpsBlock {
         web3: &Web3<T>,
         event_data: &BlockEvent,
     ) -> Result<Self, anyhow::Error> {
-        let transaction = get_ethereum_transaction(web3, &event_data.transaction_hash).await?;
-        let input_data = get_input_data_from_ethereum_transaction(&transaction)?;
+        let decoded_commitment_parameters =
+            RollupOpsBlock::get_commitment_parameters(web3, event_data).await?;
 
         let fee_account_argument_id = 1;
         let public_data_argument_id = 3;
-        let decoded_commitment_parameters = ethabi::decode(
-            vec![
-                ParamType::Uint(32),                                   // uint32 _blockNumber,
-                ParamType::Uint(32),                                   // uint32 _feeAccount,
-                ParamType::Array(Box::new(ParamType::FixedBytes(32))), // bytes32[] _newRoots,
-                ParamType::Bytes, // bytes calldata _publicData,
-                ParamType::Bytes, // bytes calldata _ethWitness,
-                ParamType::Array(Box::new(ParamType::Uint(32))), // uint32[] calldata _ethWitnessSizes
-            ]
-            .as_slice(),
-            input_data.as_slice(),
-        )
-        .map_err(|_| {
-            anyhow::Error::from(Box::new(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "can't get decoded parameters from commitment transaction",
-            )))
-        })?;
 
         if let (ethabi::Token::Uint(fee_acc), ethabi::Token::Bytes(public_data)) = (
             &decoded_commitment_parameters[fee_account_argument_id],
             &decoded_commitment_parameters[public_data_argument_id],
         ) {
-            let ops = RollupOpsBlock::get_rollup_ops_from_data(public_data.as_slice())?;
+            // Decoding the pubdata of big blocks is CPU-heavy, so it's done off the async executor.
+            let public_data = public_data.clone();
+            let ops = tokio::task::spawn_blocking(move || {
+                RollupOpsBlock::get_rollup_ops_from_data(public_data.as_slice())
+            })
+            .await??;
             let fee_account = fee_acc.as_u32();
 
             let block = RollupOpsBlock {
@@ -73,6 +61,64 @@ impl RollupOpsBlock {
         }
     }
 
+    /// Returns the root hash of the block committed on Ethereum
+    ///
+    /// # Arguments
+    ///
+    /// * `web3` - Web3 provider url
+    /// * `event_data` - Rollup contract event description
+    ///
+    pub async fn get_committed_root_hash<T: Transport>(
+        web3: &Web3<T>,
+        event_data: &BlockEvent,
+    ) -> Result<H256, anyhow::Error> {
+        let decoded_commitment_parameters =
+            RollupOpsBlock::get_commitment_parameters(web3, event_data).await?;
+
+        let new_roots_argument_id = 2;
+        match &decoded_commitment_parameters[new_roots_argument_id] {
+            ethabi::Token::Array(roots) => match roots.first() {
+                Some(ethabi::Token::FixedBytes(root)) => Ok(H256::from_slice(root)),
+                _ => Err(format_err!("can't parse committed root hash")),
+            },
+            _ => Err(format_err!("can't parse commitment parameters")),
+        }
+    }
+
+    /// Returns decoded parameters of the block commitment transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `web3` - Web3 provider url
+    /// * `event_data` - Rollup contract event description
+    ///
+    async fn get_commitment_parameters<T: Transport>(
+        web3: &Web3<T>,
+        event_data: &BlockEvent,
+    ) -> Result<Vec<ethabi::Token>, anyhow::Error> {
+        let transaction = get_ethereum_transaction(web3, &event_data.transaction_hash).await?;
+        let input_data = get_input_data_from_ethereum_transaction(&transaction)?;
+
+        ethabi::decode(
+            vec![
+                ParamType::Uint(32),                                   // uint32 _blockNumber,
+                ParamType::Uint(32),                                   // uint32 _feeAccount,
+                ParamType::Array(Box::new(ParamType::FixedBytes(32))), // bytes32[] _newRoots,
+                ParamType::Bytes, // bytes calldata _publicData,
+                ParamType::Bytes, // bytes calldata _ethWitness,
+                ParamType::Array(Box::new(ParamType::Uint(32))), // uint32[] calldata _ethWitnessSizes
+            ]
+            .as_slice(),
+            input_data.as_slice(),
+        )
+        .map_err(|_| {
+            anyhow::Error::from(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "can't get decoded parameters from commitment transaction",
+            )))
+        })
+    }
+
     /// Returns a Rollup operations vector
     ///
     /// # Arguments
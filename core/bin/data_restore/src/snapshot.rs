@@ -0,0 +1,278 @@
+// Built-in deps
+use std::path::Path;
+// External deps
+use anyhow::{ensure, format_err};
+use serde::{Deserialize, Serialize};
+use web3::{Transport, Web3};
+// Workspace deps
+use zksync_crypto::{serialization::FrSerde, Fr};
+use zksync_state::state::ZkSyncState;
+use zksync_storage::StorageProcessor;
+use zksync_types::{
+    aggregated_operations::AggregatedActionType, block::Block, Account, AccountId, AccountMap,
+    AccountUpdate, AccountUpdates, BlockNumber, PubKeyHash, Token, H256,
+};
+// Local deps
+use crate::eth_tx_helpers::{get_block_number_from_ethereum_transaction, get_ethereum_transaction};
+
+/// Snapshot of the zkSync state at some verified block.
+///
+/// Snapshot allows to start restoring the state from the snapshot block rather than
+/// from the genesis. The root hash of the accounts tree is checked upon loading, and
+/// then the root hash of the next restored block is compared to the one of the verified
+/// block on Ethereum.
+///
+/// Nothing authenticates the snapshot file, so the snapshot state is only confirmed once
+/// the next block is restored. Snapshots should only be obtained from a trusted source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// Number of the block the snapshot was taken at.
+    pub block_number: BlockNumber,
+    /// Root hash of the accounts tree after the block.
+    #[serde(with = "FrSerde")]
+    pub root_hash: Fr,
+    /// Fee account of the block.
+    pub fee_account: AccountId,
+    /// Number of the first priority operation that was not processed by the block.
+    pub unprocessed_priority_op: u64,
+    /// Size of the block in chunks.
+    pub block_chunks_size: usize,
+    /// Commitment of the block.
+    pub block_commitment: H256,
+    /// Timestamp of the block.
+    pub block_timestamp: u64,
+    /// Ethereum block number after which the events for blocks that follow the
+    /// snapshot block are emitted.
+    pub last_watched_eth_block_number: u64,
+    /// Tokens known at the moment of the snapshot.
+    pub tokens: Vec<Token>,
+    /// State of the accounts tree.
+    pub accounts: Vec<(AccountId, Account)>,
+}
+
+impl StateSnapshot {
+    /// Loads the snapshot from the JSON file.
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Saves the snapshot to the JSON file.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), anyhow::Error> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Returns the accounts state as an account map.
+    pub fn account_map(&self) -> AccountMap {
+        self.accounts.iter().cloned().collect()
+    }
+
+    /// Checks that the root hash of the accounts tree built from the snapshot accounts
+    /// matches the root hash declared in the snapshot.
+    ///
+    /// This only checks the internal consistency of the snapshot: a forged snapshot with
+    /// a matching root hash passes the check. The state is confirmed against Ethereum
+    /// after the next block is restored.
+    pub fn verify(&self) -> Result<(), anyhow::Error> {
+        ensure!(
+            self.accounts.iter().any(|(id, _)| *id == self.fee_account),
+            "Snapshot does not contain the fee account {}",
+            self.fee_account
+        );
+
+        let state = ZkSyncState::from_acc_map(self.account_map(), self.block_number);
+        ensure!(
+            state.root_hash() == self.root_hash,
+            "Snapshot root hash mismatch: declared {}, computed {}",
+            self.root_hash,
+            state.root_hash()
+        );
+        Ok(())
+    }
+
+    /// Returns the block header of the snapshot block. The block does not contain any transactions.
+    pub fn block(&self) -> Block {
+        Block::new(
+            self.block_number,
+            self.root_hash,
+            self.fee_account,
+            Vec::new(),
+            (0, self.unprocessed_priority_op),
+            self.block_chunks_size,
+            0.into(),
+            0.into(),
+            self.block_commitment,
+            self.block_timestamp,
+        )
+    }
+
+    /// Returns the list of updates that creates the snapshot accounts state from the empty one.
+    pub fn account_updates(&self) -> AccountUpdates {
+        let mut updates = Vec::new();
+        for (id, account) in &self.accounts {
+            updates.push((
+                *id,
+                AccountUpdate::Create {
+                    address: account.address,
+                    nonce: account.nonce,
+                },
+            ));
+
+            for (token, balance) in account.get_nonzero_balances() {
+                updates.push((
+                    *id,
+                    AccountUpdate::UpdateBalance {
+                        old_nonce: account.nonce,
+                        new_nonce: account.nonce,
+                        balance_update: (token, 0u32.into(), balance.0),
+                    },
+                ));
+            }
+
+            if account.pub_key_hash != PubKeyHash::default() {
+                updates.push((
+                    *id,
+                    AccountUpdate::ChangePubKeyHash {
+                        old_pub_key_hash: PubKeyHash::default(),
+                        new_pub_key_hash: account.pub_key_hash,
+                        old_nonce: account.nonce,
+                        new_nonce: account.nonce,
+                    },
+                ));
+            }
+        }
+        updates
+    }
+
+    /// Creates the snapshot of the last executed block from the server storage.
+    ///
+    /// Ethereum node is required to find the block in which the snapshot block was committed:
+    /// events for all the subsequent blocks are emitted no earlier than that.
+    pub async fn export_from_storage<T: Transport>(
+        storage: &mut StorageProcessor<'_>,
+        web3: &Web3<T>,
+    ) -> Result<Self, anyhow::Error> {
+        let mut transaction = storage.start_transaction().await?;
+
+        let (block_number, accounts) = transaction
+            .chain()
+            .state_schema()
+            .load_verified_state()
+            .await?;
+        ensure!(
+            block_number > 0,
+            "There are no executed blocks in the storage"
+        );
+
+        let block = transaction
+            .chain()
+            .block_schema()
+            .get_block(block_number)
+            .await?
+            .ok_or_else(|| format_err!("Block #{} is not stored", block_number))?;
+
+        let tokens = transaction
+            .tokens_schema()
+            .load_tokens()
+            .await?
+            .into_iter()
+            .map(|(_, token)| token)
+            .collect();
+
+        let (commit_op_id, _) = transaction
+            .chain()
+            .operations_schema()
+            .get_aggregated_op_that_affects_block(AggregatedActionType::CommitBlocks, block_number)
+            .await?
+            .ok_or_else(|| format_err!("Block #{} commitment is not found", block_number))?;
+        let commit_tx_hash = transaction
+            .ethereum_schema()
+            .aggregated_op_final_hash(commit_op_id)
+            .await?
+            .ok_or_else(|| format_err!("Block #{} commitment is not confirmed", block_number))?;
+
+        transaction.commit().await?;
+
+        let commit_tx = get_ethereum_transaction(web3, &commit_tx_hash).await?;
+        let commit_eth_block = get_block_number_from_ethereum_transaction(&commit_tx)?;
+
+        let mut accounts: Vec<_> = accounts.into_iter().collect();
+        accounts.sort_by_key(|(id, _)| *id);
+
+        Ok(Self {
+            block_number,
+            root_hash: block.new_root_hash,
+            fee_account: block.fee_account,
+            unprocessed_priority_op: block.processed_priority_ops.1,
+            block_chunks_size: block.block_chunks_size,
+            block_commitment: block.block_commitment,
+            block_timestamp: block.timestamp,
+            // Events are fetched starting from the next block after the last watched one.
+            last_watched_eth_block_number: commit_eth_block - 1,
+            tokens,
+            accounts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use zksync_types::{helpers::apply_updates, Address};
+
+    fn create_snapshot() -> StateSnapshot {
+        let mut first = Account::default_with_address(&Address::repeat_byte(1));
+        first.set_balance(0, 100u32.into());
+        first.nonce = 3;
+        let mut second = Account::default_with_address(&Address::repeat_byte(2));
+        second.set_balance(1, 20u32.into());
+        second.pub_key_hash =
+            PubKeyHash::from_hex("sync:1111111111111111111111111111111111111111").unwrap();
+
+        let accounts = vec![(0, first), (5, second)];
+        let state = ZkSyncState::from_acc_map(accounts.iter().cloned().collect(), 10);
+
+        StateSnapshot {
+            block_number: 10,
+            root_hash: state.root_hash(),
+            fee_account: 0,
+            unprocessed_priority_op: 4,
+            block_chunks_size: 10,
+            block_commitment: H256::zero(),
+            block_timestamp: 0,
+            last_watched_eth_block_number: 0,
+            tokens: Vec::new(),
+            accounts,
+        }
+    }
+
+    /// Checks that the snapshot with the correct root hash passes the verification,
+    /// and the modified one does not.
+    #[test]
+    fn snapshot_verification() {
+        let snapshot = create_snapshot();
+        snapshot.verify().unwrap();
+
+        let mut tampered = snapshot.clone();
+        tampered.accounts[1].1.set_balance(1, 21u32.into());
+        assert!(tampered.verify().is_err());
+
+        let mut wrong_fee_account = snapshot;
+        wrong_fee_account.fee_account = 1;
+        assert!(wrong_fee_account.verify().is_err());
+    }
+
+    /// Checks that the account updates produced by snapshot recreate the snapshot state.
+    #[test]
+    fn snapshot_account_updates() {
+        let snapshot = create_snapshot();
+
+        let mut accounts = AccountMap::default();
+        apply_updates(&mut accounts, snapshot.account_updates());
+
+        let state = ZkSyncState::from_acc_map(accounts, snapshot.block_number);
+        assert_eq!(state.root_hash(), snapshot.root_hash);
+    }
+}
@@ -6,14 +6,16 @@ use zksync_storage::data_restore::records::{
     NewBlockEvent, StoredBlockEvent, StoredRollupOpsBlock,
 };
 use zksync_types::{
-    block::Block, AccountMap, AccountUpdate, AccountUpdates, TokenGenesisListItem, TokenId,
+    block::Block, tokens::NewTokenEvent, AccountMap, AccountUpdate, AccountUpdates,
+    TokenGenesisListItem, TokenId,
 };
 
 use crate::{
     data_restore_driver::StorageUpdateState,
     events::{BlockEvent, EventType},
-    events_state::{EventsState, NewTokenEvent},
+    events_state::EventsState,
     rollup_ops::RollupOpsBlock,
+    snapshot::StateSnapshot,
 };
 
 pub struct StoredTreeState {
@@ -73,6 +75,15 @@ pub trait StorageInteractor {
     ///
     async fn save_genesis_tree_state(&mut self, genesis_acc_update: AccountUpdate);
 
+    /// Saves the state snapshot in storage: tokens, accounts state, snapshot block
+    /// and the last watched eth block number
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - Verified state snapshot
+    ///
+    async fn save_snapshot_state(&mut self, snapshot: &StateSnapshot);
+
     /// Returns Rollup contract events state from storage
     async fn get_block_events_state_from_storage(&mut self) -> EventsState;
 
@@ -26,7 +26,10 @@ use crate::{
     data_restore_driver::DataRestoreDriver,
     database_storage_interactor::DatabaseStorageInteractor,
     inmemory_storage_interactor::InMemoryStorageInteractor,
+    rollup_ops::RollupOpsBlock,
+    snapshot::StateSnapshot,
     tests::utils::{create_log, u32_to_32bytes},
+    tree_state::TreeState,
     END_ETH_BLOCKS_OFFSET, ETH_BLOCKS_STEP,
 };
 
@@ -521,3 +524,112 @@ async fn test_with_inmemory_storage() {
     assert_eq!(driver.events_state.committed_events.len(), events.len());
     assert_eq!(driver.tree_state.state.block_number, 2)
 }
+
+#[tokio::test]
+async fn test_restore_from_snapshot() {
+    let mut transport = Web3Transport::new();
+
+    let mut interactor = InMemoryStorageInteractor::new();
+    let contract = zksync_contract();
+
+    let blocks = vec![
+        create_block(
+            1,
+            vec![create_deposit(Default::default(), Default::default(), 50)],
+        ),
+        create_block(
+            2,
+            vec![create_withdraw_operations(
+                0,
+                Default::default(),
+                Default::default(),
+                10,
+            )],
+        ),
+    ];
+
+    // Calculate the state after the first block for the snapshot, and the
+    // root hash after the second block to be committed on Ethereum.
+    let mut tree_state = TreeState::new(vec![6, 30]);
+    let mut snapshot = None;
+    for block in &blocks {
+        let ops_block = RollupOpsBlock {
+            block_num: block.block_number,
+            ops: RollupOpsBlock::get_rollup_ops_from_data(&block.get_eth_public_data()).unwrap(),
+            fee_account: block.fee_account,
+        };
+        tree_state
+            .update_tree_states_from_ops_block(&ops_block)
+            .unwrap();
+
+        if snapshot.is_none() {
+            snapshot = Some(StateSnapshot {
+                block_number: block.block_number,
+                root_hash: tree_state.root_hash(),
+                fee_account: block.fee_account,
+                unprocessed_priority_op: tree_state.current_unprocessed_priority_op,
+                block_chunks_size: block.block_chunks_size,
+                block_commitment: block.block_commitment,
+                block_timestamp: block.timestamp,
+                last_watched_eth_block_number: 1,
+                tokens: Vec::new(),
+                accounts: tree_state.get_accounts(),
+            });
+        }
+    }
+    let mut last_block = blocks[1].clone();
+    last_block.new_root_hash = tree_state.root_hash();
+
+    // Events for both blocks are available, but only the second block is expected to be
+    // processed, so the commitment transaction for the first block is not known to the transport.
+    let block_verified_topic = contract
+        .event("BlockVerification")
+        .expect("Main contract abi error")
+        .signature();
+    let block_committed_topic = contract
+        .event("BlockCommit")
+        .expect("Main contract abi error")
+        .signature();
+    for &topic in &[block_committed_topic, block_verified_topic] {
+        let logs = (1..=2)
+            .map(|block_number| {
+                create_log(
+                    topic,
+                    vec![u32_to_32bytes(block_number).into()],
+                    Bytes(vec![]),
+                    block_number,
+                    u32_to_32bytes(block_number).into(),
+                )
+            })
+            .collect();
+        transport.insert_logs(format!("{:?}", topic), logs);
+    }
+    transport.push_transactions(vec![create_transaction(2, last_block.clone())]);
+
+    let mut driver = DataRestoreDriver::new(
+        transport.clone(),
+        [1u8; 20].into(),
+        [1u8; 20].into(),
+        ETH_BLOCKS_STEP,
+        END_ETH_BLOCKS_OFFSET,
+        vec![6, 30],
+        true,
+        None,
+    );
+    driver
+        .set_snapshot_state(&mut interactor, snapshot.unwrap())
+        .await;
+    assert_eq!(driver.snapshot_block, Some(1));
+
+    driver.run_state_update(&mut interactor).await;
+
+    // Root hash of the restored block matches the verified one, so the snapshot is confirmed.
+    assert_eq!(driver.snapshot_block, None);
+    assert_eq!(driver.tree_state.state.block_number, 2);
+    assert_eq!(driver.tree_state.root_hash(), last_block.new_root_hash);
+
+    let (_, account) = interactor
+        .get_account_by_address(&Default::default())
+        .unwrap();
+    assert_eq!(BigUint::from(40u32), account.get_balance(0));
+}
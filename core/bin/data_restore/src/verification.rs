@@ -0,0 +1,349 @@
+// Built-in deps
+use std::collections::{BTreeSet, HashMap};
+// External deps
+use serde::{Deserialize, Serialize};
+// Workspace deps
+use zksync_crypto::{serialization::FrSerde, Fr};
+use zksync_storage::StorageProcessor;
+use zksync_types::{AccountId, AccountMap, Address, BlockNumber, Nonce, PubKeyHash, TokenId};
+use zksync_utils::BigUintSerdeWrapper;
+// Local deps
+use crate::inmemory_storage_interactor::InMemoryStorageInteractor;
+
+/// Discrepancy between the state restored from Ethereum and the state stored in the server database.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum StateDiscrepancy {
+    /// Block is restored, but is not present in the database.
+    MissingBlock { block: BlockNumber },
+    /// Root hash of the block differs.
+    RootHash {
+        block: BlockNumber,
+        #[serde(with = "FrSerde")]
+        restored: Fr,
+        #[serde(with = "FrSerde")]
+        stored: Fr,
+    },
+    /// Account exists only in one of the states.
+    MissingAccount {
+        account_id: AccountId,
+        restored: Option<Address>,
+        stored: Option<Address>,
+    },
+    /// Account address differs.
+    Address {
+        account_id: AccountId,
+        restored: Address,
+        stored: Address,
+    },
+    /// Account nonce differs.
+    Nonce {
+        account_id: AccountId,
+        restored: Nonce,
+        stored: Nonce,
+    },
+    /// Account public key hash differs.
+    PubKeyHash {
+        account_id: AccountId,
+        restored: PubKeyHash,
+        stored: PubKeyHash,
+    },
+    /// Account balance differs.
+    Balance {
+        account_id: AccountId,
+        token: TokenId,
+        restored: BigUintSerdeWrapper,
+        stored: BigUintSerdeWrapper,
+    },
+    /// Token is known only in one of the states, or its address differs.
+    /// Symbol and decimals are not compared, since they are not available on Ethereum.
+    Token {
+        token: TokenId,
+        restored: Option<Address>,
+        stored: Option<Address>,
+    },
+}
+
+/// Result of the comparison of the restored state with the server database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// The last restored block. Accounts are compared at this block.
+    pub last_block: BlockNumber,
+    /// Amount of blocks which root hashes were compared.
+    pub blocks_checked: usize,
+    /// Amount of accounts compared.
+    pub accounts_checked: usize,
+    /// Amount of tokens compared.
+    pub tokens_checked: usize,
+    /// Found discrepancies.
+    pub discrepancies: Vec<StateDiscrepancy>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if no discrepancies were found.
+    pub fn is_ok(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Compares the state restored independently via `InMemoryStorageInteractor` with the
+/// state stored in the server database.
+///
+/// Comparison is performed block-by-block for the root hashes of the restored blocks,
+/// and account-by-account for the state at the last restored block.
+pub struct StateVerifier<'a, 'c> {
+    storage: &'a mut StorageProcessor<'c>,
+}
+
+impl<'a, 'c> StateVerifier<'a, 'c> {
+    pub fn new(storage: &'a mut StorageProcessor<'c>) -> Self {
+        Self { storage }
+    }
+
+    /// Compares the restored state with the database one and returns the report
+    /// with all the found discrepancies.
+    ///
+    /// # Arguments
+    ///
+    /// * `interactor` - Interactor the state was restored with
+    ///
+    pub async fn verify(
+        &mut self,
+        interactor: &InMemoryStorageInteractor,
+    ) -> Result<VerificationReport, anyhow::Error> {
+        let mut discrepancies = Vec::new();
+
+        let last_block = interactor
+            .root_hashes()
+            .keys()
+            .next_back()
+            .copied()
+            .unwrap_or_default();
+
+        let blocks_checked = self
+            .verify_root_hashes(interactor, last_block, &mut discrepancies)
+            .await?;
+
+        let (_, stored_accounts) = self
+            .storage
+            .chain()
+            .state_schema()
+            .load_committed_state(Some(last_block))
+            .await?;
+        let accounts_checked =
+            compare_accounts(interactor.accounts(), &stored_accounts, &mut discrepancies);
+
+        let tokens_checked = self.verify_tokens(interactor, &mut discrepancies).await?;
+
+        Ok(VerificationReport {
+            last_block,
+            blocks_checked,
+            accounts_checked,
+            tokens_checked,
+            discrepancies,
+        })
+    }
+
+    async fn verify_root_hashes(
+        &mut self,
+        interactor: &InMemoryStorageInteractor,
+        last_block: BlockNumber,
+        discrepancies: &mut Vec<StateDiscrepancy>,
+    ) -> Result<usize, anyhow::Error> {
+        let stored_root_hashes: HashMap<_, _> = self
+            .storage
+            .chain()
+            .block_schema()
+            .load_block_root_hashes(1, last_block)
+            .await?
+            .into_iter()
+            .collect();
+
+        // Genesis block is not stored in the `blocks` table.
+        let restored_root_hashes = interactor
+            .root_hashes()
+            .iter()
+            .filter(|(&block, _)| block > 0);
+        let mut blocks_checked = 0;
+        for (&block, &restored) in restored_root_hashes {
+            blocks_checked += 1;
+            match stored_root_hashes.get(&block) {
+                Some(&stored) if stored != restored => {
+                    discrepancies.push(StateDiscrepancy::RootHash {
+                        block,
+                        restored,
+                        stored,
+                    });
+                }
+                Some(_) => {}
+                None => discrepancies.push(StateDiscrepancy::MissingBlock { block }),
+            }
+        }
+
+        Ok(blocks_checked)
+    }
+
+    async fn verify_tokens(
+        &mut self,
+        interactor: &InMemoryStorageInteractor,
+        discrepancies: &mut Vec<StateDiscrepancy>,
+    ) -> Result<usize, anyhow::Error> {
+        let stored_tokens = self.storage.tokens_schema().load_tokens().await?;
+        let restored_tokens = interactor.tokens();
+
+        let token_ids: BTreeSet<TokenId> = stored_tokens
+            .keys()
+            .chain(restored_tokens.keys())
+            .copied()
+            // ETH is not listed on Ethereum.
+            .filter(|&id| id != 0)
+            .collect();
+
+        for &token in &token_ids {
+            let restored = restored_tokens.get(&token).map(|token| token.address);
+            let stored = stored_tokens.get(&token).map(|token| token.address);
+            if restored != stored {
+                discrepancies.push(StateDiscrepancy::Token {
+                    token,
+                    restored,
+                    stored,
+                });
+            }
+        }
+
+        Ok(token_ids.len())
+    }
+}
+
+/// Compares two account maps and returns the amount of compared accounts.
+fn compare_accounts(
+    restored_accounts: &AccountMap,
+    stored_accounts: &AccountMap,
+    discrepancies: &mut Vec<StateDiscrepancy>,
+) -> usize {
+    let account_ids: BTreeSet<AccountId> = restored_accounts
+        .keys()
+        .chain(stored_accounts.keys())
+        .copied()
+        .collect();
+
+    for &account_id in &account_ids {
+        let (restored, stored) = match (
+            restored_accounts.get(&account_id),
+            stored_accounts.get(&account_id),
+        ) {
+            (Some(restored), Some(stored)) => (restored, stored),
+            (restored, stored) => {
+                discrepancies.push(StateDiscrepancy::MissingAccount {
+                    account_id,
+                    restored: restored.map(|account| account.address),
+                    stored: stored.map(|account| account.address),
+                });
+                continue;
+            }
+        };
+
+        if restored.address != stored.address {
+            discrepancies.push(StateDiscrepancy::Address {
+                account_id,
+                restored: restored.address,
+                stored: stored.address,
+            });
+        }
+        if restored.nonce != stored.nonce {
+            discrepancies.push(StateDiscrepancy::Nonce {
+                account_id,
+                restored: restored.nonce,
+                stored: stored.nonce,
+            });
+        }
+        if restored.pub_key_hash != stored.pub_key_hash {
+            discrepancies.push(StateDiscrepancy::PubKeyHash {
+                account_id,
+                restored: restored.pub_key_hash,
+                stored: stored.pub_key_hash,
+            });
+        }
+
+        let restored_balances = restored.get_nonzero_balances();
+        let stored_balances = stored.get_nonzero_balances();
+        let tokens: BTreeSet<TokenId> = restored_balances
+            .keys()
+            .chain(stored_balances.keys())
+            .copied()
+            .collect();
+        for token in tokens {
+            let restored = restored_balances.get(&token).cloned().unwrap_or_default();
+            let stored = stored_balances.get(&token).cloned().unwrap_or_default();
+            if restored != stored {
+                discrepancies.push(StateDiscrepancy::Balance {
+                    account_id,
+                    token,
+                    restored,
+                    stored,
+                });
+            }
+        }
+    }
+
+    account_ids.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use zksync_types::Account;
+
+    #[test]
+    fn accounts_comparison() {
+        let mut restored = AccountMap::default();
+        let mut stored = AccountMap::default();
+
+        let mut account = Account::default_with_address(&Address::repeat_byte(1));
+        account.set_balance(0, 10u32.into());
+        restored.insert(0, account.clone());
+        stored.insert(0, account.clone());
+
+        // Same accounts don't produce discrepancies.
+        let mut discrepancies = Vec::new();
+        assert_eq!(compare_accounts(&restored, &stored, &mut discrepancies), 1);
+        assert!(discrepancies.is_empty());
+
+        // Modify the stored account and add the one missing in the restored state.
+        let stored_account = stored.get_mut(&0).unwrap();
+        stored_account.nonce = 1;
+        stored_account.set_balance(0, 11u32.into());
+        stored_account.set_balance(2, 1u32.into());
+        stored.insert(1, Account::default_with_address(&Address::repeat_byte(2)));
+
+        let mut discrepancies = Vec::new();
+        assert_eq!(compare_accounts(&restored, &stored, &mut discrepancies), 2);
+        assert_eq!(
+            discrepancies,
+            vec![
+                StateDiscrepancy::Nonce {
+                    account_id: 0,
+                    restored: 0,
+                    stored: 1,
+                },
+                StateDiscrepancy::Balance {
+                    account_id: 0,
+                    token: 0,
+                    restored: BigUintSerdeWrapper(10u32.into()),
+                    stored: BigUintSerdeWrapper(11u32.into()),
+                },
+                StateDiscrepancy::Balance {
+                    account_id: 0,
+                    token: 2,
+                    restored: BigUintSerdeWrapper(0u32.into()),
+                    stored: BigUintSerdeWrapper(1u32.into()),
+                },
+                StateDiscrepancy::MissingAccount {
+                    account_id: 1,
+                    restored: None,
+                    stored: Some(Address::repeat_byte(2)),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Decoding of the `commitBlocks` calldata.
+
+// External deps
+use anyhow::{ensure, format_err};
+use ethabi::{Contract, Token};
+// Workspace deps
+use zksync_types::BlockNumber;
+
+const COMMIT_BLOCKS_FUNCTION: &str = "commitBlocks";
+
+/// Witness posted along with the block for the operation that has to be processed on Ethereum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnchainOperation {
+    /// Offset of the operation in the block public data, in bytes.
+    pub public_data_offset: usize,
+    /// Witness of the operation, e.g. Ethereum signature of `ChangePubKey`.
+    pub eth_witness: Vec<u8>,
+}
+
+/// Block data posted to the `commitBlocks` contract method.
+/// Only the fields required to inspect the block operations are kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommittedBlock {
+    pub block_number: BlockNumber,
+    pub public_data: Vec<u8>,
+    pub onchain_operations: Vec<OnchainOperation>,
+}
+
+/// Returns `true` if the data starts with the `commitBlocks` method selector.
+pub fn is_commit_calldata(contract: &Contract, data: &[u8]) -> bool {
+    contract
+        .function(COMMIT_BLOCKS_FUNCTION)
+        .map(|function| data.starts_with(&function.short_signature()))
+        .unwrap_or(false)
+}
+
+/// Decodes the calldata of the `commitBlocks` transaction (including the method selector)
+/// and returns all the blocks committed by it.
+pub fn decode_commit_calldata(
+    contract: &Contract,
+    calldata: &[u8],
+) -> Result<Vec<CommittedBlock>, anyhow::Error> {
+    ensure!(
+        is_commit_calldata(contract, calldata),
+        "Data is not a `{}` calldata",
+        COMMIT_BLOCKS_FUNCTION
+    );
+    let function = contract.function(COMMIT_BLOCKS_FUNCTION)?;
+    let mut args = function.decode_input(&calldata[4..])?;
+
+    // `commitBlocks(StoredBlockInfo _lastCommittedBlockData, CommitBlockInfo[] _newBlocksData)`
+    match args.pop() {
+        Some(Token::Array(blocks)) => blocks.into_iter().map(decode_commit_block_info).collect(),
+        _ => Err(format_err!(
+            "Can't find the committed blocks in the calldata"
+        )),
+    }
+}
+
+fn decode_commit_block_info(token: Token) -> Result<CommittedBlock, anyhow::Error> {
+    let fields = match token {
+        Token::Tuple(fields) if fields.len() == 6 => fields,
+        _ => return Err(format_err!("Unexpected `CommitBlockInfo` encoding")),
+    };
+
+    // `CommitBlockInfo(uint32 blockNumber, uint32 feeAccount, bytes32 newStateHash,
+    //  bytes publicData, uint256 timestamp, OnchainOperationData[] onchainOperations)`
+    let mut fields = fields.into_iter();
+    let block_number = decode_uint(fields.next())?.as_u32();
+    let public_data = decode_bytes(fields.nth(2))?;
+    let onchain_operations = match fields.nth(1) {
+        Some(Token::Array(ops)) => ops
+            .into_iter()
+            .map(decode_onchain_operation)
+            .collect::<Result<_, _>>()?,
+        _ => return Err(format_err!("Unexpected `onchainOperations` encoding")),
+    };
+
+    Ok(CommittedBlock {
+        block_number,
+        public_data,
+        onchain_operations,
+    })
+}
+
+fn decode_onchain_operation(token: Token) -> Result<OnchainOperation, anyhow::Error> {
+    // `OnchainOperationData(uint32 publicDataOffset, bytes ethWitness)`
+    match token {
+        Token::Tuple(fields) if fields.len() == 2 => {
+            let mut fields = fields.into_iter();
+            Ok(OnchainOperation {
+                public_data_offset: decode_uint(fields.next())?.as_usize(),
+                eth_witness: decode_bytes(fields.next())?,
+            })
+        }
+        _ => Err(format_err!("Unexpected `OnchainOperationData` encoding")),
+    }
+}
+
+fn decode_uint(token: Option<Token>) -> Result<ethabi::Uint, anyhow::Error> {
+    match token {
+        Some(Token::Uint(value)) => Ok(value),
+        _ => Err(format_err!("Expected uint in the calldata")),
+    }
+}
+
+fn decode_bytes(token: Option<Token>) -> Result<Vec<u8>, anyhow::Error> {
+    match token {
+        Some(Token::Bytes(bytes)) => Ok(bytes),
+        _ => Err(format_err!("Expected bytes in the calldata")),
+    }
+}
@@ -0,0 +1,366 @@
+//! Decoding of the block public data into the list of operation records.
+
+// Built-in deps
+use std::{collections::HashMap, path::Path};
+// External deps
+use anyhow::{ensure, format_err};
+use serde::Serialize;
+// Workspace deps
+use zksync_crypto::params::CHUNK_BYTES;
+use zksync_types::{
+    tokens::TokenGenesisListItem, AccountId, BlockNumber, Token, TokenId, ZkSyncOp,
+};
+// Local deps
+use crate::commitment::OnchainOperation;
+
+/// Resolves token ids to their symbols.
+#[derive(Debug, Clone)]
+pub struct TokenSymbols(HashMap<TokenId, String>);
+
+impl Default for TokenSymbols {
+    fn default() -> Self {
+        let mut symbols = HashMap::new();
+        symbols.insert(0, "ETH".to_string());
+        Self(symbols)
+    }
+}
+
+impl TokenSymbols {
+    /// Loads token symbols from the JSON file.
+    ///
+    /// File may be either the list of tokens with ids (as returned by the API), or the list
+    /// of genesis tokens from `etc/tokens`, in which case ids are assigned in the listing order.
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let content = std::fs::read_to_string(path)?;
+        let mut symbols = Self::default();
+
+        if let Ok(tokens) = serde_json::from_str::<Vec<Token>>(&content) {
+            symbols
+                .0
+                .extend(tokens.into_iter().map(|token| (token.id, token.symbol)));
+        } else {
+            let tokens: Vec<TokenGenesisListItem> = serde_json::from_str(&content)?;
+            symbols.0.extend(
+                tokens
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, token)| (idx as TokenId + 1, token.symbol)),
+            );
+        }
+
+        Ok(symbols)
+    }
+
+    pub fn get(&self, token: TokenId) -> Option<&str> {
+        self.0.get(&token).map(String::as_str)
+    }
+}
+
+/// Decoded operation from the block public data.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationRecord {
+    /// Number of the block, if the public data was taken from the commitment.
+    pub block_number: Option<BlockNumber>,
+    /// Index of the operation in the block, noops are not counted.
+    pub index: usize,
+    /// Offset of the first operation chunk in the block public data.
+    pub chunk_offset: usize,
+    /// Offset of the operation in the block public data, in bytes.
+    pub byte_offset: usize,
+    pub chunks: usize,
+    pub op_type: &'static str,
+    pub account_id: Option<AccountId>,
+    pub token: Option<TokenId>,
+    pub token_symbol: Option<String>,
+    pub amount: Option<String>,
+    pub fee: Option<String>,
+    /// Hex-encoded witness posted for the operation, if any.
+    pub eth_witness: Option<String>,
+    /// Full decoded operation. Not included into CSV output.
+    pub op: ZkSyncOp,
+}
+
+impl OperationRecord {
+    pub const CSV_HEADER: &'static str = "block_number,index,chunk_offset,byte_offset,chunks,\
+        op_type,account_id,token,token_symbol,amount,fee,eth_witness";
+
+    /// Returns the record as a CSV row matching `CSV_HEADER`.
+    pub fn to_csv_row(&self) -> String {
+        fn field<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(T::to_string).unwrap_or_default()
+        }
+
+        vec![
+            field(&self.block_number),
+            self.index.to_string(),
+            self.chunk_offset.to_string(),
+            self.byte_offset.to_string(),
+            self.chunks.to_string(),
+            self.op_type.to_string(),
+            field(&self.account_id),
+            field(&self.token),
+            csv_escape(&field(&self.token_symbol)),
+            field(&self.amount),
+            field(&self.fee),
+            field(&self.eth_witness),
+        ]
+        .join(",")
+    }
+}
+
+/// Decodes the block public data into operation records.
+///
+/// # Arguments
+///
+/// * `block_number` - Number of the block, if known
+/// * `public_data` - Block public data
+/// * `onchain_operations` - Witnesses for the operations posted along with the block
+/// * `tokens` - Token symbols
+///
+pub fn inspect_public_data(
+    block_number: Option<BlockNumber>,
+    public_data: &[u8],
+    onchain_operations: &[OnchainOperation],
+    tokens: &TokenSymbols,
+) -> Result<Vec<OperationRecord>, anyhow::Error> {
+    ensure!(
+        public_data.len() % CHUNK_BYTES == 0,
+        "Public data length {} is not a multiple of the chunk size",
+        public_data.len()
+    );
+
+    let witnesses: HashMap<_, _> = onchain_operations
+        .iter()
+        .map(|op| (op.public_data_offset, &op.eth_witness))
+        .collect();
+
+    let mut records = Vec::new();
+    let mut matched_witnesses = 0;
+    let mut offset = 0;
+    while offset < public_data.len() {
+        let op_type = public_data[offset];
+        let op_length = ZkSyncOp::public_data_length(op_type)?;
+        ensure!(
+            offset + op_length <= public_data.len(),
+            "Not enough bytes in the public data for the operation at offset {}",
+            offset
+        );
+        let op = ZkSyncOp::from_public_data(&public_data[offset..offset + op_length])
+            .map_err(|err| format_err!("Can't parse operation at offset {}: {}", offset, err))?;
+
+        if !matches!(op, ZkSyncOp::Noop(_)) {
+            let eth_witness = witnesses.get(&offset).map(|witness| {
+                matched_witnesses += 1;
+                hex::encode(witness)
+            });
+            let details = OperationDetails::new(&op);
+
+            records.push(OperationRecord {
+                block_number,
+                index: records.len(),
+                chunk_offset: offset / CHUNK_BYTES,
+                byte_offset: offset,
+                chunks: op_length / CHUNK_BYTES,
+                op_type: details.op_type,
+                account_id: details.account_id,
+                token: details.token,
+                token_symbol: details
+                    .token
+                    .and_then(|token| tokens.get(token))
+                    .map(str::to_string),
+                amount: details.amount,
+                fee: details.fee,
+                eth_witness,
+                op,
+            });
+        }
+        offset += op_length;
+    }
+
+    ensure!(
+        matched_witnesses == witnesses.len(),
+        "Some of the onchain operations offsets don't point to the operations start"
+    );
+
+    Ok(records)
+}
+
+/// Common fields extracted from the operation.
+struct OperationDetails {
+    op_type: &'static str,
+    account_id: Option<AccountId>,
+    token: Option<TokenId>,
+    amount: Option<String>,
+    fee: Option<String>,
+}
+
+impl OperationDetails {
+    fn new(op: &ZkSyncOp) -> Self {
+        let (op_type, account_id, token, amount, fee) = match op {
+            ZkSyncOp::Deposit(op) => (
+                "Deposit",
+                Some(op.account_id),
+                Some(op.priority_op.token),
+                Some(op.priority_op.amount.to_string()),
+                None,
+            ),
+            ZkSyncOp::Transfer(op) => (
+                "Transfer",
+                Some(op.from),
+                Some(op.tx.token),
+                Some(op.tx.amount.to_string()),
+                Some(op.tx.fee.to_string()),
+            ),
+            ZkSyncOp::TransferToNew(op) => (
+                "TransferToNew",
+                Some(op.from),
+                Some(op.tx.token),
+                Some(op.tx.amount.to_string()),
+                Some(op.tx.fee.to_string()),
+            ),
+            ZkSyncOp::Withdraw(op) => (
+                "Withdraw",
+                Some(op.account_id),
+                Some(op.tx.token),
+                Some(op.tx.amount.to_string()),
+                Some(op.tx.fee.to_string()),
+            ),
+            ZkSyncOp::Close(op) => ("Close", Some(op.account_id), None, None, None),
+            ZkSyncOp::FullExit(op) => (
+                "FullExit",
+                Some(op.priority_op.account_id),
+                Some(op.priority_op.token),
+                op.withdraw_amount
+                    .as_ref()
+                    .map(|amount| amount.0.to_string()),
+                None,
+            ),
+            ZkSyncOp::ChangePubKeyOffchain(op) => (
+                "ChangePubKey",
+                Some(op.account_id),
+                Some(op.tx.fee_token),
+                None,
+                Some(op.tx.fee.to_string()),
+            ),
+            ZkSyncOp::ForcedExit(op) => (
+                "ForcedExit",
+                Some(op.target_account_id),
+                Some(op.tx.token),
+                op.withdraw_amount
+                    .as_ref()
+                    .map(|amount| amount.0.to_string()),
+                Some(op.tx.fee.to_string()),
+            ),
+            ZkSyncOp::Noop(_) => ("Noop", None, None, None, None),
+        };
+
+        Self {
+            op_type,
+            account_id,
+            token,
+            amount,
+            fee,
+        }
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use zksync_types::{Deposit, DepositOp, Withdraw, WithdrawOp};
+
+    fn block_public_data() -> Vec<u8> {
+        let deposit = ZkSyncOp::Deposit(Box::new(DepositOp {
+            priority_op: Deposit {
+                from: [1u8; 20].into(),
+                token: 1,
+                amount: 10u32.into(),
+                to: [2u8; 20].into(),
+            },
+            account_id: 6,
+        }));
+        let withdraw = ZkSyncOp::Withdraw(Box::new(WithdrawOp {
+            tx: Withdraw::new(
+                3,
+                [3u8; 20].into(),
+                [4u8; 20].into(),
+                0,
+                20u32.into(),
+                1u32.into(),
+                2,
+                None,
+            ),
+            account_id: 3,
+        }));
+
+        let mut public_data = deposit.public_data();
+        public_data.extend(withdraw.public_data());
+        // Pad the block with noops.
+        public_data.extend(vec![0u8; CHUNK_BYTES * 2]);
+        public_data
+    }
+
+    /// Checks that operations are decoded with correct offsets, token symbols and witnesses.
+    #[test]
+    fn public_data_inspection() {
+        let public_data = block_public_data();
+        let withdraw_offset = ZkSyncOp::public_data_length(DepositOp::OP_CODE).unwrap();
+        let onchain_ops = vec![OnchainOperation {
+            public_data_offset: withdraw_offset,
+            eth_witness: vec![0xab],
+        }];
+
+        let records = inspect_public_data(
+            Some(5),
+            &public_data,
+            &onchain_ops,
+            &TokenSymbols::default(),
+        )
+        .unwrap();
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0].op_type, "Deposit");
+        assert_eq!(records[0].chunk_offset, 0);
+        assert_eq!(records[0].account_id, Some(6));
+        assert_eq!(records[0].token_symbol, None);
+        assert_eq!(records[0].eth_witness, None);
+
+        assert_eq!(records[1].op_type, "Withdraw");
+        assert_eq!(records[1].index, 1);
+        assert_eq!(records[1].chunk_offset, DepositOp::CHUNKS);
+        assert_eq!(records[1].token_symbol.as_deref(), Some("ETH"));
+        assert_eq!(records[1].amount.as_deref(), Some("20"));
+        assert_eq!(records[1].eth_witness.as_deref(), Some("ab"));
+        assert_eq!(
+            records[1].to_csv_row(),
+            format!(
+                "5,1,{},{},{},Withdraw,3,0,ETH,20,1,ab",
+                DepositOp::CHUNKS,
+                withdraw_offset,
+                WithdrawOp::CHUNKS
+            )
+        );
+
+        // Witness that doesn't point to the operation start is an error.
+        let wrong_onchain_ops = vec![OnchainOperation {
+            public_data_offset: withdraw_offset + 1,
+            eth_witness: vec![0xab],
+        }];
+        assert!(inspect_public_data(
+            None,
+            &public_data,
+            &wrong_onchain_ops,
+            &TokenSymbols::default()
+        )
+        .is_err());
+    }
+}
@@ -1,22 +1,208 @@
-use zksync_types::ZkSyncOp;
-
-fn main() {
-    let hex_data = std::env::args()
-        .nth(1)
-        .expect("cli arg should be hex of pubdata");
-    let data = hex::decode(&hex_data).expect("failed to decode hex");
-
-    let mut unparsed_data = data.as_slice();
-    while !unparsed_data.is_empty() {
-        let op_type = unparsed_data[0];
-        let op_data_len = ZkSyncOp::public_data_length(op_type).expect("wrong op type");
-        assert!(
-            data.len() > op_data_len,
-            "not enough bytes in the pubdata for current op"
-        );
-        let (current_op, unparsed) = unparsed_data.split_at(op_data_len);
-        let op = ZkSyncOp::from_public_data(&current_op).expect("failed to parse pubdata");
-        println!("{:#?}", op);
-        unparsed_data = unparsed;
+//! Tool to inspect the data posted on Ethereum by the block commitments.
+//!
+//! Data can be provided as a raw block public data, as a file with either the public data
+//! or the `commitBlocks` calldata, or fetched from Ethereum by the commitment transaction
+//! hash or the block number. Decoded operations are printed as JSON or CSV.
+
+use std::str::FromStr;
+
+use anyhow::format_err;
+use structopt::StructOpt;
+use web3::{
+    transports::Http,
+    types::{Address, BlockNumber as EthBlockNumber, FilterBuilder, TransactionId, H256},
+    Web3,
+};
+use zksync_contracts::zksync_contract;
+use zksync_types::BlockNumber;
+
+use crate::{
+    commitment::{decode_commit_calldata, is_commit_calldata},
+    inspector::{inspect_public_data, OperationRecord, TokenSymbols},
+};
+
+mod commitment;
+mod inspector;
+
+#[derive(Debug, Clone, Copy)]
+enum OutputFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format_err!("Unknown output format: {}", s)),
+        }
+    }
+}
+
+#[derive(StructOpt)]
+#[structopt(
+    name = "Pubdata inspector",
+    author = "Matter Labs",
+    rename_all = "snake_case"
+)]
+struct Opt {
+    /// Hex-encoded block public data
+    #[structopt(required_unless_one = &["file", "tx", "block"])]
+    pubdata: Option<String>,
+
+    /// File with the hex-encoded block public data or `commitBlocks` calldata
+    #[structopt(long, conflicts_with_all = &["pubdata", "tx", "block"])]
+    file: Option<String>,
+
+    /// Hash of the `commitBlocks` transaction to fetch from Ethereum
+    #[structopt(long, conflicts_with_all = &["pubdata", "block"])]
+    tx: Option<H256>,
+
+    /// Number of the block to find the commitment for on Ethereum
+    #[structopt(long, conflicts_with = "pubdata")]
+    block: Option<BlockNumber>,
+
+    /// Web3 API url, `WEB3_URL` environment variable is used if not set
+    #[structopt(long)]
+    web3: Option<String>,
+
+    /// zkSync contract address, `CONTRACT_ADDR` environment variable is used if not set
+    #[structopt(long)]
+    contract: Option<Address>,
+
+    /// JSON file with tokens used to resolve token symbols
+    #[structopt(long)]
+    tokens: Option<String>,
+
+    /// Output format: `json` or `csv`
+    #[structopt(long, default_value = "json")]
+    format: OutputFormat,
+}
+
+impl Opt {
+    fn web3(&self) -> anyhow::Result<Web3<Http>> {
+        let url = match &self.web3 {
+            Some(url) => url.clone(),
+            None => std::env::var("WEB3_URL")
+                .map_err(|_| format_err!("Neither `--web3` nor `WEB3_URL` is set"))?,
+        };
+        Ok(Web3::new(Http::new(&url)?))
+    }
+
+    fn contract_address(&self) -> anyhow::Result<Address> {
+        match self.contract {
+            Some(address) => Ok(address),
+            None => std::env::var("CONTRACT_ADDR")
+                .map_err(|_| format_err!("Neither `--contract` nor `CONTRACT_ADDR` is set"))?
+                .trim_start_matches("0x")
+                .parse()
+                .map_err(|err| format_err!("Invalid `CONTRACT_ADDR`: {}", err)),
+        }
+    }
+}
+
+/// Finds the hash of the last transaction that committed the block.
+async fn find_commit_tx(
+    web3: &Web3<Http>,
+    contract: &ethabi::Contract,
+    contract_address: Address,
+    block: BlockNumber,
+) -> anyhow::Result<H256> {
+    let block_commit_topic = contract.event("BlockCommit")?.signature();
+    let filter = FilterBuilder::default()
+        .address(vec![contract_address])
+        .from_block(EthBlockNumber::Earliest)
+        .to_block(EthBlockNumber::Latest)
+        .topics(
+            Some(vec![block_commit_topic]),
+            Some(vec![H256::from_low_u64_be(block as u64)]),
+            None,
+            None,
+        )
+        .build();
+
+    // Block could be reverted and committed again, the last commitment is the actual one.
+    web3.eth()
+        .logs(filter)
+        .await?
+        .into_iter()
+        .filter_map(|log| log.transaction_hash)
+        .last()
+        .ok_or_else(|| format_err!("Block #{} is not committed", block))
+}
+
+async fn fetch_calldata(web3: &Web3<Http>, tx_hash: H256) -> anyhow::Result<Vec<u8>> {
+    let tx = web3
+        .eth()
+        .transaction(TransactionId::Hash(tx_hash))
+        .await?
+        .ok_or_else(|| format_err!("Transaction {:?} is not found", tx_hash))?;
+    Ok(tx.input.0)
+}
+
+fn decode_hex(data: &str) -> anyhow::Result<Vec<u8>> {
+    let data = data.trim();
+    Ok(hex::decode(data.strip_prefix("0x").unwrap_or(data))?)
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let opt = Opt::from_args();
+    let contract = zksync_contract();
+    let tokens = match &opt.tokens {
+        Some(path) => TokenSymbols::load_from_file(path)?,
+        None => TokenSymbols::default(),
+    };
+
+    let data = if let Some(pubdata) = &opt.pubdata {
+        decode_hex(pubdata)?
+    } else if let Some(path) = &opt.file {
+        decode_hex(&std::fs::read_to_string(path)?)?
+    } else {
+        let web3 = opt.web3()?;
+        let tx_hash = match opt.tx {
+            Some(tx_hash) => tx_hash,
+            None => {
+                let block = opt.block.expect("Either tx or block is required");
+                find_commit_tx(&web3, &contract, opt.contract_address()?, block).await?
+            }
+        };
+        fetch_calldata(&web3, tx_hash).await?
+    };
+
+    let records = if is_commit_calldata(&contract, &data) {
+        let mut records = Vec::new();
+        for block in decode_commit_calldata(&contract, &data)? {
+            if opt
+                .block
+                .map_or(false, |number| number != block.block_number)
+            {
+                continue;
+            }
+            records.extend(inspect_public_data(
+                Some(block.block_number),
+                &block.public_data,
+                &block.onchain_operations,
+                &tokens,
+            )?);
+        }
+        records
+    } else {
+        inspect_public_data(None, &data, &[], &tokens)?
+    };
+
+    match opt.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&records)?),
+        OutputFormat::Csv => {
+            println!("{}", OperationRecord::CSV_HEADER);
+            for record in &records {
+                println!("{}", record.to_csv_row());
+            }
+        }
     }
+
+    Ok(())
 }
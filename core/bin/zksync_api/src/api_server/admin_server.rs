@@ -10,15 +10,22 @@ use actix_web_httpauth::extractors::{
     AuthenticationError,
 };
 use actix_web_httpauth::middleware::HttpAuthentication;
+use chrono::{DateTime, Utc};
 use futures::channel::mpsc;
 use jsonwebtoken::errors::Error as JwtError;
 use jsonwebtoken::{decode, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 
 // Local uses
-use zksync_types::{tokens, Address, TokenId};
+use zksync_storage::webhooks::records::StoredWebhook;
+use zksync_types::{tokens, ActionType, Address, BlockNumber, TokenId, TokenLike};
 use zksync_utils::panic_notify::ThreadPanicNotify;
 
+use super::event_notify::WebhookEvent;
+
+/// Maximum number of the latest webhook deliveries returned by the server.
+const WEBHOOK_DELIVERIES_LIMIT: i64 = 100;
+
 #[derive(Debug, Serialize, Deserialize)]
 struct PayloadAuthToken {
     /// Subject (whom auth token refers to).
@@ -56,6 +63,69 @@ struct AddTokenRequest {
     pub decimals: u8,
 }
 
+/// Request to revert the blocks that were not executed yet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct RevertBlocksRequest {
+    /// Number of the last block to keep, all the blocks after it will be reverted.
+    pub last_block: BlockNumber,
+    /// If set, the changes are only reported, but not applied.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Request to update the token metadata. Fields that are not set are left unchanged.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct UpdateTokenMetadataRequest {
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+/// Request to update the token status. Fields that are not set are left unchanged.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct UpdateTokenStatusRequest {
+    /// If set, the token is not accepted to pay fees in.
+    pub deprecated_for_fees: Option<bool>,
+    /// If set, new transfers in the token are not accepted.
+    pub paused: Option<bool>,
+}
+
+/// Request to register a webhook notified about the block events.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct AddWebhookRequest {
+    /// URL the notifications are sent to with `POST` requests.
+    pub url: String,
+    /// Secret used to sign the notifications.
+    pub secret: String,
+    pub event: WebhookEvent,
+    pub action: ActionType,
+}
+
+/// Registered webhook. Webhook secret is not exposed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct WebhookInfo {
+    pub id: i64,
+    pub url: String,
+    pub event: WebhookEvent,
+    pub action: ActionType,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookInfo {
+    fn from_stored(webhook: StoredWebhook) -> anyhow::Result<Self> {
+        Ok(Self {
+            id: webhook.id,
+            url: webhook.url,
+            event: WebhookEvent::from_stored(&webhook.event_type, &webhook.event_id)?,
+            action: webhook
+                .action_type
+                .parse()
+                .map_err(|e| anyhow::format_err!("{}", e))?,
+            created_at: webhook.created_at,
+        })
+    }
+}
+
 struct AuthTokenValidator<'a> {
     decoding_key: DecodingKey<'a>,
 }
@@ -125,6 +195,260 @@ async fn add_token(
     Ok(HttpResponse::Ok().json(token))
 }
 
+/// Updates the token symbol and/or decimals.
+///
+/// Note that API servers cache tokens, so the change may take up to a minute to propagate.
+async fn update_token_metadata(
+    data: web::Data<AppState>,
+    token_id: web::Path<TokenId>,
+    request: web::Json<UpdateTokenMetadataRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+    let request = request.into_inner();
+
+    if let Some(symbol) = &request.symbol {
+        let existing_token = storage
+            .tokens_schema()
+            .get_token(TokenLike::Symbol(symbol.clone()))
+            .await
+            .map_err(|e| {
+                vlog::warn!("failed to load token: {}", e);
+                actix_web::error::ErrorInternalServerError("storage layer error")
+            })?;
+        if existing_token.map_or(false, |token| token.id != *token_id) {
+            return Err(actix_web::error::ErrorBadRequest(format!(
+                "Token with symbol {} already exists",
+                symbol
+            )));
+        }
+    }
+
+    let token = storage
+        .tokens_schema()
+        .update_token_metadata(*token_id, request.symbol, request.decimals)
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to update token metadata: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?
+        .ok_or_else(|| actix_web::error::ErrorNotFound("token not found"))?;
+
+    Ok(HttpResponse::Ok().json(token))
+}
+
+/// Updates the token status, i.e. whether the token can be used to pay fees and
+/// whether new transfers in the token are accepted.
+async fn update_token_status(
+    data: web::Data<AppState>,
+    token_id: web::Path<TokenId>,
+    request: web::Json<UpdateTokenStatusRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+    let mut transaction = storage.start_transaction().await.map_err(|e| {
+        vlog::warn!("failed to start database transaction: {}", e);
+        actix_web::error::ErrorInternalServerError("storage layer error")
+    })?;
+
+    let storage_error = |e: anyhow::Error| {
+        vlog::warn!("failed to update token status: {}", e);
+        actix_web::error::ErrorInternalServerError("storage layer error")
+    };
+
+    transaction
+        .tokens_schema()
+        .get_token(TokenLike::Id(*token_id))
+        .await
+        .map_err(storage_error)?
+        .ok_or_else(|| actix_web::error::ErrorNotFound("token not found"))?;
+
+    let mut status = transaction
+        .tokens_schema()
+        .get_token_status(*token_id)
+        .await
+        .map_err(storage_error)?;
+    if let Some(deprecated_for_fees) = request.deprecated_for_fees {
+        status.deprecated_for_fees = deprecated_for_fees;
+    }
+    if let Some(paused) = request.paused {
+        status.paused = paused;
+    }
+    transaction
+        .tokens_schema()
+        .update_token_status(*token_id, status)
+        .await
+        .map_err(storage_error)?;
+    transaction.commit().await.map_err(storage_error)?;
+
+    Ok(HttpResponse::Ok().json(status))
+}
+
+/// Returns the audit log of the token metadata and status changes.
+async fn token_changes(
+    data: web::Data<AppState>,
+    token_id: web::Path<TokenId>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+
+    let changes = storage
+        .tokens_schema()
+        .load_token_changes(*token_id)
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to load token changes: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+
+    Ok(HttpResponse::Ok().json(changes))
+}
+
+/// Reverts the blocks after the requested one and returns the summary of the removed data.
+///
+/// Note that the server must be restarted after the revert, since the state keeper
+/// and `eth_sender` keep the chain state in memory.
+async fn revert_blocks(
+    data: web::Data<AppState>,
+    revert_request: web::Json<RevertBlocksRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+    let mut transaction = storage.start_transaction().await.map_err(|e| {
+        vlog::warn!("failed to start database transaction: {}", e);
+        actix_web::error::ErrorInternalServerError("storage layer error")
+    })?;
+
+    let summary = transaction
+        .chain()
+        .block_schema()
+        .revert_blocks(revert_request.last_block)
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to revert blocks: {}", e);
+            actix_web::error::ErrorBadRequest(e.to_string())
+        })?;
+
+    if !revert_request.dry_run {
+        transaction.commit().await.map_err(|e| {
+            vlog::warn!("failed to commit blocks revert: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+        log::info!(
+            "Blocks after #{} are reverted, the server must be restarted",
+            revert_request.last_block
+        );
+    }
+
+    Ok(HttpResponse::Ok().json(summary))
+}
+
+/// Registers a webhook, which will be notified about the requested event.
+async fn add_webhook(
+    data: web::Data<AppState>,
+    request: web::Json<AddWebhookRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let request = request.into_inner();
+    let url = request
+        .url
+        .parse::<reqwest::Url>()
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid webhook url: {}", e)))?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(actix_web::error::ErrorBadRequest(
+            "Webhook url must be either http or https",
+        ));
+    }
+    if request.secret.is_empty() {
+        return Err(actix_web::error::ErrorBadRequest(
+            "Webhook secret must not be empty",
+        ));
+    }
+
+    let mut storage = data.access_storage().await?;
+    let webhook = storage
+        .webhooks_schema()
+        .store_webhook(
+            url.as_str(),
+            &request.secret,
+            request.event.event_type(),
+            &request.event.event_id(),
+            request.action,
+        )
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to store webhook: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+
+    Ok(HttpResponse::Ok().json(WebhookInfo {
+        id: webhook.id,
+        url: webhook.url,
+        event: request.event,
+        action: request.action,
+        created_at: webhook.created_at,
+    }))
+}
+
+/// Returns all the registered webhooks.
+async fn webhooks(data: web::Data<AppState>) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+
+    let webhooks = storage
+        .webhooks_schema()
+        .load_webhooks()
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to load webhooks: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?
+        .into_iter()
+        .map(WebhookInfo::from_stored)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            vlog::warn!("failed to decode stored webhook: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+
+    Ok(HttpResponse::Ok().json(webhooks))
+}
+
+/// Removes the webhook together with its pending notifications.
+async fn remove_webhook(
+    data: web::Data<AppState>,
+    webhook_id: web::Path<i64>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+
+    let removed = storage
+        .webhooks_schema()
+        .remove_webhook(*webhook_id)
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to remove webhook: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+    if !removed {
+        return Err(actix_web::error::ErrorNotFound("webhook not found"));
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Returns the latest deliveries of the webhook notifications with their state.
+async fn webhook_deliveries(
+    data: web::Data<AppState>,
+    webhook_id: web::Path<i64>,
+) -> actix_web::Result<HttpResponse> {
+    let mut storage = data.access_storage().await?;
+
+    let deliveries = storage
+        .webhooks_schema()
+        .load_deliveries(*webhook_id, WEBHOOK_DELIVERIES_LIMIT)
+        .await
+        .map_err(|e| {
+            vlog::warn!("failed to load webhook deliveries: {}", e);
+            actix_web::error::ErrorInternalServerError("storage layer error")
+        })?;
+
+    Ok(HttpResponse::Ok().json(deliveries))
+}
+
 async fn run_server(app_state: AppState, bind_to: SocketAddr) {
     HttpServer::new(move || {
         let auth = HttpAuthentication::bearer(move |req, credentials| async {
@@ -142,6 +466,20 @@ async fn run_server(app_state: AppState, bind_to: SocketAddr) {
             .wrap(auth)
             .data(app_state.clone())
             .route("/tokens", web::post().to(add_token))
+            .route(
+                "/tokens/{id}/metadata",
+                web::post().to(update_token_metadata),
+            )
+            .route("/tokens/{id}/status", web::post().to(update_token_status))
+            .route("/tokens/{id}/changes", web::get().to(token_changes))
+            .route("/blocks/revert", web::post().to(revert_blocks))
+            .route("/webhooks", web::post().to(add_webhook))
+            .route("/webhooks", web::get().to(webhooks))
+            .route("/webhooks/{id}", web::delete().to(remove_webhook))
+            .route(
+                "/webhooks/{id}/deliveries",
+                web::get().to(webhook_deliveries),
+            )
     })
     .workers(1)
     .bind(&bind_to)
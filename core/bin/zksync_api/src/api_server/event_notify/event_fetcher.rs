@@ -24,7 +24,8 @@ macro_rules! await_db {
 /// whether new blocks were committed or verified.
 ///
 /// Once tha new data is available, it is sent to the `OperationNotifier`, which broadcasts it
-/// to the subscribers.
+/// to the subscribers. New blocks are also passed to the `WebhookNotifier`, which stores
+/// the notifications for the registered webhooks.
 #[derive(Debug)]
 pub struct EventFetcher {
     miniblock_interval: Duration,
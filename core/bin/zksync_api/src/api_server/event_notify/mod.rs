@@ -10,12 +10,19 @@ use zksync_types::tx::TxHash;
 use zksync_types::BlockNumber;
 use zksync_types::{block::ExecutedOperations, ActionType, Address};
 
-use self::{event_fetcher::EventFetcher, operation_notifier::OperationNotifier};
+use self::{
+    event_fetcher::EventFetcher, operation_notifier::OperationNotifier,
+    webhook_notifier::WebhookNotifier, webhook_sender::WebhookSender,
+};
+
+pub use self::webhook_notifier::WebhookEvent;
 
 mod event_fetcher;
 mod operation_notifier;
 mod state;
 mod sub_store;
+mod webhook_notifier;
+mod webhook_sender;
 
 const NOTIFIER_CHANNEL_CAPACITY: usize = 32_768;
 
@@ -59,11 +66,19 @@ pub fn start_sub_notifier(
     mut subscription_stream: mpsc::Receiver<EventNotifierRequest>,
     api_requests_caches_size: usize,
     miniblock_interval: Duration,
+    webhook_retry_interval: Duration,
+    webhook_max_attempts: u32,
 ) -> tokio::task::JoinHandle<()> {
     let (new_block_sender, mut new_block_receiver) = mpsc::channel(NOTIFIER_CHANNEL_CAPACITY);
     let (new_txs_sender, mut new_txs_receiver) = mpsc::channel(NOTIFIER_CHANNEL_CAPACITY);
 
     let mut notifier = OperationNotifier::new(api_requests_caches_size, db_pool.clone());
+    let mut webhook_notifier = WebhookNotifier::new(api_requests_caches_size, db_pool.clone());
+    let webhook_sender = WebhookSender::new(
+        db_pool.clone(),
+        webhook_retry_interval,
+        webhook_max_attempts,
+    );
 
     tokio::spawn(async move {
         let fetcher = EventFetcher::new(
@@ -76,11 +91,16 @@ pub fn start_sub_notifier(
         .expect("Unable to create event fetcher");
 
         tokio::spawn(fetcher.run());
+        tokio::spawn(webhook_sender.run());
 
         loop {
             select! {
                 new_block = new_block_receiver.next() => {
                     if let Some(new_block) = new_block {
+                        webhook_notifier.handle_new_block(&new_block)
+                            .await
+                            .map_err(|e| log::warn!("Failed to store webhook notifications: {}",e))
+                            .unwrap_or_default();
                         notifier.handle_new_block(new_block)
                             .await
                             .map_err(|e| log::warn!("Failed to handle new block: {}",e))
@@ -0,0 +1,291 @@
+//! Notifier which stores the webhook notifications about the block events.
+//!
+//! Unlike the subscriptions, webhooks are only notified about the operations included
+//! into the committed or verified blocks, and not about the ones executed in the pending block.
+//! Notifications are stored in the database and sent by the `WebhookSender`.
+
+use crate::api_server::rpc_server::types::{BlockInfo, ETHOpInfoResp, TransactionInfoResp};
+use anyhow::format_err;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::time::Instant;
+use zksync_storage::webhooks::records::{NewWebhookDelivery, StoredWebhook};
+use zksync_storage::ConnectionPool;
+use zksync_types::tx::TxHash;
+use zksync_types::{
+    block::ExecutedOperations, AccountId, ActionType, Address, BlockNumber, Operation,
+};
+
+use super::state::NotifierState;
+
+/// Event the webhook is notified about.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum WebhookEvent {
+    /// Account state is changed by the block.
+    Account { address: Address },
+    /// Transaction is included into the block.
+    Transaction { hash: TxHash },
+    /// Priority operation is included into the block.
+    #[serde(rename_all = "camelCase")]
+    PriorityOp { serial_id: u64 },
+}
+
+impl WebhookEvent {
+    /// Type of the event as it is stored in the database.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::Account { .. } => "account",
+            Self::Transaction { .. } => "transaction",
+            Self::PriorityOp { .. } => "priorityOp",
+        }
+    }
+
+    /// Identifier of the event entity as it is stored in the database.
+    pub fn event_id(&self) -> String {
+        match self {
+            Self::Account { address } => format!("{:?}", address),
+            Self::Transaction { hash } => hash.to_string(),
+            Self::PriorityOp { serial_id } => serial_id.to_string(),
+        }
+    }
+
+    pub fn from_stored(event_type: &str, event_id: &str) -> anyhow::Result<Self> {
+        let incorrect_id_err = || format_err!("Incorrect {} event id: {}", event_type, event_id);
+
+        match event_type {
+            "account" => Ok(Self::Account {
+                address: event_id
+                    .trim_start_matches("0x")
+                    .parse()
+                    .map_err(|_| incorrect_id_err())?,
+            }),
+            "transaction" => Ok(Self::Transaction {
+                hash: event_id.parse().map_err(|_| incorrect_id_err())?,
+            }),
+            "priorityOp" => Ok(Self::PriorityOp {
+                serial_id: event_id.parse().map_err(|_| incorrect_id_err())?,
+            }),
+            _ => Err(format_err!("Unknown event type: {}", event_type)),
+        }
+    }
+}
+
+/// Payload of the notification sent to the webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub webhook_id: i64,
+    pub event: WebhookEvent,
+    pub action: ActionType,
+    pub block_number: BlockNumber,
+    /// Event data, the same as the one sent to the corresponding subscription.
+    pub data: serde_json::Value,
+}
+
+/// Webhooks that should be notified about the block event, grouped by the event entity.
+#[derive(Debug, Default)]
+struct BlockWebhooks {
+    transactions: HashMap<TxHash, Vec<(i64, WebhookEvent)>>,
+    priority_ops: HashMap<u64, Vec<(i64, WebhookEvent)>>,
+    accounts: HashMap<AccountId, Vec<(i64, WebhookEvent)>>,
+}
+
+pub struct WebhookNotifier {
+    state: NotifierState,
+    /// IDs of the accounts watched by the webhooks. Account ID never changes once assigned.
+    account_ids: HashMap<Address, AccountId>,
+}
+
+impl WebhookNotifier {
+    pub fn new(cache_capacity: usize, db_pool: ConnectionPool) -> Self {
+        Self {
+            state: NotifierState::new(cache_capacity, db_pool),
+            account_ids: HashMap::new(),
+        }
+    }
+
+    /// Stores the notifications about the new block action (commit or verify)
+    /// for the webhooks watching the block operations and accounts.
+    pub async fn handle_new_block(&mut self, op: &Operation) -> Result<(), anyhow::Error> {
+        let start = Instant::now();
+        let action = op.action.get_type();
+        let block_number = op.block.block_number;
+
+        let webhooks = self.load_block_webhooks(action).await?;
+        let mut deliveries = Vec::new();
+        let new_delivery = |webhook_id, event, data: serde_json::Value| {
+            let payload = WebhookPayload {
+                webhook_id,
+                event,
+                action,
+                block_number,
+                data,
+            };
+            serde_json::to_value(payload).map(|payload| NewWebhookDelivery {
+                webhook_id,
+                block_number,
+                action_type: action,
+                payload,
+            })
+        };
+
+        let block_info = BlockInfo {
+            block_number: i64::from(block_number),
+            committed: true,
+            verified: action == ActionType::VERIFY,
+        };
+        for exec_op in &op.block.block_transactions {
+            match exec_op {
+                ExecutedOperations::Tx(tx) => {
+                    let hash = tx.signed_tx.hash();
+                    for (webhook_id, event) in
+                        webhooks.transactions.get(&hash).into_iter().flatten()
+                    {
+                        let resp = TransactionInfoResp {
+                            executed: true,
+                            success: Some(tx.success),
+                            fail_reason: tx.fail_reason.clone(),
+                            block: Some(block_info.clone()),
+                        };
+                        deliveries.push(new_delivery(
+                            *webhook_id,
+                            *event,
+                            serde_json::to_value(resp)?,
+                        )?);
+                    }
+                }
+                ExecutedOperations::PriorityOp(prior_op) => {
+                    let serial_id = prior_op.priority_op.serial_id;
+                    for (webhook_id, event) in
+                        webhooks.priority_ops.get(&serial_id).into_iter().flatten()
+                    {
+                        let resp = ETHOpInfoResp {
+                            executed: true,
+                            block: Some(block_info.clone()),
+                        };
+                        deliveries.push(new_delivery(
+                            *webhook_id,
+                            *event,
+                            serde_json::to_value(resp)?,
+                        )?);
+                    }
+                }
+            }
+        }
+
+        let updated_accounts: BTreeSet<AccountId> = op
+            .block
+            .block_transactions
+            .iter()
+            .map(|exec_op| exec_op.get_updated_account_ids())
+            .flatten()
+            .filter(|id| webhooks.accounts.contains_key(id))
+            .collect();
+        for id in updated_accounts {
+            let account_state = match self.state.get_account_state(id, action).await? {
+                Some(account_state) => account_state,
+                None => {
+                    log::warn!(
+                        "Account is updated but not stored in DB, id: {}, block: {}",
+                        id,
+                        block_number
+                    );
+                    continue;
+                }
+            };
+            let data = serde_json::to_value(account_state)?;
+            for (webhook_id, event) in &webhooks.accounts[&id] {
+                deliveries.push(new_delivery(*webhook_id, *event, data.clone())?);
+            }
+        }
+
+        if !deliveries.is_empty() {
+            let mut storage = self.state.db_pool.access_storage().await?;
+            storage
+                .webhooks_schema()
+                .store_deliveries(deliveries)
+                .await?;
+        }
+
+        metrics::histogram!("api.webhook_notifier.handle_new_block", start.elapsed());
+        Ok(())
+    }
+
+    async fn load_block_webhooks(&mut self, action: ActionType) -> anyhow::Result<BlockWebhooks> {
+        let stored_webhooks = self
+            .state
+            .db_pool
+            .access_storage()
+            .await?
+            .webhooks_schema()
+            .load_webhooks_for_action(action)
+            .await?;
+
+        let mut webhooks = BlockWebhooks::default();
+        for StoredWebhook {
+            id,
+            event_type,
+            event_id,
+            ..
+        } in stored_webhooks
+        {
+            let event = match WebhookEvent::from_stored(&event_type, &event_id) {
+                Ok(event) => event,
+                Err(err) => {
+                    log::warn!("Skipping malformed webhook #{}: {}", id, err);
+                    continue;
+                }
+            };
+
+            match event {
+                WebhookEvent::Transaction { hash } => {
+                    webhooks
+                        .transactions
+                        .entry(hash)
+                        .or_default()
+                        .push((id, event));
+                }
+                WebhookEvent::PriorityOp { serial_id } => {
+                    webhooks
+                        .priority_ops
+                        .entry(serial_id)
+                        .or_default()
+                        .push((id, event));
+                }
+                WebhookEvent::Account { address } => {
+                    // Account that doesn't exist yet can't be updated by the block.
+                    if let Some(account_id) = self.account_id(address).await? {
+                        webhooks
+                            .accounts
+                            .entry(account_id)
+                            .or_default()
+                            .push((id, event));
+                    }
+                }
+            }
+        }
+
+        Ok(webhooks)
+    }
+
+    async fn account_id(&mut self, address: Address) -> anyhow::Result<Option<AccountId>> {
+        if let Some(account_id) = self.account_ids.get(&address) {
+            return Ok(Some(*account_id));
+        }
+
+        let account_id = self
+            .state
+            .db_pool
+            .access_storage()
+            .await?
+            .chain()
+            .account_schema()
+            .account_id_by_address(address)
+            .await?;
+        if let Some(account_id) = account_id {
+            self.account_ids.insert(address, account_id);
+        }
+        Ok(account_id)
+    }
+}
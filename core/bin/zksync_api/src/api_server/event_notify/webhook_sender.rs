@@ -0,0 +1,169 @@
+//! Sender of the webhook notifications stored by the `WebhookNotifier`.
+//!
+//! Each notification is sent as a JSON `POST` request signed with the webhook secret:
+//! the `X-ZkSync-Signature` header contains the hex-encoded HMAC-SHA256 of the request body.
+//! Failed deliveries are retried with an exponential backoff until the attempts limit is reached.
+
+use anyhow::ensure;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use hmac::{Hmac, Mac, NewMac};
+use reqwest::header::CONTENT_TYPE;
+use sha2::Sha256;
+use std::time::{Duration, Instant};
+use zksync_storage::webhooks::records::PendingWebhookDelivery;
+use zksync_storage::ConnectionPool;
+
+/// Header with the hex-encoded HMAC-SHA256 signature of the request body.
+pub const SIGNATURE_HEADER: &str = "X-ZkSync-Signature";
+/// Header with the delivery ID, which is the same for all the attempts of the delivery.
+pub const DELIVERY_ID_HEADER: &str = "X-ZkSync-Delivery";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const DELIVERIES_PER_ITERATION: i64 = 100;
+/// Limits the backoff growth, so the interval between attempts is at most `2^10` retry intervals.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// Returns the hex-encoded HMAC-SHA256 signature of the webhook request body.
+pub fn payload_signature(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_varkey(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[derive(Debug)]
+pub struct WebhookSender {
+    db_pool: ConnectionPool,
+    client: reqwest::Client,
+    retry_interval: Duration,
+    max_attempts: u32,
+}
+
+impl WebhookSender {
+    pub fn new(db_pool: ConnectionPool, retry_interval: Duration, max_attempts: u32) -> Self {
+        let client = reqwest::ClientBuilder::new()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build reqwest::Client");
+
+        Self {
+            db_pool,
+            client,
+            retry_interval,
+            max_attempts,
+        }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            self.send_pending_deliveries()
+                .await
+                .map_err(|e| log::warn!("Failed to send webhook notifications: {}", e))
+                .unwrap_or_default();
+        }
+    }
+
+    async fn send_pending_deliveries(&self) -> anyhow::Result<()> {
+        let start = Instant::now();
+        let now = Utc::now();
+        // Claimed deliveries are retried if the results are not recorded within the lease time.
+        let lease_until = now + chrono::Duration::from_std(REQUEST_TIMEOUT * 2)?;
+        let deliveries = self
+            .db_pool
+            .access_storage()
+            .await?
+            .webhooks_schema()
+            .claim_pending_deliveries(DELIVERIES_PER_ITERATION, now, lease_until)
+            .await?;
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let results = join_all(deliveries.iter().map(|delivery| self.send(delivery))).await;
+
+        let mut storage = self.db_pool.access_storage().await?;
+        for (delivery, result) in deliveries.iter().zip(results) {
+            match result {
+                Ok(()) => {
+                    storage
+                        .webhooks_schema()
+                        .record_delivery_success(delivery.id)
+                        .await?;
+                }
+                Err(err) => {
+                    let next_attempt_at = self.next_attempt_at(delivery.attempts as u32 + 1);
+                    if next_attempt_at.is_none() {
+                        log::warn!(
+                            "Webhook delivery #{} to {} has failed: {}",
+                            delivery.id,
+                            delivery.url,
+                            err
+                        );
+                    }
+                    storage
+                        .webhooks_schema()
+                        .record_delivery_failure(delivery.id, &err.to_string(), next_attempt_at)
+                        .await?;
+                }
+            }
+        }
+
+        metrics::histogram!(
+            "api.webhook_sender.send_pending_deliveries",
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    async fn send(&self, delivery: &PendingWebhookDelivery) -> anyhow::Result<()> {
+        let body = serde_json::to_vec(&delivery.payload)?;
+        let response = self
+            .client
+            .post(&delivery.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, payload_signature(&delivery.secret, &body))
+            .header(DELIVERY_ID_HEADER, delivery.id.to_string())
+            .body(body)
+            .send()
+            .await?;
+
+        ensure!(
+            response.status().is_success(),
+            "Webhook responded with status {}",
+            response.status()
+        );
+        Ok(())
+    }
+
+    /// Returns the time of the next delivery attempt after the given number of failed ones,
+    /// or `None` if the delivery should not be retried anymore.
+    fn next_attempt_at(&self, failed_attempts: u32) -> Option<DateTime<Utc>> {
+        if failed_attempts >= self.max_attempts {
+            return None;
+        }
+
+        let backoff = 2u32.pow((failed_attempts - 1).min(MAX_BACKOFF_EXPONENT));
+        let delay = chrono::Duration::from_std(self.retry_interval * backoff).ok()?;
+        Some(Utc::now() + delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the signature against the HMAC-SHA256 test vector from RFC 4231.
+    #[test]
+    fn webhook_payload_signature() {
+        assert_eq!(
+            payload_signature("Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -5,13 +5,14 @@
 // External uses
 
 // Workspace uses
+use zksync_types::{AccountProof, BlockNumber, TokenLike};
 
 // Local uses
 use crate::api_server::v1::client::{Client, ClientError};
 
 use super::types::{
-    AccountInfo, AccountQuery, AccountReceipts, AccountReceiptsQuery, AccountTxReceipt,
-    PendingAccountTxReceipt,
+    AccountInfo, AccountProofQuery, AccountQuery, AccountReceipts, AccountReceiptsQuery,
+    AccountStateQuery, AccountTxReceipt, PendingAccountTxReceipt,
 };
 
 /// Accounts API part.
@@ -26,6 +27,20 @@ impl Client {
         self.get(&format!("accounts/{}", account)).send().await
     }
 
+    /// Gets account information at the time of the block.
+    pub async fn account_info_at_block(
+        &self,
+        account: impl Into<AccountQuery>,
+        block: BlockNumber,
+    ) -> Result<Option<AccountInfo>, ClientError> {
+        let account = account.into();
+
+        self.get(&format!("accounts/{}", account))
+            .query(&AccountStateQuery { block: Some(block) })
+            .send()
+            .await
+    }
+
     pub async fn account_receipts(
         &self,
         account: impl Into<AccountQuery>,
@@ -50,4 +65,23 @@ impl Client {
             .send()
             .await
     }
+
+    /// Gets the Merkle proof of the account token balance in the state of the verified block.
+    /// The last verified block is used if the block number is not specified.
+    pub async fn account_proof(
+        &self,
+        account: impl Into<AccountQuery>,
+        token: impl Into<TokenLike>,
+        block: Option<BlockNumber>,
+    ) -> Result<AccountProof, ClientError> {
+        let account = account.into();
+
+        self.get(&format!("accounts/{}/proof", account))
+            .query(&AccountProofQuery {
+                block,
+                token: Some(token.into().to_string()),
+            })
+            .send()
+            .await
+    }
 }
@@ -4,24 +4,30 @@
 pub use self::types::{AccountInfo, AccountState, DepositingBalances, DepositingFunds};
 
 // Built-in uses
+use std::sync::Arc;
 
 // External uses
 use actix_web::{
     web::{self, Json},
     Scope,
 };
+use tokio::sync::Mutex;
 
 // Workspace uses
 use zksync_config::ConfigurationOptions;
+use zksync_crypto::params::account_tree_depth;
 use zksync_storage::{QueryResult, StorageProcessor};
-use zksync_types::{AccountId, Address, BlockNumber, TokenId};
+use zksync_types::{
+    aggregated_operations::StoredBlockInfo, AccountId, AccountProof, AccountTree, Address,
+    BlockNumber, TokenId, TokenLike,
+};
 
 // Local uses
 use crate::{core_api_client::CoreApiClient, utils::token_db_cache::TokenDBCache};
 
 use self::types::{
-    AccountQuery, AccountReceiptsQuery, AccountTxReceipt, PendingAccountTxReceipt, SearchDirection,
-    TxLocation,
+    AccountProofQuery, AccountQuery, AccountReceiptsQuery, AccountStateQuery, AccountTxReceipt,
+    PendingAccountTxReceipt, SearchDirection, TxLocation,
 };
 use super::{ApiError, JsonResult};
 
@@ -42,12 +48,18 @@ fn parse_account_query(query: String) -> Result<AccountQuery, ApiError> {
     })
 }
 
+/// Account tree of the block used to build the account proofs. Building the tree is
+/// expensive, so the tree of the last requested block is kept. The lock is only held
+/// to get or replace the tree, and never while the tree is being built.
+type AccountTreeCache = Arc<Mutex<Option<(BlockNumber, Arc<AccountTree>)>>>;
+
 /// Shared data between `api/v1/accounts` endpoints.
 #[derive(Clone)]
 struct ApiAccountsData {
     tokens: TokenDBCache,
     core_api_client: CoreApiClient,
     confirmations_for_eth_event: BlockNumber,
+    account_tree: AccountTreeCache,
 }
 
 impl ApiAccountsData {
@@ -60,6 +72,7 @@ impl ApiAccountsData {
             tokens,
             core_api_client,
             confirmations_for_eth_event,
+            account_tree: AccountTreeCache::default(),
         }
     }
 
@@ -164,6 +177,185 @@ impl ApiAccountsData {
         Ok(Some(info))
     }
 
+    async fn account_proof(
+        &self,
+        query: AccountQuery,
+        token: TokenLike,
+        block: Option<BlockNumber>,
+    ) -> Result<AccountProof, ApiError> {
+        let token = self
+            .tokens
+            .get_token(token.clone())
+            .await
+            .map_err(ApiError::internal)?
+            .ok_or_else(|| {
+                ApiError::bad_request("Unable to find token.")
+                    .detail(format!("Given token {} is absent", token))
+            })?;
+
+        let mut storage = self.access_storage().await.map_err(ApiError::internal)?;
+        let account_id = Self::account_id(&mut storage, query)
+            .await
+            .map_err(ApiError::internal)?
+            .ok_or_else(|| {
+                ApiError::bad_request("Unable to find account.")
+                    .detail(format!("Given account {:?} is absent", query))
+            })?;
+
+        // Proofs are only provided for the verified blocks, since the state of the committed
+        // blocks can still be reverted.
+        let last_verified_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await
+            .map_err(ApiError::internal)?;
+        let block_number = block.unwrap_or(last_verified_block);
+        if block_number == 0 || block_number > last_verified_block {
+            return Err(
+                ApiError::bad_request("Block is not verified.").detail(format!(
+                    "Block {} is not verified, the last verified block is {}",
+                    block_number, last_verified_block
+                )),
+            );
+        }
+
+        let block = storage
+            .chain()
+            .block_schema()
+            .get_block(block_number)
+            .await
+            .map_err(ApiError::internal)?
+            .ok_or_else(|| {
+                ApiError::internal(format!("Verified block {} is absent", block_number))
+            })?;
+
+        let cached_tree = self
+            .account_tree
+            .lock()
+            .await
+            .as_ref()
+            .filter(|(number, _)| *number == block_number)
+            .map(|(_, tree)| tree.clone());
+        let tree = match cached_tree {
+            Some(tree) => tree,
+            None => {
+                let (_, accounts) = storage
+                    .chain()
+                    .state_schema()
+                    .load_committed_state(Some(block_number))
+                    .await
+                    .map_err(ApiError::internal)?;
+
+                let mut tree = AccountTree::new(account_tree_depth());
+                for (id, account) in accounts {
+                    tree.insert(id, account);
+                }
+                if tree.root_hash() != block.new_root_hash {
+                    return Err(ApiError::internal(format!(
+                        "Restored state root hash doesn't match the root hash of block {}",
+                        block_number
+                    )));
+                }
+
+                let tree = Arc::new(tree);
+                *self.account_tree.lock().await = Some((block_number, tree.clone()));
+                tree
+            }
+        };
+
+        Ok(AccountProof::new(
+            &tree,
+            account_id,
+            token.id,
+            StoredBlockInfo::new(&block),
+        ))
+    }
+
+    /// Returns the account information at the time of the block. Verified state is the state
+    /// at the same block if it's verified, or at the last verified block otherwise.
+    /// Depositing balances are not provided, since they only make sense for the latest state.
+    async fn account_info_at_block(
+        &self,
+        query: AccountQuery,
+        block: BlockNumber,
+    ) -> Result<Option<AccountInfo>, ApiError> {
+        let mut storage = self.access_storage().await.map_err(ApiError::internal)?;
+
+        let last_committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_block()
+            .await
+            .map_err(ApiError::internal)?;
+        if block > last_committed_block {
+            return Err(
+                ApiError::bad_request("Block is not committed.").detail(format!(
+                    "Block {} is not committed, the last committed block is {}",
+                    block, last_committed_block
+                )),
+            );
+        }
+
+        let account_id = match Self::account_id(&mut storage, query)
+            .await
+            .map_err(ApiError::internal)?
+        {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        let committed = storage
+            .chain()
+            .account_schema()
+            .account_state_at_block(account_id, block)
+            .await
+            .map_err(ApiError::internal)?;
+        let committed = match committed {
+            Some(account) => account,
+            // Account didn't exist at this block.
+            None => return Ok(None),
+        };
+
+        let last_verified_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await
+            .map_err(ApiError::internal)?;
+        let verified = if block <= last_verified_block {
+            Some(committed.clone())
+        } else {
+            storage
+                .chain()
+                .account_schema()
+                .account_state_at_block(account_id, last_verified_block)
+                .await
+                .map_err(ApiError::internal)?
+        };
+
+        // Drop storage access to avoid deadlocks.
+        // TODO Rewrite `TokensDBCache` logic to make such errors impossible. ZKS-169
+        drop(storage);
+
+        let info = AccountInfo {
+            address: committed.address,
+            id: account_id,
+            committed: AccountState::from_storage(&committed, &self.tokens)
+                .await
+                .map_err(ApiError::internal)?,
+            verified: match verified {
+                Some(account) => AccountState::from_storage(&account, &self.tokens)
+                    .await
+                    .map_err(ApiError::internal)?,
+                None => AccountState::default(),
+            },
+            depositing: DepositingBalances::default(),
+        };
+
+        Ok(Some(info))
+    }
+
     async fn tx_receipts(
         &self,
         address: Address,
@@ -211,9 +403,14 @@ impl ApiAccountsData {
 async fn account_info(
     data: web::Data<ApiAccountsData>,
     web::Path(query): web::Path<String>,
+    web::Query(state_query): web::Query<AccountStateQuery>,
 ) -> JsonResult<Option<AccountInfo>> {
     let query = parse_account_query(query)?;
 
+    if let Some(block) = state_query.block {
+        return data.account_info_at_block(query, block).await.map(Json);
+    }
+
     data.account_info(query)
         .await
         .map(Json)
@@ -250,6 +447,23 @@ async fn account_pending_receipts(
     Ok(Json(receipts))
 }
 
+async fn account_proof(
+    data: web::Data<ApiAccountsData>,
+    web::Path(account_query): web::Path<String>,
+    web::Query(proof_query): web::Query<AccountProofQuery>,
+) -> JsonResult<AccountProof> {
+    let query = parse_account_query(account_query)?;
+    let token = proof_query
+        .token
+        .as_deref()
+        .map(TokenLike::parse)
+        .unwrap_or(TokenLike::Id(0));
+
+    data.account_proof(query, token, proof_query.block)
+        .await
+        .map(Json)
+}
+
 pub fn api_scope(
     env_options: &ConfigurationOptions,
     tokens: TokenDBCache,
@@ -269,4 +483,5 @@ pub fn api_scope(
             "{id}/receipts/pending",
             web::get().to(account_pending_receipts),
         )
+        .route("{id}/proof", web::get().to(account_proof))
 }
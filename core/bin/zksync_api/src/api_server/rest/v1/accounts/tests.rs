@@ -19,7 +19,7 @@ use zksync_types::{tx::TxHash, Address, BlockNumber, H256};
 use crate::{
     api_server::v1::{
         client::{Client, TxReceipt},
-        test_utils::TestServerConfig,
+        test_utils::{TestServerConfig, COMMITTED_BLOCKS_COUNT},
     },
     core_api_client::CoreApiClient,
     utils::token_db_cache::TokenDBCache,
@@ -134,7 +134,18 @@ async fn accounts_scope() -> anyhow::Result<()> {
 
     let account_info = client.account_info(address).await?.unwrap();
     let id = account_info.id;
-    assert_eq!(client.account_info(id).await?, Some(account_info));
+    assert_eq!(client.account_info(id).await?, Some(account_info.clone()));
+
+    // Account state at the last committed block is the same as the latest one.
+    let account_info_at_block = client
+        .account_info_at_block(id, COMMITTED_BLOCKS_COUNT)
+        .await?
+        .unwrap();
+    assert_eq!(account_info_at_block.committed, account_info.committed);
+    assert!(client
+        .account_info_at_block(id, COMMITTED_BLOCKS_COUNT + 1)
+        .await
+        .is_err());
 
     // Provide unconfirmed deposits
     let deposits = json!([
@@ -143,6 +143,24 @@ pub struct AccountReceiptsQuery {
     pub limit: BlockNumber,
 }
 
+/// Account state query.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStateQuery {
+    /// Block to get the account state at, the latest state if not set.
+    pub block: Option<BlockNumber>,
+}
+
+/// Account proof query.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProofQuery {
+    /// Verified block to prove the account state for, the last verified block if not set.
+    pub block: Option<BlockNumber>,
+    /// Token to prove the balance of, `ETH` if not set.
+    pub token: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountTxReceipt {
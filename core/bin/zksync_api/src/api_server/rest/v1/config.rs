@@ -20,6 +20,7 @@ use super::{
 #[derive(Debug, Clone)]
 struct ApiConfigData {
     contract_address: Address,
+    gov_contract_address: Address,
     deposit_confirmations: u64,
     network: Network,
 }
@@ -28,6 +29,7 @@ impl ApiConfigData {
     fn new(env_options: &ConfigurationOptions) -> Self {
         Self {
             contract_address: env_options.contract_eth_addr,
+            gov_contract_address: env_options.governance_eth_addr,
             deposit_confirmations: env_options.confirmations_for_eth_event,
             network: env_options.eth_network.parse().unwrap(),
         }
@@ -39,6 +41,7 @@ impl ApiConfigData {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Contracts {
     pub contract: Address,
+    pub gov_contract: Address,
 }
 
 // Client implementation
@@ -63,6 +66,7 @@ impl Client {
 async fn contracts(data: web::Data<ApiConfigData>) -> Json<Contracts> {
     Json(Contracts {
         contract: data.contract_address,
+        gov_contract: data.gov_contract_address,
     })
 }
 
@@ -105,7 +109,8 @@ mod tests {
         assert_eq!(
             client.contracts().await?,
             Contracts {
-                contract: cfg.env_options.contract_eth_addr
+                contract: cfg.env_options.contract_eth_addr,
+                gov_contract: cfg.env_options.governance_eth_addr,
             },
         );
 
@@ -57,6 +57,10 @@ pub(crate) fn api_scope(
         .service(transactions::api_scope(tx_sender.clone()))
         .service(operations::api_scope(tx_sender.pool.clone()))
         .service(search::api_scope(tx_sender.pool.clone()))
+        .service(tokens::gas_price_scope(
+            tx_sender.tokens.clone(),
+            tx_sender.ticker_requests.clone(),
+        ))
         .service(tokens::api_scope(
             tx_sender.tokens,
             tx_sender.ticker_requests,
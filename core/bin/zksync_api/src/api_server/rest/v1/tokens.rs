@@ -8,6 +8,7 @@ use actix_web::{
     Scope,
 };
 use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 // Workspace uses
@@ -15,7 +16,7 @@ use futures::{
     channel::{mpsc, oneshot},
     prelude::*,
 };
-use zksync_storage::QueryResult;
+use zksync_storage::{tokens::records::DbPriceHistoryEntry, QueryResult};
 use zksync_types::{Token, TokenLike};
 
 // Local uses
@@ -28,6 +29,15 @@ use crate::{
     utils::token_db_cache::TokenDBCache,
 };
 
+/// Length of the price history time range if the start of the range is not specified.
+const DEFAULT_PRICE_HISTORY_DAYS: i64 = 7;
+/// Price history aggregation interval if it's not specified.
+const DEFAULT_PRICE_HISTORY_INTERVAL_SECS: u64 = 60 * 60;
+/// Minimal price history aggregation interval.
+const MIN_PRICE_HISTORY_INTERVAL_SECS: u64 = 60;
+/// Maximum number of intervals in the price history response.
+const MAX_PRICE_HISTORY_INTERVALS: u64 = 1000;
+
 /// Shared data between `api/v1/tokens` endpoints.
 #[derive(Clone)]
 struct ApiTokensData {
@@ -56,6 +66,42 @@ impl ApiTokensData {
         self.tokens.get_token(token_like).await
     }
 
+    async fn token_price_history(
+        &self,
+        token_like: TokenLike,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_secs: u64,
+    ) -> QueryResult<Option<Vec<PriceHistoryEntry>>> {
+        let token = match self.tokens.get_token(token_like).await? {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+
+        let mut storage = self.tokens.pool.access_storage().await?;
+        let history = storage
+            .tokens_schema()
+            .load_ticker_price_history(token.id, from, to, interval_secs)
+            .await?;
+
+        Ok(Some(history.into_iter().map(From::from).collect()))
+    }
+
+    async fn gas_price_history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_secs: u64,
+    ) -> QueryResult<Vec<PriceHistoryEntry>> {
+        let mut storage = self.tokens.pool.access_storage().await?;
+        let history = storage
+            .ethereum_schema()
+            .load_gas_price_history(from, to, interval_secs)
+            .await?;
+
+        Ok(history.into_iter().map(From::from).collect())
+    }
+
     async fn token_price_usd(&self, token: TokenLike) -> QueryResult<Option<BigDecimal>> {
         let (price_sender, price_receiver) = oneshot::channel();
         self.fee_ticker
@@ -97,6 +143,38 @@ struct TokenPriceQuery {
     kind: TokenPriceKind,
 }
 
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Default)]
+pub struct PriceHistoryQuery {
+    /// Start of the time range, a week before its end by default.
+    pub from: Option<DateTime<Utc>>,
+    /// End of the time range (exclusive), the current time by default.
+    pub to: Option<DateTime<Utc>>,
+    /// Aggregation interval in seconds, an hour by default.
+    pub interval: Option<u64>,
+}
+
+/// Prices aggregated for the interval starting at `timestamp`: token prices in USD,
+/// or gas prices in wei.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceHistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub min_price: BigDecimal,
+    pub avg_price: BigDecimal,
+    pub max_price: BigDecimal,
+}
+
+impl From<DbPriceHistoryEntry> for PriceHistoryEntry {
+    fn from(entry: DbPriceHistoryEntry) -> Self {
+        Self {
+            timestamp: entry.timestamp,
+            min_price: entry.min_price,
+            avg_price: entry.avg_price,
+            max_price: entry.max_price,
+        }
+    }
+}
+
 // Client implementation
 
 /// Tokens API part.
@@ -119,6 +197,24 @@ impl Client {
             .send()
             .await
     }
+
+    pub async fn token_price_history(
+        &self,
+        token: &TokenLike,
+        query: PriceHistoryQuery,
+    ) -> client::Result<Option<Vec<PriceHistoryEntry>>> {
+        self.get(&format!("tokens/{}/price_history", token))
+            .query(&query)
+            .send()
+            .await
+    }
+
+    pub async fn gas_price_history(
+        &self,
+        query: PriceHistoryQuery,
+    ) -> client::Result<Vec<PriceHistoryEntry>> {
+        self.get("gas_price_history").query(&query).send().await
+    }
 }
 
 // Server implementation
@@ -162,6 +258,73 @@ async fn token_price(
     Ok(Json(price))
 }
 
+/// Returns the time range and the aggregation interval of the price history request,
+/// applying the default values and checking the limits.
+fn price_history_range(
+    query: PriceHistoryQuery,
+) -> Result<(DateTime<Utc>, DateTime<Utc>, u64), ApiError> {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_PRICE_HISTORY_DAYS));
+    let interval = query
+        .interval
+        .unwrap_or(DEFAULT_PRICE_HISTORY_INTERVAL_SECS);
+
+    if from >= to {
+        return Err(ApiError::bad_request(
+            "Start of the time range must be before its end",
+        ));
+    }
+    if interval < MIN_PRICE_HISTORY_INTERVAL_SECS {
+        return Err(
+            ApiError::bad_request("Aggregation interval is too small").detail(format!(
+                "Minimal interval is {} seconds",
+                MIN_PRICE_HISTORY_INTERVAL_SECS
+            )),
+        );
+    }
+    let intervals = (to - from).num_seconds() as u64 / interval;
+    if intervals > MAX_PRICE_HISTORY_INTERVALS {
+        return Err(
+            ApiError::bad_request("Too many intervals requested").detail(format!(
+                "Maximum number of intervals is {}",
+                MAX_PRICE_HISTORY_INTERVALS
+            )),
+        );
+    }
+
+    Ok((from, to, interval))
+}
+
+async fn token_price_history(
+    data: web::Data<ApiTokensData>,
+    web::Path(token_like): web::Path<String>,
+    web::Query(query): web::Query<PriceHistoryQuery>,
+) -> JsonResult<Option<Vec<PriceHistoryEntry>>> {
+    let token_like = TokenLike::parse(&token_like);
+    let (from, to, interval) = price_history_range(query)?;
+
+    let history = data
+        .token_price_history(token_like, from, to, interval)
+        .await
+        .map_err(ApiError::internal)?;
+    Ok(Json(history))
+}
+
+async fn gas_price_history(
+    data: web::Data<ApiTokensData>,
+    web::Query(query): web::Query<PriceHistoryQuery>,
+) -> JsonResult<Vec<PriceHistoryEntry>> {
+    let (from, to, interval) = price_history_range(query)?;
+
+    let history = data
+        .gas_price_history(from, to, interval)
+        .await
+        .map_err(ApiError::internal)?;
+    Ok(Json(history))
+}
+
 pub fn api_scope(tokens_db: TokenDBCache, fee_ticker: mpsc::Sender<TickerRequest>) -> Scope {
     let data = ApiTokensData::new(tokens_db, fee_ticker);
 
@@ -170,13 +333,25 @@ pub fn api_scope(tokens_db: TokenDBCache, fee_ticker: mpsc::Sender<TickerRequest
         .route("", web::get().to(tokens))
         .route("{id}", web::get().to(token_by_id))
         .route("{id}/price", web::get().to(token_price))
+        .route("{id}/price_history", web::get().to(token_price_history))
+}
+
+/// Gas price is not related to any token, so its history is provided outside of the
+/// `tokens` scope, where it would be shadowed by the token routes.
+pub fn gas_price_scope(tokens_db: TokenDBCache, fee_ticker: mpsc::Sender<TickerRequest>) -> Scope {
+    let data = ApiTokensData::new(tokens_db, fee_ticker);
+
+    web::scope("gas_price_history")
+        .data(data)
+        .route("", web::get().to(gas_price_history))
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use zksync_types::Address;
+    use num::rational::Ratio;
+    use zksync_types::{Address, TokenPrice};
 
     use super::{super::test_utils::TestServerConfig, *};
 
@@ -300,6 +475,80 @@ mod tests {
         );
         assert_eq!(client.token_by_id(&TokenLike::parse("XM")).await?, None);
 
+        // Price history requests
+        let price_updated_at = Utc::now() - Duration::hours(1);
+        {
+            let mut storage = cfg.pool.access_storage().await?;
+            storage
+                .tokens_schema()
+                .update_historical_ticker_price(
+                    0,
+                    TokenPrice {
+                        usd_price: Ratio::from_integer(500u32.into()),
+                        last_updated: price_updated_at,
+                    },
+                )
+                .await?;
+        }
+
+        let history = client
+            .token_price_history(&TokenLike::parse("ETH"), PriceHistoryQuery::default())
+            .await?
+            .expect("ETH token should exist");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].avg_price, BigDecimal::from(500));
+        assert!(history[0].timestamp <= price_updated_at);
+
+        let query = PriceHistoryQuery {
+            to: Some(price_updated_at - Duration::minutes(1)),
+            ..PriceHistoryQuery::default()
+        };
+        assert!(client
+            .token_price_history(&TokenLike::Id(0), query)
+            .await?
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            client
+                .token_price_history(&TokenLike::parse("XM"), PriceHistoryQuery::default())
+                .await?,
+            None
+        );
+        let query = PriceHistoryQuery {
+            interval: Some(1),
+            ..PriceHistoryQuery::default()
+        };
+        client
+            .token_price_history(&TokenLike::Id(0), query)
+            .await
+            .unwrap_err();
+
+        server.stop().await;
+
+        // Gas price history requests
+        let fee_ticker = dummy_fee_ticker(&[]);
+        let (client, server) = cfg.start_server(move |cfg| {
+            gas_price_scope(TokenDBCache::new(cfg.pool.clone()), fee_ticker.clone())
+        });
+        {
+            let mut storage = cfg.pool.access_storage().await?;
+            storage
+                .ethereum_schema()
+                .store_gas_price_history(100u32.into(), price_updated_at)
+                .await?;
+        }
+        let history = client
+            .gas_price_history(PriceHistoryQuery::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].max_price, BigDecimal::from(100));
+        let query = PriceHistoryQuery {
+            from: Some(price_updated_at),
+            to: Some(price_updated_at - Duration::minutes(1)),
+            ..PriceHistoryQuery::default()
+        };
+        client.gas_price_history(query).await.unwrap_err();
+
         server.stop().await;
         Ok(())
     }
@@ -15,7 +15,7 @@ use zksync_storage::{
 };
 use zksync_types::{
     tx::{TxEthSignature, TxHash},
-    BlockNumber, SignedZkSyncTx, ZkSyncTx,
+    Address, BlockNumber, SignedZkSyncTx, TokenLike, TxFeeTypes, ZkSyncTx,
 };
 
 // Local uses
@@ -24,6 +24,7 @@ use super::{
 };
 use crate::api_server::rpc_server::types::TxWithSignature;
 use crate::api_server::tx_sender::{SubmitError, TxSender};
+use crate::fee_ticker::SignedFeeQuote;
 
 #[derive(Debug, Clone, Copy)]
 pub enum SumbitErrorCode {
@@ -33,6 +34,8 @@ pub enum SumbitErrorCode {
     IncorrectTx = 104,
     TxAdd = 105,
     InappropriateFeeToken = 106,
+    TokenPaused = 107,
+    InvalidFeeQuote = 108,
 
     Internal = 110,
     CommunicationCoreServer = 111,
@@ -48,6 +51,8 @@ impl SumbitErrorCode {
             SubmitError::IncorrectTx(_) => Self::IncorrectTx,
             SubmitError::TxAdd(_) => Self::TxAdd,
             SubmitError::InappropriateFeeToken => Self::InappropriateFeeToken,
+            SubmitError::TokenPaused(_) => Self::TokenPaused,
+            SubmitError::InvalidFeeQuote(_) => Self::InvalidFeeQuote,
             SubmitError::CommunicationCoreServer(_) => Self::CommunicationCoreServer,
             SubmitError::Internal(_) => Self::Internal,
             SubmitError::Other(_) => Self::Other,
@@ -194,13 +199,32 @@ struct FastProcessingQuery {
 struct IncomingTx {
     tx: ZkSyncTx,
     signature: Option<TxEthSignature>,
+    /// Signed fee quote obtained via `transactions/fee/quote`.
+    #[serde(default)]
+    fee_quote: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct FeeQuoteRequest {
+    tx_type: TxFeeTypes,
+    /// Account which will submit the transaction.
+    sender: Address,
+    address: Address,
+    token: TokenLike,
 }
 
+/// Fee quotes are not supported for batches, so the batch must pay the current fee.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct IncomingTxBatch {
     txs: Vec<ZkSyncTx>,
+    /// Ethereum signatures of the whole batch.
     #[serde(default)]
     signatures: Vec<TxEthSignature>,
+    /// Ethereum signatures of the individual transactions, which are only checked
+    /// if the batch signatures are not provided. Either empty or one per transaction.
+    #[serde(default)]
+    tx_signatures: Vec<Option<TxEthSignature>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -232,19 +256,66 @@ impl Client {
     ) -> Result<TxHash, ClientError> {
         self.post("transactions/submit")
             .query(&FastProcessingQuery { fast_processing })
-            .body(&IncomingTx { tx, signature })
+            .body(&IncomingTx {
+                tx,
+                signature,
+                fee_quote: None,
+            })
+            .send()
+            .await
+    }
+
+    /// Sends a new transaction to the memory pool, paying the fee from the provided quote.
+    pub async fn submit_tx_with_fee_quote(
+        &self,
+        tx: ZkSyncTx,
+        signature: Option<TxEthSignature>,
+        fee_quote: String,
+    ) -> Result<TxHash, ClientError> {
+        self.post("transactions/submit")
+            .body(&IncomingTx {
+                tx,
+                signature,
+                fee_quote: Some(fee_quote),
+            })
+            .send()
+            .await
+    }
+
+    /// Gets a signed quote of the fee required for the transaction.
+    /// Quote can only be used by the transaction of the provided sender.
+    pub async fn tx_fee_quote(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenLike,
+    ) -> Result<SignedFeeQuote, ClientError> {
+        self.post("transactions/fee/quote")
+            .body(&FeeQuoteRequest {
+                tx_type,
+                sender,
+                address,
+                token,
+            })
             .send()
             .await
     }
 
     /// Sends a new transactions batch to the memory pool.
+    /// Transaction signatures are either empty or provided for every transaction.
     pub async fn submit_tx_batch(
         &self,
         txs: Vec<ZkSyncTx>,
         signatures: Vec<TxEthSignature>,
+        tx_signatures: Vec<Option<TxEthSignature>>,
     ) -> Result<Vec<TxHash>, ClientError> {
         self.post("transactions/submit/batch")
-            .body(&IncomingTxBatch { txs, signatures })
+            .body(&IncomingTxBatch {
+                txs,
+                signatures,
+                tx_signatures,
+            })
             .send()
             .await
     }
@@ -355,24 +426,53 @@ async fn submit_tx(
 ) -> JsonResult<TxHash> {
     let tx_hash = data
         .tx_sender
-        .submit_tx(body.tx, body.signature, query.fast_processing)
+        .submit_tx(
+            body.tx,
+            body.signature,
+            query.fast_processing,
+            body.fee_quote,
+        )
         .await
         .map_err(ApiError::from)?;
 
     Ok(Json(tx_hash))
 }
 
+async fn tx_fee_quote(
+    data: web::Data<ApiTransactionsData>,
+    Json(body): Json<FeeQuoteRequest>,
+) -> JsonResult<SignedFeeQuote> {
+    let fee_quote = data
+        .tx_sender
+        .get_tx_fee_quote(body.tx_type, body.sender, body.address, body.token)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Json(fee_quote))
+}
+
 async fn submit_tx_batch(
     data: web::Data<ApiTransactionsData>,
     Json(body): Json<IncomingTxBatch>,
 ) -> JsonResult<Vec<TxHash>> {
+    // Transaction signatures may be omitted entirely, but if they are provided,
+    // there must be exactly one entry per transaction.
+    let tx_signatures = if body.tx_signatures.is_empty() {
+        vec![None; body.txs.len()]
+    } else if body.tx_signatures.len() == body.txs.len() {
+        body.tx_signatures
+    } else {
+        return Err(ApiError::from(SubmitError::InvalidParams(format!(
+            "{} transaction signatures are provided for {} transactions",
+            body.tx_signatures.len(),
+            body.txs.len()
+        ))));
+    };
     let txs = body
         .txs
         .into_iter()
-        .map(|tx| TxWithSignature {
-            tx,
-            signature: None,
-        })
+        .zip(tx_signatures)
+        .map(|(tx, signature)| TxWithSignature { tx, signature })
         .collect();
 
     let tx_hashes = data
@@ -398,30 +498,45 @@ pub fn api_scope(tx_sender: TxSender) -> Scope {
         .route("{tx_hash}/receipts", web::get().to(tx_receipts))
         .route("submit", web::post().to(submit_tx))
         .route("submit/batch", web::post().to(submit_tx_batch))
+        .route("fee/quote", web::post().to(tx_fee_quote))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use actix_web::App;
 
     use bigdecimal::BigDecimal;
     use futures::{channel::mpsc, prelude::*};
     use num::BigUint;
+    use zksync::{
+        error::ClientError as ProviderError,
+        provider::{Provider, RestProvider},
+    };
     use zksync_storage::ConnectionPool;
     use zksync_test_account::ZkSyncAccount;
-    use zksync_types::{tokens::TokenLike, SignedZkSyncTx};
+    use zksync_types::{tokens::TokenLike, Nonce, SignedZkSyncTx};
 
     use super::{super::test_utils::TestServerConfig, *};
     use crate::{
         // api_server::helpers::try_parse_tx_hash,
         core_api_client::CoreApiClient,
-        fee_ticker::{Fee, OutputFeeType::Withdraw, TickerRequest},
+        fee_ticker::{Fee, FeeQuoteSigner, OutputFeeType::Withdraw, TickerRequest},
         signature_checker::{VerifiedTx, VerifyTxSignatureRequest},
+        tx_error::TxAddError,
     };
 
+    /// Nonce of the transactions rejected by the mempool of the loopback server.
+    const REJECTED_NONCE: Nonce = 0xbad;
+
     fn submit_txs_loopback() -> (CoreApiClient, actix_web::test::TestServer) {
-        async fn send_tx(_tx: Json<SignedZkSyncTx>) -> Json<Result<(), ()>> {
-            Json(Ok(()))
+        async fn send_tx(tx: Json<SignedZkSyncTx>) -> Json<Result<(), TxAddError>> {
+            if tx.tx.nonce() == REJECTED_NONCE {
+                Json(Err(TxAddError::NonceMismatch))
+            } else {
+                Json(Ok(()))
+            }
         }
 
         async fn send_txs_batch(
@@ -443,6 +558,7 @@ mod tests {
 
     fn dummy_fee_ticker() -> mpsc::Sender<TickerRequest> {
         let (sender, mut receiver) = mpsc::channel(10);
+        let quote_signer = FeeQuoteSigner::new("sample".to_owned(), Duration::from_secs(60));
 
         actix_rt::spawn(async move {
             while let Some(item) = receiver.next().await {
@@ -472,6 +588,34 @@ mod tests {
                         };
                         response.send(Ok(!is_phnx)).unwrap_or_default();
                     }
+                    TickerRequest::GetTxFeeQuote {
+                        tx_type,
+                        sender,
+                        address,
+                        token,
+                        response,
+                    } => {
+                        let token_id = match token {
+                            TokenLike::Id(id) => id,
+                            _ => unreachable!(),
+                        };
+                        let fee = Fee::new(
+                            Withdraw,
+                            BigUint::from(1_u64).into(),
+                            BigUint::from(1_u64).into(),
+                            1_u64.into(),
+                            1_u64.into(),
+                        );
+                        let quote = quote_signer
+                            .sign(tx_type, sender, address, token_id, fee)
+                            .map(Some);
+                        response.send(quote).unwrap_or_default();
+                    }
+                    TickerRequest::VerifyFeeQuote { quote, response } => {
+                        response
+                            .send(quote_signer.verify(&quote))
+                            .unwrap_or_default();
+                    }
                 }
             }
         });
@@ -861,4 +1005,179 @@ mod tests {
         server.stop().await;
         Ok(())
     }
+
+    #[actix_rt::test]
+    async fn test_submit_tx_with_fee_quote() -> anyhow::Result<()> {
+        let (client, server) = TestServer::new().await?;
+
+        let from = ZkSyncAccount::rand();
+        from.set_account_id(Some(0xdead));
+        let to = ZkSyncAccount::rand();
+
+        let fee_quote = client
+            .tx_fee_quote(
+                TxFeeTypes::Withdraw,
+                from.address,
+                to.address,
+                TokenLike::Id(0),
+            )
+            .await?;
+        let sign_withdraw = |account: &ZkSyncAccount, amount: u64, nonce: Option<Nonce>| {
+            let (tx, eth_sig) = account.sign_withdraw(
+                0,
+                "ETH",
+                amount.into(),
+                1_u64.into(),
+                &to.address,
+                nonce,
+                false,
+            );
+            (
+                ZkSyncTx::Withdraw(Box::new(tx)),
+                Some(TxEthSignature::EthereumSignature(eth_sig)),
+            )
+        };
+
+        // Quote can't be used by another account.
+        let other = ZkSyncAccount::rand();
+        other.set_account_id(Some(0xbeef));
+        let (tx, eth_sig) = sign_withdraw(&other, 100, None);
+        client
+            .submit_tx_with_fee_quote(tx, eth_sig, fee_quote.quote.clone())
+            .await
+            .unwrap_err();
+
+        // Quote of the transaction rejected by the mempool can be used again.
+        let (tx, eth_sig) = sign_withdraw(&from, 100, Some(REJECTED_NONCE));
+        client
+            .submit_tx_with_fee_quote(tx, eth_sig, fee_quote.quote.clone())
+            .await
+            .unwrap_err();
+
+        // Quote can only be used once.
+        let (tx, eth_sig) = sign_withdraw(&from, 100, None);
+        client
+            .submit_tx_with_fee_quote(tx, eth_sig, fee_quote.quote.clone())
+            .await?;
+        let (tx, eth_sig) = sign_withdraw(&from, 200, None);
+        client
+            .submit_tx_with_fee_quote(tx, eth_sig, fee_quote.quote)
+            .await
+            .unwrap_err();
+
+        // Quote issued for another transaction type is rejected.
+        let fee_quote = client
+            .tx_fee_quote(
+                TxFeeTypes::Transfer,
+                from.address,
+                to.address,
+                TokenLike::Id(0),
+            )
+            .await?;
+        let (tx, eth_sig) = sign_withdraw(&from, 300, None);
+        client
+            .submit_tx_with_fee_quote(tx, eth_sig, fee_quote.quote)
+            .await
+            .unwrap_err();
+
+        server.stop().await;
+        Ok(())
+    }
+    #[actix_rt::test]
+    async fn test_submit_tx_batch_with_tx_signatures() -> anyhow::Result<()> {
+        let (client, server) = TestServer::new().await?;
+
+        let from = ZkSyncAccount::rand();
+        from.set_account_id(Some(0xdead));
+        let to = ZkSyncAccount::rand();
+
+        let (txs, tx_signatures): (Vec<_>, Vec<_>) = (0..2)
+            .map(|nonce| {
+                let (tx, eth_sig) = from.sign_transfer(
+                    0,
+                    "ETH",
+                    100_u64.into(),
+                    1_u64.into(),
+                    &to.address,
+                    Some(nonce),
+                    false,
+                );
+                (
+                    ZkSyncTx::Transfer(Box::new(tx)),
+                    Some(TxEthSignature::EthereumSignature(eth_sig)),
+                )
+            })
+            .unzip();
+        let tx_hashes: Vec<_> = txs.iter().map(ZkSyncTx::hash).collect();
+
+        // Transaction signatures must be provided for every transaction.
+        let err = client
+            .submit_tx_batch(txs.clone(), Vec::new(), tx_signatures[..1].to_vec())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid params"));
+
+        assert_eq!(
+            client
+                .submit_tx_batch(txs, Vec::new(), tx_signatures)
+                .await?,
+            tx_hashes
+        );
+
+        server.stop().await;
+        Ok(())
+    }
+
+    /// Checks that the requests of the SDK `RestProvider` are understood by the server,
+    /// and that the server responses, including the errors, are understood by the provider.
+    #[actix_rt::test]
+    async fn test_rest_provider_round_trip() -> anyhow::Result<()> {
+        let (_client, server) = TestServer::new().await?;
+        // Fee requests are not sent, so the JSON RPC address is never used.
+        let provider = RestProvider::from_addr(
+            server.api_server.url("").trim_end_matches('/'),
+            "http://127.0.0.1:3030",
+        );
+
+        let from = ZkSyncAccount::rand();
+        from.set_account_id(Some(0xdead));
+        let to = ZkSyncAccount::rand();
+        let sign_transfer = |nonce: Nonce| {
+            let (tx, eth_sig) = from.sign_transfer(
+                0,
+                "ETH",
+                100_u64.into(),
+                1_u64.into(),
+                &to.address,
+                Some(nonce),
+                false,
+            );
+            (
+                ZkSyncTx::Transfer(Box::new(tx)),
+                Some(TxEthSignature::EthereumSignature(eth_sig)),
+            )
+        };
+
+        let (tx, eth_sig) = sign_transfer(0);
+        let tx_hash = tx.hash();
+        assert_eq!(provider.send_tx(tx, eth_sig).await?, tx_hash);
+
+        // Transactions in the batch are sent with their own signatures.
+        let txs = vec![sign_transfer(1), sign_transfer(2)];
+        let tx_hashes: Vec<_> = txs.iter().map(|(tx, _)| tx.hash()).collect();
+        assert_eq!(provider.send_txs_batch(txs, Vec::new()).await?, tx_hashes);
+
+        // Rejection reason is provided in the error response.
+        let (tx, eth_sig) = sign_transfer(REJECTED_NONCE);
+        match provider.send_tx(tx, eth_sig).await.unwrap_err() {
+            ProviderError::RestError(err) => {
+                assert_eq!(err.code, Some(SumbitErrorCode::TxAdd as u64));
+                assert!(err.title.contains(&TxAddError::NonceMismatch.to_string()));
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+
+        server.stop().await;
+        Ok(())
+    }
 }
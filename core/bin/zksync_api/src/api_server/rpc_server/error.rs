@@ -10,6 +10,8 @@ pub enum RpcErrorCodes {
     IncorrectTx = 103,
     FeeTooLow = 104,
     InappropriateFeeToken = 105,
+    TokenPaused = 106,
+    InvalidFeeQuote = 107,
 
     MissingEthSignature = 200,
     EIP1271SignatureVerificationFail = 201,
@@ -80,6 +82,16 @@ impl From<SubmitError> for jsonrpc_core::Error {
                 message: inner.to_string(),
                 data: None,
             },
+            SubmitError::TokenPaused(_) => Self {
+                code: RpcErrorCodes::TokenPaused.into(),
+                message: inner.to_string(),
+                data: None,
+            },
+            SubmitError::InvalidFeeQuote(_) => Self {
+                code: RpcErrorCodes::InvalidFeeQuote.into(),
+                message: inner.to_string(),
+                data: None,
+            },
             SubmitError::CommunicationCoreServer(reason) => Self {
                 code: RpcErrorCodes::Other.into(),
                 message: "Error communicating core server".to_string(),
@@ -13,7 +13,7 @@ use zksync_types::{
 // Local uses
 use crate::{
     api_server::tx_sender::SubmitError,
-    fee_ticker::{BatchFee, Fee, TokenPriceRequestType},
+    fee_ticker::{BatchFee, Fee, SignedFeeQuote, TokenPriceRequestType},
 };
 use bigdecimal::BigDecimal;
 
@@ -104,11 +104,12 @@ impl RpcApp {
         tx: Box<ZkSyncTx>,
         signature: Box<Option<TxEthSignature>>,
         fast_processing: Option<bool>,
+        fee_quote: Option<String>,
     ) -> Result<TxHash> {
         let start = Instant::now();
         let result = self
             .tx_sender
-            .submit_tx(*tx, *signature, fast_processing)
+            .submit_tx(*tx, *signature, fast_processing, fee_quote)
             .await
             .map_err(Error::from);
         metrics::histogram!("api.rpc.tx_submit", start.elapsed());
@@ -219,6 +220,23 @@ impl RpcApp {
         result
     }
 
+    pub async fn _impl_get_tx_fee_quote(
+        self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenLike,
+    ) -> Result<SignedFeeQuote> {
+        let start = Instant::now();
+        let result = self
+            .tx_sender
+            .get_tx_fee_quote(tx_type, sender, address, token)
+            .await
+            .map_err(Error::from);
+        metrics::histogram!("api.rpc.get_tx_fee_quote", start.elapsed());
+        result
+    }
+
     pub async fn _impl_get_txs_batch_fee_in_wei(
         self,
         tx_types: Vec<TxFeeTypes>,
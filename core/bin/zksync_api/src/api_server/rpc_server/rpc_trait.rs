@@ -10,7 +10,7 @@ use zksync_types::{
 };
 
 // Local uses
-use crate::fee_ticker::{BatchFee, Fee};
+use crate::fee_ticker::{BatchFee, Fee, SignedFeeQuote};
 use bigdecimal::BigDecimal;
 
 use super::{types::*, RpcApp};
@@ -34,6 +34,7 @@ pub trait Rpc {
         tx: Box<ZkSyncTx>,
         signature: Box<Option<TxEthSignature>>,
         fast_processing: Option<bool>,
+        fee_quote: Option<String>,
     ) -> FutureResp<TxHash>;
 
     #[rpc(name = "submit_txs_batch", returns = "Vec<TxHash>")]
@@ -58,6 +59,15 @@ pub trait Rpc {
         token_like: TokenLike,
     ) -> FutureResp<Fee>;
 
+    #[rpc(name = "get_tx_fee_quote", returns = "SignedFeeQuote")]
+    fn get_tx_fee_quote(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token_like: TokenLike,
+    ) -> FutureResp<SignedFeeQuote>;
+
     #[rpc(name = "get_txs_batch_fee_in_wei", returns = "BatchFee")]
     fn get_txs_batch_fee_in_wei(
         &self,
@@ -108,12 +118,13 @@ impl Rpc for RpcApp {
         tx: Box<ZkSyncTx>,
         signature: Box<Option<TxEthSignature>>,
         fast_processing: Option<bool>,
+        fee_quote: Option<String>,
     ) -> FutureResp<TxHash> {
         let handle = self.runtime_handle.clone();
         let self_ = self.clone();
         let resp = async move {
             handle
-                .spawn(self_._impl_tx_submit(tx, signature, fast_processing))
+                .spawn(self_._impl_tx_submit(tx, signature, fast_processing, fee_quote))
                 .await
                 .unwrap()
         };
@@ -167,6 +178,24 @@ impl Rpc for RpcApp {
         Box::new(resp.boxed().compat())
     }
 
+    fn get_tx_fee_quote(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token_like: TokenLike,
+    ) -> FutureResp<SignedFeeQuote> {
+        let handle = self.runtime_handle.clone();
+        let self_ = self.clone();
+        let resp = async move {
+            handle
+                .spawn(self_._impl_get_tx_fee_quote(tx_type, sender, address, token_like))
+                .await
+                .unwrap()
+        };
+        Box::new(resp.boxed().compat())
+    }
+
     fn get_txs_batch_fee_in_wei(
         &self,
         tx_types: Vec<TxFeeTypes>,
@@ -194,6 +194,8 @@ pub fn start_ws_server(
         config_options
             .miniblock_timings
             .miniblock_iteration_interval,
+        api_server_options.webhook_retry_interval,
+        api_server_options.webhook_max_attempts,
     );
 
     let req_rpc_app = super::rpc_server::RpcApp::new(
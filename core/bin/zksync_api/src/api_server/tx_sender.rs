@@ -26,7 +26,7 @@ use zksync_types::{
 use crate::api_server::rpc_server::types::TxWithSignature;
 use crate::{
     core_api_client::CoreApiClient,
-    fee_ticker::{Fee, TickerRequest, TokenPriceRequestType},
+    fee_ticker::{Fee, FeeQuote, SignedFeeQuote, TickerRequest, TokenPriceRequestType},
     signature_checker::{TxVariant, VerifiedTx, VerifyTxSignatureRequest},
     tx_error::TxAddError,
     utils::token_db_cache::TokenDBCache,
@@ -62,6 +62,10 @@ pub enum SubmitError {
     TxAdd(TxAddError),
     #[error("Chosen token is not suitable for paying fees.")]
     InappropriateFeeToken,
+    #[error("Transfers in the token {0} are paused.")]
+    TokenPaused(TokenId),
+    #[error("Invalid fee quote: {0}.")]
+    InvalidFeeQuote(String),
 
     #[error("Communication error with the core server: {0}.")]
     CommunicationCoreServer(String),
@@ -171,6 +175,7 @@ impl TxSender {
         mut tx: ZkSyncTx,
         signature: Option<TxEthSignature>,
         fast_processing: Option<bool>,
+        fee_quote: Option<String>,
     ) -> Result<TxHash, SubmitError> {
         if tx.is_close() {
             return Err(SubmitError::AccountCloseDisabled);
@@ -179,6 +184,7 @@ impl TxSender {
         if let ZkSyncTx::ForcedExit(forced_exit) = &tx {
             self.check_forced_exit(forced_exit).await?;
         }
+        self.check_token_not_paused(&tx).await?;
 
         let fast_processing = fast_processing.unwrap_or_default(); // `None` => false
         if fast_processing && !tx.is_withdraw() {
@@ -206,6 +212,12 @@ impl TxSender {
         let sign_verify_channel = self.sign_verify_requests.clone();
         let ticker_request_sender = self.ticker_requests.clone();
 
+        let tx_sender = self
+            .get_tx_sender(&tx)
+            .await
+            .or(Err(SubmitError::TxAdd(TxAddError::DbError)))?;
+
+        let mut verified_fee_quote = None;
         if let Some((tx_type, token, address, provided_fee)) = tx_fee_info {
             let should_enforce_fee =
                 !matches!(tx_type, TxFeeTypes::ChangePubKey{..}) || self.enforce_pubkey_change_fee;
@@ -217,33 +229,53 @@ impl TxSender {
                 return Err(SubmitError::InappropriateFeeToken);
             }
 
-            let required_fee =
-                Self::ticker_request(ticker_request_sender, tx_type, address, token.clone())
-                    .await?;
-            // Converting `BitUint` to `BigInt` is safe.
-            let required_fee: BigDecimal = required_fee.total_fee.to_bigint().unwrap().into();
-            let provided_fee: BigDecimal = provided_fee.to_bigint().unwrap().into();
-            // Scaling the fee required since the price may change between signing the transaction and sending it to the server.
-            let scaled_provided_fee = scale_user_fee_up(provided_fee.clone());
-            if required_fee >= scaled_provided_fee && should_enforce_fee {
-                log::error!(
-                    "User provided fee is too low, required: {}, provided: {} (scaled: {}); difference {}, token: {:?}",
-                    required_fee.to_string(),
-                    provided_fee.to_string(),
-                    scaled_provided_fee.to_string(),
-                    (required_fee - scaled_provided_fee).to_string(),
-                    token
-                );
-
-                return Err(SubmitError::TxAdd(TxAddError::TxFeeTooLow));
+            if let Some(quote) = fee_quote {
+                // Quoted fee is accepted while the quote is valid, since the fee required by
+                // the server may change after the transaction was signed.
+                let fee_quote = Self::verify_fee_quote(
+                    ticker_request_sender,
+                    quote.clone(),
+                    tx_type,
+                    tx_sender,
+                    address,
+                    &token,
+                )
+                .await?;
+                if provided_fee < fee_quote.fee && should_enforce_fee {
+                    log::error!(
+                        "User provided fee is lower than quoted, quoted: {}, provided: {}, token: {:?}",
+                        fee_quote.fee,
+                        provided_fee,
+                        token
+                    );
+
+                    return Err(SubmitError::TxAdd(TxAddError::TxFeeTooLow));
+                }
+                verified_fee_quote = Some((quote, fee_quote));
+            } else {
+                let required_fee =
+                    Self::ticker_request(ticker_request_sender, tx_type, address, token.clone())
+                        .await?;
+                // Converting `BitUint` to `BigInt` is safe.
+                let required_fee: BigDecimal = required_fee.total_fee.to_bigint().unwrap().into();
+                let provided_fee: BigDecimal = provided_fee.to_bigint().unwrap().into();
+                // Scaling the fee required since the price may change between signing the transaction and sending it to the server.
+                let scaled_provided_fee = scale_user_fee_up(provided_fee.clone());
+                if required_fee >= scaled_provided_fee && should_enforce_fee {
+                    log::error!(
+                        "User provided fee is too low, required: {}, provided: {} (scaled: {}); difference {}, token: {:?}",
+                        required_fee.to_string(),
+                        provided_fee.to_string(),
+                        scaled_provided_fee.to_string(),
+                        (required_fee - scaled_provided_fee).to_string(),
+                        token
+                    );
+
+                    return Err(SubmitError::TxAdd(TxAddError::TxFeeTooLow));
+                }
             }
         }
 
-        let tx_sender = self
-            .get_tx_sender(&tx)
-            .await
-            .or(Err(SubmitError::TxAdd(TxAddError::DbError)))?;
-
         let verified_tx = verify_tx_info_message_signature(
             &tx,
             tx_sender,
@@ -254,16 +286,34 @@ impl TxSender {
         .await?
         .unwrap_tx();
 
+        // The quote is only redeemed for the verified transactions, so it can't be used up
+        // by the transactions which are rejected anyway.
+        let redeemed_quote_hash = match &verified_fee_quote {
+            Some((quote, fee_quote)) => Some(self.redeem_fee_quote(quote, fee_quote).await?),
+            None => None,
+        };
+
         // Send verified transactions to the mempool.
-        self.core_api_client
+        let result = self
+            .core_api_client
             .send_tx(verified_tx)
             .await
-            .map_err(SubmitError::communication_core_server)?
-            .map_err(SubmitError::TxAdd)?;
+            .map_err(SubmitError::communication_core_server)?;
+        if let Err(err) = result {
+            // Transaction was rejected by the mempool, so the user can use the quote again.
+            if let Some(quote_hash) = redeemed_quote_hash {
+                if let Err(err) = self.release_fee_quote(&quote_hash).await {
+                    log::warn!("Failed to release the fee quote: {}", err);
+                }
+            }
+            return Err(SubmitError::TxAdd(err));
+        }
         // if everything is OK, return the transactions hashes.
         Ok(tx.hash())
     }
 
+    /// Submits the transactions batch. Fee quotes are not accepted for batches: the total fee
+    /// of the batch is always checked against the current fee.
     pub async fn submit_txs_batch(
         &self,
         txs: Vec<TxWithSignature>,
@@ -286,6 +336,9 @@ impl TxSender {
         if txs.iter().any(|tx| tx.tx.is_close()) {
             return Err(SubmitError::AccountCloseDisabled);
         }
+        for tx in &txs {
+            self.check_token_not_paused(&tx.tx).await?;
+        }
 
         // Checking fees data
         let mut required_total_usd_fee = BigDecimal::from(0);
@@ -410,6 +463,22 @@ impl TxSender {
         Ok(tx_hashes)
     }
 
+    /// Checks that the token of the transfer is not paused by the server operator.
+    /// Other transactions are not affected, so users can always withdraw their funds.
+    async fn check_token_not_paused(&self, tx: &ZkSyncTx) -> Result<(), SubmitError> {
+        if let ZkSyncTx::Transfer(transfer) = tx {
+            let status = self
+                .tokens
+                .token_status(transfer.token)
+                .await
+                .map_err(SubmitError::internal)?;
+            if status.paused {
+                return Err(SubmitError::TokenPaused(transfer.token));
+            }
+        }
+        Ok(())
+    }
+
     /// For forced exits, we must check that target account exists for more
     /// than 24 hours in order to give new account owners give an opportunity
     /// to set the signing key. While `ForcedExit` operation doesn't do anything
@@ -504,6 +573,112 @@ impl TxSender {
         resp.map_err(|err| internal_error!(err))
     }
 
+    /// Checks that the fee quote is valid and was issued for the transaction
+    /// with the given fee parameters. The quote is not redeemed by this check.
+    async fn verify_fee_quote(
+        mut ticker_request_sender: mpsc::Sender<TickerRequest>,
+        quote: String,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: &TokenLike,
+    ) -> Result<FeeQuote, SubmitError> {
+        let req = oneshot::channel();
+        ticker_request_sender
+            .send(TickerRequest::VerifyFeeQuote {
+                quote,
+                response: req.0,
+            })
+            .await
+            .map_err(SubmitError::internal)?;
+
+        let fee_quote = req
+            .1
+            .await
+            .map_err(SubmitError::internal)?
+            .map_err(|err| SubmitError::InvalidFeeQuote(err.to_string()))?;
+
+        // Fee token of the transaction is always specified by its id.
+        let is_matching = match token {
+            TokenLike::Id(token_id) => fee_quote.matches(tx_type, sender, address, *token_id),
+            _ => false,
+        };
+        if !is_matching {
+            return Err(SubmitError::InvalidFeeQuote(
+                "quote was issued for another transaction".to_string(),
+            ));
+        }
+        Ok(fee_quote)
+    }
+
+    /// Marks the verified fee quote as used, so it can't be submitted with another transaction.
+    /// Returns the hash of the redeemed quote.
+    async fn redeem_fee_quote(
+        &self,
+        quote: &str,
+        fee_quote: &FeeQuote,
+    ) -> Result<Vec<u8>, SubmitError> {
+        let quote_hash = FeeQuote::hash(quote);
+        let redeemed = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(SubmitError::internal)?
+            .tokens_schema()
+            .redeem_fee_quote(&quote_hash, fee_quote.expires_at())
+            .await
+            .map_err(SubmitError::internal)?;
+        if !redeemed {
+            return Err(SubmitError::InvalidFeeQuote(
+                "quote was already used".to_string(),
+            ));
+        }
+        Ok(quote_hash)
+    }
+
+    /// Makes the redeemed fee quote available again.
+    async fn release_fee_quote(&self, quote_hash: &[u8]) -> Result<(), anyhow::Error> {
+        self.pool
+            .access_storage()
+            .await?
+            .tokens_schema()
+            .release_fee_quote(quote_hash)
+            .await
+    }
+
+    /// Issues a signed quote of the fee required for the transaction with the given parameters.
+    /// Returns an error if the fee quotes are disabled by the server configuration.
+    pub async fn get_tx_fee_quote(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenLike,
+    ) -> Result<SignedFeeQuote, SubmitError> {
+        let mut ticker_request_sender = self.ticker_requests.clone();
+        let token_allowed =
+            Self::token_allowed_for_fees(ticker_request_sender.clone(), token.clone()).await?;
+        if !token_allowed {
+            return Err(SubmitError::InappropriateFeeToken);
+        }
+
+        let req = oneshot::channel();
+        ticker_request_sender
+            .send(TickerRequest::GetTxFeeQuote {
+                tx_type,
+                sender,
+                address,
+                token,
+                response: req.0,
+            })
+            .await
+            .map_err(SubmitError::internal)?;
+
+        let resp = req.1.await.map_err(SubmitError::internal)?;
+        resp.map_err(|err| internal_error!(err))?
+            .ok_or_else(|| SubmitError::InvalidFeeQuote("fee quotes are disabled".to_string()))
+    }
+
     async fn token_allowed_for_fees(
         mut ticker_request_sender: mpsc::Sender<TickerRequest>,
         token: TokenLike,
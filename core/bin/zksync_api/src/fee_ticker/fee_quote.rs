@@ -0,0 +1,134 @@
+//! Signed fee quotes.
+//!
+//! Fee required by the server may change between the moment it was shown to the user and
+//! the moment the transaction is submitted. To avoid rejecting such transactions, the fee
+//! ticker can issue a quote: the required fee signed by the server together with the
+//! transaction parameters it was calculated for. Transaction provided with a quote that
+//! is not expired yet only has to pay the quoted fee.
+//!
+//! Quote is bound to the transaction sender and can only be redeemed once, so it can't be
+//! reused for several transactions. Redeemed quotes are stored in the database (by their
+//! hashes) until they expire, so the redemption is shared between all the API servers.
+//!
+//! Quotes are only issued if the signing secret is configured, and are only accepted for
+//! the single transactions: batches always have to pay the current fee.
+
+// Built-in deps
+use std::time::Duration;
+// External deps
+use chrono::{DateTime, TimeZone, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+// Workspace deps
+use zksync_types::{Address, TokenId, TxFeeTypes};
+use zksync_utils::BigUintSerdeAsRadix10Str;
+// Local deps
+use super::Fee;
+
+/// Fee quote data signed by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeQuote {
+    pub tx_type: TxFeeTypes,
+    /// Account which is allowed to redeem the quote.
+    pub sender: Address,
+    /// Address the fee was calculated for (e.g. the transfer recipient).
+    pub address: Address,
+    pub token: TokenId,
+    /// Total fee required for the transaction.
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    pub fee: BigUint,
+    /// Expiration time (as UTC timestamp).
+    pub exp: u64,
+}
+
+impl FeeQuote {
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        Utc.timestamp(self.exp as i64, 0)
+    }
+
+    /// Returns `true` if the quote was issued for the transaction with the given fee parameters.
+    pub fn matches(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenId,
+    ) -> bool {
+        self.tx_type == tx_type
+            && self.sender == sender
+            && self.address == address
+            && self.token == token
+    }
+
+    /// Returns the hash identifying the encoded quote in the redeemed quotes storage.
+    pub fn hash(quote: &str) -> Vec<u8> {
+        Sha256::digest(quote.as_bytes()).to_vec()
+    }
+}
+
+/// Fee quote issued to the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedFeeQuote {
+    pub fee: Fee,
+    pub expires_at: DateTime<Utc>,
+    /// Encoded signed quote, which should be submitted along with the transaction.
+    pub quote: String,
+}
+
+/// Signs and verifies fee quotes with the shared secret.
+#[derive(Debug, Clone)]
+pub struct FeeQuoteSigner {
+    secret: String,
+    validity: Duration,
+}
+
+impl FeeQuoteSigner {
+    pub fn new(secret: String, validity: Duration) -> Self {
+        Self { secret, validity }
+    }
+
+    /// Issues a quote for the fee calculated for the transaction with the given parameters.
+    pub fn sign(
+        &self,
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenId,
+        fee: Fee,
+    ) -> Result<SignedFeeQuote, anyhow::Error> {
+        let exp = (Utc::now() + chrono::Duration::from_std(self.validity)?).timestamp() as u64;
+        let fee_quote = FeeQuote {
+            tx_type,
+            sender,
+            address,
+            token,
+            fee: fee.total_fee.clone(),
+            exp,
+        };
+        let quote = encode(
+            &Header::default(),
+            &fee_quote,
+            &EncodingKey::from_secret(self.secret.as_ref()),
+        )?;
+
+        Ok(SignedFeeQuote {
+            fee,
+            expires_at: fee_quote.expires_at(),
+            quote,
+        })
+    }
+
+    /// Decodes the quote, checking that it was signed by the server and is not expired.
+    pub fn verify(&self, quote: &str) -> Result<FeeQuote, anyhow::Error> {
+        let fee_quote = decode::<FeeQuote>(
+            quote,
+            &DecodingKey::from_secret(self.secret.as_ref()),
+            &Validation::default(),
+        )?;
+        Ok(fee_quote.claims)
+    }
+}
@@ -5,8 +5,8 @@
 use std::collections::{HashMap, HashSet};
 // Workspace uses
 use zksync_types::{
-    tokens::{Token, TokenLike},
-    Address,
+    tokens::{Token, TokenLike, TokenStatus},
+    Address, TokenId,
 };
 // Local uses
 use crate::utils::token_db_cache::TokenDBCache;
@@ -47,7 +47,12 @@ impl FeeTokenValidator {
         // though it's not really `async` at this moment.
 
         if let Some(token) = token {
-            let not_acceptable = self.disabled_tokens.contains(&token.address);
+            let not_acceptable = self.disabled_tokens.contains(&token.address)
+                || self
+                    .tokens_cache
+                    .token_status(token.id)
+                    .await?
+                    .deprecated_for_fees;
             Ok(!not_acceptable)
         } else {
             // Unknown tokens aren't suitable for our needs, obviously.
@@ -81,6 +86,15 @@ impl TokenCacheWrapper {
             Self::Memory(cache) => Ok(cache.get(&token_like).cloned()),
         }
     }
+
+    /// Returns the token status. Statuses are not supported by the in-memory cache,
+    /// so the default status is returned for it.
+    pub async fn token_status(&self, token_id: TokenId) -> anyhow::Result<TokenStatus> {
+        match self {
+            Self::DB(cache) => cache.token_status(token_id).await,
+            Self::Memory(_) => Ok(TokenStatus::default()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -5,8 +5,10 @@
 
 // Built-in deps
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 // External deps
 use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use futures::{
     channel::{mpsc::Receiver, oneshot},
     StreamExt,
@@ -28,6 +30,7 @@ use zksync_types::{
 use zksync_utils::ratio_to_big_decimal;
 // Local deps
 use crate::fee_ticker::{
+    fee_quote::FeeQuoteSigner,
     fee_token_validator::FeeTokenValidator,
     ticker_api::{
         coingecko::CoinGeckoAPI, coinmarkercap::CoinMarketCapAPI, FeeTickerAPI, TickerApi,
@@ -37,10 +40,11 @@ use crate::fee_ticker::{
 };
 use crate::utils::token_db_cache::TokenDBCache;
 
-pub use self::fee::*;
+pub use self::{fee::*, fee_quote::*};
 
 mod constants;
 mod fee;
+mod fee_quote;
 mod fee_token_validator;
 mod ticker_api;
 mod ticker_info;
@@ -168,6 +172,19 @@ pub enum TickerRequest {
         token: TokenLike,
         response: oneshot::Sender<Result<bool, anyhow::Error>>,
     },
+    GetTxFeeQuote {
+        tx_type: TxFeeTypes,
+        sender: Address,
+        address: Address,
+        token: TokenLike,
+        /// `None` is returned if the fee quotes are disabled.
+        response: oneshot::Sender<Result<Option<SignedFeeQuote>, anyhow::Error>>,
+    },
+    /// Decodes the fee quote, checking its signature and expiration time.
+    VerifyFeeQuote {
+        quote: String,
+        response: oneshot::Sender<Result<FeeQuote, anyhow::Error>>,
+    },
 }
 
 struct FeeTicker<API, INFO> {
@@ -176,8 +193,13 @@ struct FeeTicker<API, INFO> {
     requests: Receiver<TickerRequest>,
     config: TickerConfig,
     validator: FeeTokenValidator,
+    /// Signer of the fee quotes, `None` if the fee quotes are disabled.
+    quote_signer: Option<FeeQuoteSigner>,
 }
 
+/// Interval between removals of the outdated price history entries.
+const PRICE_HISTORY_PRUNING_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[must_use]
 pub fn run_ticker_task(
     db_pool: ConnectionPool,
@@ -194,6 +216,14 @@ pub fn run_ticker_task(
 
     let cache = TokenDBCache::new(db_pool.clone());
     let validator = FeeTokenValidator::new(cache, config.disabled_tokens);
+    let quote_signer = config
+        .fee_quote_secret
+        .map(|secret| FeeQuoteSigner::new(secret, config.fee_quote_validity));
+
+    tokio::spawn(prune_price_history(
+        db_pool.clone(),
+        config.price_history_retention,
+    ));
 
     let client = reqwest::ClientBuilder::new()
         .timeout(CONNECTION_TIMEOUT)
@@ -212,6 +242,7 @@ pub fn run_ticker_task(
                 tricker_requests,
                 ticker_config,
                 validator,
+                quote_signer,
             );
 
             tokio::spawn(fee_ticker.run())
@@ -228,6 +259,7 @@ pub fn run_ticker_task(
                 tricker_requests,
                 ticker_config,
                 validator,
+                quote_signer,
             );
 
             tokio::spawn(fee_ticker.run())
@@ -235,6 +267,49 @@ pub fn run_ticker_task(
     }
 }
 
+/// Periodically removes the token and gas prices stored more than `retention` ago
+/// from the price history, together with the expired redeemed fee quotes.
+async fn prune_price_history(db_pool: ConnectionPool, retention: Duration) {
+    let retention = chrono::Duration::from_std(retention)
+        .expect("Unable to convert std::Duration to chrono::Duration");
+    let mut timer = tokio::time::interval(PRICE_HISTORY_PRUNING_INTERVAL);
+    loop {
+        timer.tick().await;
+
+        if let Err(e) = remove_price_history(&db_pool, Utc::now() - retention).await {
+            log::warn!("Failed to remove outdated price history: {}", e);
+        }
+        if let Err(e) = remove_expired_fee_quotes(&db_pool).await {
+            log::warn!("Failed to remove expired fee quotes: {}", e);
+        }
+    }
+}
+
+async fn remove_expired_fee_quotes(db_pool: &ConnectionPool) -> anyhow::Result<()> {
+    let mut storage = db_pool.access_storage().await?;
+    storage
+        .tokens_schema()
+        .remove_expired_fee_quotes(Utc::now())
+        .await?;
+    Ok(())
+}
+
+async fn remove_price_history(
+    db_pool: &ConnectionPool,
+    before: DateTime<Utc>,
+) -> anyhow::Result<()> {
+    let mut storage = db_pool.access_storage().await?;
+    storage
+        .tokens_schema()
+        .remove_ticker_price_history(before)
+        .await?;
+    storage
+        .ethereum_schema()
+        .remove_gas_price_history(before)
+        .await?;
+    Ok(())
+}
+
 impl<API: FeeTickerAPI, INFO: FeeTickerInfo> FeeTicker<API, INFO> {
     fn new(
         api: API,
@@ -242,6 +317,7 @@ impl<API: FeeTickerAPI, INFO: FeeTickerInfo> FeeTicker<API, INFO> {
         requests: Receiver<TickerRequest>,
         config: TickerConfig,
         validator: FeeTokenValidator,
+        quote_signer: Option<FeeQuoteSigner>,
     ) -> Self {
         Self {
             api,
@@ -249,6 +325,7 @@ impl<API: FeeTickerAPI, INFO: FeeTickerInfo> FeeTicker<API, INFO> {
             requests,
             config,
             validator,
+            quote_signer,
         }
     }
 
@@ -278,6 +355,23 @@ impl<API: FeeTickerAPI, INFO: FeeTickerInfo> FeeTicker<API, INFO> {
                     let allowed = self.validator.token_allowed(token).await;
                     response.send(allowed).unwrap_or_default();
                 }
+                TickerRequest::GetTxFeeQuote {
+                    tx_type,
+                    sender,
+                    address,
+                    token,
+                    response,
+                } => {
+                    let quote = self.get_fee_quote(tx_type, token, sender, address).await;
+                    response.send(quote).unwrap_or_default();
+                }
+                TickerRequest::VerifyFeeQuote { quote, response } => {
+                    let quote = match &self.quote_signer {
+                        Some(quote_signer) => quote_signer.verify(&quote),
+                        None => Err(anyhow::anyhow!("fee quotes are disabled")),
+                    };
+                    response.send(quote).unwrap_or_default();
+                }
             }
         }
     }
@@ -301,6 +395,29 @@ impl<API: FeeTickerAPI, INFO: FeeTickerInfo> FeeTicker<API, INFO> {
             .map(|price| ratio_to_big_decimal(&(price.usd_price / factor), 100))
     }
 
+    /// Calculates the fee for the transaction and issues a signed quote for it.
+    /// Returns `None` if the fee quotes are disabled.
+    async fn get_fee_quote(
+        &mut self,
+        tx_type: TxFeeTypes,
+        token: TokenLike,
+        sender: Address,
+        recipient: Address,
+    ) -> Result<Option<SignedFeeQuote>, anyhow::Error> {
+        let quote_signer = match self.quote_signer.clone() {
+            Some(quote_signer) => quote_signer,
+            None => return Ok(None),
+        };
+
+        let token_id = self.api.get_token(token.clone()).await?.id;
+        let fee = self
+            .get_fee_from_ticker_in_wei(tx_type, token, recipient)
+            .await?;
+        quote_signer
+            .sign(tx_type, sender, recipient, token_id, fee)
+            .map(Some)
+    }
+
     /// Returns `true` if account does not yet exist in the zkSync network.
     async fn is_account_new(&mut self, address: Address) -> bool {
         self.info.is_account_new(address).await
@@ -151,6 +151,10 @@ impl FeeTickerInfo for MockTickerInfo {
     }
 }
 
+fn test_quote_signer() -> FeeQuoteSigner {
+    FeeQuoteSigner::new("sample".to_string(), Duration::from_secs(60))
+}
+
 fn format_with_dot(num: &Ratio<BigUint>, precision: usize) -> String {
     UnsignedRatioSerializeAsDecimal::serialize_to_str_with_dot(num, precision)
 }
@@ -166,6 +170,7 @@ fn test_ticker_formula() {
         mpsc::channel(1).1,
         config,
         validator,
+        Some(test_quote_signer()),
     );
 
     let mut get_token_fee_in_usd =
@@ -262,6 +267,7 @@ fn test_fee_for_unsubsidized_tokens() {
         mpsc::channel(1).1,
         config,
         validator,
+        Some(test_quote_signer()),
     );
 
     let mut get_gas_amount =
@@ -313,3 +319,100 @@ fn test_fee_for_unsubsidized_tokens() {
         }
     }
 }
+
+/// Checks that the issued fee quote is accepted only if it's signed with the same secret,
+/// not modified and not expired.
+#[test]
+fn test_fee_quote() {
+    let validator = FeeTokenValidator::new(HashMap::new(), Default::default());
+    let mut ticker = FeeTicker::new(
+        MockApiProvider,
+        MockTickerInfo,
+        mpsc::channel(1).1,
+        get_test_ticker_config(),
+        validator,
+        Some(test_quote_signer()),
+    );
+    let sender = Address::repeat_byte(0x22);
+    let address = Address::repeat_byte(0x11);
+
+    let signed_quote = block_on(ticker.get_fee_quote(
+        TxFeeTypes::Withdraw,
+        TokenLike::Id(TestToken::hex().id),
+        sender,
+        address,
+    ))
+    .expect("failed to get fee quote")
+    .expect("fee quotes must be enabled");
+    assert!(signed_quote.expires_at > Utc::now());
+
+    let quote = test_quote_signer()
+        .verify(&signed_quote.quote)
+        .expect("failed to verify fee quote");
+    assert_eq!(quote.fee, signed_quote.fee.total_fee);
+    assert_eq!(quote.expires_at(), signed_quote.expires_at);
+    let token_id = TestToken::hex().id;
+    assert!(quote.matches(TxFeeTypes::Withdraw, sender, address, token_id));
+    assert!(!quote.matches(TxFeeTypes::Transfer, sender, address, token_id));
+    assert!(!quote.matches(TxFeeTypes::Withdraw, sender, Address::zero(), token_id));
+    assert!(!quote.matches(TxFeeTypes::Withdraw, sender, address, TestToken::eth().id));
+    // Quote can't be redeemed by another account, e.g. the recipient.
+    assert!(!quote.matches(TxFeeTypes::Withdraw, address, address, token_id));
+
+    // Quote signed with another secret is rejected.
+    let other_signer = FeeQuoteSigner::new("other".to_string(), Duration::from_secs(60));
+    assert!(other_signer.verify(&signed_quote.quote).is_err());
+
+    // Quote with the signature of another quote is rejected.
+    let other_quote = block_on(ticker.get_fee_quote(
+        TxFeeTypes::Transfer,
+        TokenLike::Id(TestToken::hex().id),
+        sender,
+        address,
+    ))
+    .unwrap()
+    .unwrap();
+    let signature = other_quote.quote.rsplit('.').next().unwrap();
+    let (message, _) = signed_quote
+        .quote
+        .split_at(signed_quote.quote.rfind('.').unwrap() + 1);
+    assert!(test_quote_signer()
+        .verify(&format!("{}{}", message, signature))
+        .is_err());
+
+    // Expired quote is rejected.
+    let expired_quote = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &FeeQuote {
+            exp: (Utc::now() - chrono::Duration::minutes(1)).timestamp() as u64,
+            ..quote.clone()
+        },
+        &jsonwebtoken::EncodingKey::from_secret(b"sample"),
+    )
+    .unwrap();
+    assert!(test_quote_signer().verify(&expired_quote).is_err());
+
+    // Quotes aren't issued if there is no signing secret.
+    let mut ticker = FeeTicker::new(
+        MockApiProvider,
+        MockTickerInfo,
+        mpsc::channel(1).1,
+        get_test_ticker_config(),
+        FeeTokenValidator::new(HashMap::new(), Default::default()),
+        None,
+    );
+    let no_quote = block_on(ticker.get_fee_quote(
+        TxFeeTypes::Withdraw,
+        TokenLike::Id(TestToken::hex().id),
+        sender,
+        address,
+    ))
+    .unwrap();
+    assert!(no_quote.is_none());
+
+    // Different quotes are stored under different hashes.
+    assert_ne!(
+        FeeQuote::hash(&signed_quote.quote),
+        FeeQuote::hash(&other_quote.quote)
+    );
+}
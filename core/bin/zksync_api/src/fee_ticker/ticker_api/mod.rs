@@ -249,6 +249,13 @@ impl<T: TokenPriceAPI + Send + Sync> FeeTickerAPI for TickerApi<T> {
             .as_u64();
         let average_gas_price = BigUint::from(average_gas_price);
 
+        storage
+            .ethereum_schema()
+            .store_gas_price_history(average_gas_price.clone(), Utc::now())
+            .await
+            .map_err(|e| log::warn!("Failed to store gas price history: {}", e))
+            .unwrap_or_default();
+
         *cached_value = Some((average_gas_price.clone(), Instant::now()));
 
         metrics::histogram!("ticker.get_gas_price_wei", start.elapsed());
@@ -1,15 +1,23 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use tokio::sync::RwLock;
 
 use zksync_storage::ConnectionPool;
-use zksync_types::{Token, TokenId, TokenLike};
+use zksync_types::{Token, TokenId, TokenLike, TokenStatus};
+
+/// Lifetime of the cached entries. Token metadata and status may be changed
+/// via the admin server, so entries have to be reloaded from time to time.
+const CACHE_ENTRY_LIFETIME: Duration = Duration::from_secs(60);
 
 #[derive(Debug, Clone)]
 pub struct TokenDBCache {
     pub pool: ConnectionPool,
-    // TODO: handle stale entries, edge case when we rename token after adding it (ZKS-97)
-    cache: Arc<RwLock<HashMap<TokenLike, Token>>>,
+    cache: Arc<RwLock<HashMap<TokenLike, (Token, Instant)>>>,
+    statuses: Arc<RwLock<HashMap<TokenId, (TokenStatus, Instant)>>>,
 }
 
 impl TokenDBCache {
@@ -17,6 +25,7 @@ impl TokenDBCache {
         Self {
             pool,
             cache: Arc::new(RwLock::new(HashMap::new())),
+            statuses: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -49,8 +58,10 @@ impl TokenDBCache {
 
     async fn get_token_impl(&self, token_query: TokenLike) -> anyhow::Result<Option<Token>> {
         // Just return token from cache.
-        if let Some(token) = self.cache.read().await.get(&token_query) {
-            return Ok(Some(token.clone()));
+        if let Some((token, cached_at)) = self.cache.read().await.get(&token_query) {
+            if cached_at.elapsed() < CACHE_ENTRY_LIFETIME {
+                return Ok(Some(token.clone()));
+            }
         }
         // Tries to fetch token from the underlying database.
         let token = {
@@ -62,7 +73,10 @@ impl TokenDBCache {
         };
         // Stores received token into the local cache.
         if let Some(token) = &token {
-            self.cache.write().await.insert(token_query, token.clone());
+            self.cache
+                .write()
+                .await
+                .insert(token_query, (token.clone(), Instant::now()));
         }
 
         Ok(token)
@@ -72,4 +86,24 @@ impl TokenDBCache {
         let token = self.get_token(token_id).await?;
         Ok(token.map(|token| token.symbol))
     }
+
+    /// Returns the administrative status of the token.
+    pub async fn token_status(&self, token_id: TokenId) -> anyhow::Result<TokenStatus> {
+        if let Some((status, cached_at)) = self.statuses.read().await.get(&token_id) {
+            if cached_at.elapsed() < CACHE_ENTRY_LIFETIME {
+                return Ok(*status);
+            }
+        }
+
+        let status = {
+            let mut storage = self.pool.access_storage().await?;
+            storage.tokens_schema().get_token_status(token_id).await?
+        };
+        self.statuses
+            .write()
+            .await
+            .insert(token_id, (status, Instant::now()));
+
+        Ok(status)
+    }
 }
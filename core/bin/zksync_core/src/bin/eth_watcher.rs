@@ -14,13 +14,17 @@ fn main() {
         [2..]
         .parse()
         .expect("Failed to parse CONTRACT_ADDR");
+    let governance_address = std::env::var("GOVERNANCE_ADDR")
+        .expect("GOVERNANCE_ADDR env var not found")[2..]
+        .parse()
+        .expect("Failed to parse GOVERNANCE_ADDR");
     let transport = web3::transports::Http::new(&web3_url).unwrap();
     let web3 = web3::Web3::new(transport);
 
     let (eth_req_sender, eth_req_receiver) = mpsc::channel(256);
 
     let db_pool = ConnectionPool::new(None);
-    let eth_client = EthHttpClient::new(web3, contract_address);
+    let eth_client = EthHttpClient::new(web3, contract_address, governance_address);
 
     let storage = DBStorage::new(db_pool);
 
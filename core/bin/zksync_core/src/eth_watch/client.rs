@@ -1,25 +1,33 @@
 use std::{convert::TryFrom, time::Instant};
 
 use anyhow::format_err;
-use ethabi::Hash;
+use ethabi::{Hash, ParamType};
 use serde::export::fmt::Debug;
 use web3::{
     contract::{Contract, Options},
     transports::Http,
-    types::{BlockNumber, FilterBuilder, Log},
+    types::{BlockNumber, Bytes, CallRequest, FilterBuilder, Log},
     Web3,
 };
 
-use zksync_contracts::zksync_contract;
-use zksync_types::{ethereum::CompleteWithdrawalsTx, Address, Nonce, PriorityOp, H160};
+use zksync_contracts::{governance_contract, zksync_contract};
+use zksync_types::{
+    ethereum::CompleteWithdrawalsTx, tokens::NewTokenEvent, Address, Nonce, PriorityOp, H160,
+};
+
+/// Selector of the ERC20 `symbol()` method.
+const ERC20_SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+/// Selector of the ERC20 `decimals()` method.
+const ERC20_DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
 
 struct ContractTopics {
     new_priority_request: Hash,
     complete_withdrawals_event: Hash,
+    new_token: Hash,
 }
 
 impl ContractTopics {
-    fn new(zksync_contract: &ethabi::Contract) -> Self {
+    fn new(zksync_contract: &ethabi::Contract, governance_contract: &ethabi::Contract) -> Self {
         Self {
             new_priority_request: zksync_contract
                 .event("NewPriorityRequest")
@@ -30,6 +38,11 @@ impl ContractTopics {
                 .event("PendingWithdrawalsComplete")
                 .expect("main contract abi error")
                 .signature(),
+
+            new_token: governance_contract
+                .event("NewToken")
+                .expect("governance contract abi error")
+                .signature(),
         }
     }
 }
@@ -46,7 +59,16 @@ pub trait EthClient {
         from: BlockNumber,
         to: BlockNumber,
     ) -> anyhow::Result<Vec<CompleteWithdrawalsTx>>;
+    async fn get_new_token_events(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> anyhow::Result<Vec<NewTokenEvent>>;
     async fn block_number(&self) -> anyhow::Result<u64>;
+    /// Returns the result of the ERC20 `symbol()` call for the token contract.
+    async fn get_erc20_symbol(&self, token: Address) -> anyhow::Result<String>;
+    /// Returns the result of the ERC20 `decimals()` call for the token contract.
+    async fn get_erc20_decimals(&self, token: Address) -> anyhow::Result<u8>;
     async fn get_auth_fact(&self, address: Address, nonce: Nonce) -> anyhow::Result<Vec<u8>>;
     async fn get_first_pending_withdrawal_index(&self) -> anyhow::Result<u32>;
     async fn get_number_of_pending_withdrawals(&self) -> anyhow::Result<u32>;
@@ -55,23 +77,30 @@ pub trait EthClient {
 pub struct EthHttpClient {
     web3: Web3<Http>,
     zksync_contract: Contract<Http>,
+    governance_contract_addr: H160,
     topics: ContractTopics,
 }
 
 impl EthHttpClient {
-    pub fn new(web3: Web3<Http>, zksync_contract_addr: H160) -> Self {
+    pub fn new(
+        web3: Web3<Http>,
+        zksync_contract_addr: H160,
+        governance_contract_addr: H160,
+    ) -> Self {
         let zksync_contract = Contract::new(web3.eth(), zksync_contract_addr, zksync_contract());
 
-        let topics = ContractTopics::new(zksync_contract.abi());
+        let topics = ContractTopics::new(zksync_contract.abi(), &governance_contract());
         Self {
             zksync_contract,
             web3,
+            governance_contract_addr,
             topics,
         }
     }
 
     async fn get_events<T>(
         &self,
+        contract_addr: H160,
         from: BlockNumber,
         to: BlockNumber,
         topics: Vec<Hash>,
@@ -81,7 +110,7 @@ impl EthHttpClient {
         T::Error: Debug,
     {
         let filter = FilterBuilder::default()
-            .address(vec![self.zksync_contract.address()])
+            .address(vec![contract_addr])
             .from_block(from)
             .to_block(to)
             .topics(Some(topics), None, None, None)
@@ -98,6 +127,19 @@ impl EthHttpClient {
             })
             .collect()
     }
+
+    /// Calls the token contract method without arguments and returns the raw output.
+    async fn call_erc20(&self, token: Address, selector: [u8; 4]) -> anyhow::Result<Vec<u8>> {
+        let request = CallRequest {
+            from: None,
+            to: Some(token),
+            gas: None,
+            gas_price: None,
+            value: None,
+            data: Some(Bytes(selector.to_vec())),
+        };
+        Ok(self.web3.eth().call(request, None).await?.0)
+    }
 }
 
 #[async_trait::async_trait]
@@ -110,7 +152,12 @@ impl EthClient for EthHttpClient {
         let start = Instant::now();
 
         let result = self
-            .get_events(from, to, vec![self.topics.new_priority_request])
+            .get_events(
+                self.zksync_contract.address(),
+                from,
+                to,
+                vec![self.topics.new_priority_request],
+            )
             .await;
         metrics::histogram!("eth_watcher.get_priority_op_events", start.elapsed());
         result
@@ -124,7 +171,12 @@ impl EthClient for EthHttpClient {
         let start = Instant::now();
 
         let result = self
-            .get_events(from, to, vec![self.topics.complete_withdrawals_event])
+            .get_events(
+                self.zksync_contract.address(),
+                from,
+                to,
+                vec![self.topics.complete_withdrawals_event],
+            )
             .await;
 
         metrics::histogram!(
@@ -134,10 +186,65 @@ impl EthClient for EthHttpClient {
         result
     }
 
+    async fn get_new_token_events(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> anyhow::Result<Vec<NewTokenEvent>> {
+        let start = Instant::now();
+
+        let result = self
+            .get_events(
+                self.governance_contract_addr,
+                from,
+                to,
+                vec![self.topics.new_token],
+            )
+            .await;
+
+        metrics::histogram!("eth_watcher.get_new_token_events", start.elapsed());
+        result
+    }
+
     async fn block_number(&self) -> anyhow::Result<u64> {
         Ok(self.web3.eth().block_number().await?.as_u64())
     }
 
+    async fn get_erc20_symbol(&self, token: Address) -> anyhow::Result<String> {
+        let output = self.call_erc20(token, ERC20_SYMBOL_SELECTOR).await?;
+
+        // Most of the tokens return `string`, but some of the older ones (e.g. MKR) return `bytes32`.
+        if let Ok(mut tokens) = ethabi::decode(&[ParamType::String], &output) {
+            if let Some(symbol) = tokens.pop().and_then(|token| token.to_string()) {
+                return Ok(symbol);
+            }
+        }
+        if output.len() == 32 {
+            let symbol: Vec<u8> = output.into_iter().take_while(|&byte| byte != 0).collect();
+            return Ok(String::from_utf8(symbol)?);
+        }
+        Err(format_err!(
+            "Unexpected `symbol()` output of the token {:?}",
+            token
+        ))
+    }
+
+    async fn get_erc20_decimals(&self, token: Address) -> anyhow::Result<u8> {
+        let output = self.call_erc20(token, ERC20_DECIMALS_SELECTOR).await?;
+
+        let decimals = ethabi::decode(&[ParamType::Uint(8)], &output)
+            .ok()
+            .and_then(|mut tokens| tokens.pop())
+            .and_then(|token| token.to_uint())
+            .ok_or_else(|| {
+                format_err!("Unexpected `decimals()` output of the token {:?}", token)
+            })?;
+        if decimals > u8::MAX.into() {
+            return Err(format_err!("Token {:?} decimals are too big", token));
+        }
+        Ok(decimals.as_u32() as u8)
+    }
+
     async fn get_auth_fact(&self, address: Address, nonce: u32) -> anyhow::Result<Vec<u8>> {
         self.zksync_contract
             .query(
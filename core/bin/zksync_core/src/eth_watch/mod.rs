@@ -2,6 +2,9 @@
 //! such as PriorityQueue events or NewToken events.
 //! New events are accepted to the zkSync network once they have the sufficient amount of confirmations.
 //!
+//! Tokens listed in the Governance contract are stored in the database, so the API
+//! components (including the fee ticker) pick them up on the next token lookup.
+//!
 //! Poll interval is configured using the `ETH_POLL_INTERVAL` constant.
 //! Number of confirmations is configured using the `CONFIRMATIONS_FOR_ETH_EVENT` environment variable.
 
@@ -24,7 +27,10 @@ use web3::types::{Address, BlockNumber};
 use zksync_config::ConfigurationOptions;
 use zksync_crypto::params::PRIORITY_EXPIRATION;
 use zksync_storage::ConnectionPool;
-use zksync_types::{Nonce, PriorityOp, PubKeyHash, ZkSyncPriorityOp};
+use zksync_types::{
+    tokens::NewTokenEvent, Nonce, PriorityOp, PubKeyHash, Token, TokenId, TokenLike,
+    ZkSyncPriorityOp,
+};
 
 // Local deps
 use self::{
@@ -49,6 +55,11 @@ mod tests;
 /// before repeating the request.
 const RATE_LIMIT_DELAY: Duration = Duration::from_secs(30);
 
+/// Decimals of the listed token used if its contract doesn't implement `decimals()`.
+const DEFAULT_TOKEN_DECIMALS: u8 = 18;
+/// Maximum length of the token symbol obtained from the token contract.
+const MAX_TOKEN_SYMBOL_LENGTH: usize = 16;
+
 /// Ethereum Watcher operating mode.
 ///
 /// Normally Ethereum watcher will always poll the Ethereum node upon request,
@@ -135,6 +146,18 @@ impl<W: EthClient, S: Storage> EthWatch<W, S> {
     async fn process_new_blocks(&mut self, last_ethereum_block: u64) -> anyhow::Result<()> {
         debug_assert!(self.eth_state.last_ethereum_block() < last_ethereum_block);
 
+        let previous_block_with_accepted_events = self
+            .eth_state
+            .last_ethereum_block()
+            .saturating_sub(self.number_of_confirmations_for_event);
+        let new_block_with_accepted_events =
+            last_ethereum_block.saturating_sub(self.number_of_confirmations_for_event);
+        self.process_new_tokens(
+            previous_block_with_accepted_events + 1,
+            new_block_with_accepted_events,
+        )
+        .await?;
+
         let (unconfirmed_queue, received_priority_queue) = self
             .update_eth_state(last_ethereum_block, self.number_of_confirmations_for_event)
             .await?;
@@ -151,6 +174,14 @@ impl<W: EthClient, S: Storage> EthWatch<W, S> {
     }
 
     async fn restore_state_from_eth(&mut self, last_ethereum_block: u64) -> anyhow::Result<()> {
+        // Tokens could be listed while the server was down, so the whole history is checked.
+        // Tokens that are already known are skipped.
+        self.process_new_tokens(
+            0,
+            last_ethereum_block.saturating_sub(self.number_of_confirmations_for_event),
+        )
+        .await?;
+
         let (unconfirmed_queue, priority_queue) = self
             .update_eth_state(last_ethereum_block, PRIORITY_EXPIRATION)
             .await?;
@@ -187,6 +218,93 @@ impl<W: EthClient, S: Storage> EthWatch<W, S> {
         Ok((unconfirmed_queue, priority_queue))
     }
 
+    /// Lists the tokens added to the Governance contract in the given range of blocks.
+    async fn process_new_tokens(&mut self, from_block: u64, to_block: u64) -> anyhow::Result<()> {
+        if from_block > to_block {
+            return Ok(());
+        }
+
+        let new_tokens = self
+            .client
+            .get_new_token_events(
+                BlockNumber::Number(from_block.into()),
+                BlockNumber::Number(to_block.into()),
+            )
+            .await?;
+        for event in new_tokens {
+            self.list_token(event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the listed token, resolving its metadata from the token contract.
+    /// Token contracts are not required to implement `symbol()` and `decimals()`, so
+    /// if they don't, or return something unusable, the default values are used.
+    async fn list_token(&mut self, event: NewTokenEvent) -> anyhow::Result<()> {
+        if self
+            .storage
+            .get_token(TokenLike::Id(event.id))
+            .await?
+            .is_some()
+        {
+            // Token was either added manually or listed before the restart.
+            return Ok(());
+        }
+
+        let symbol = match self.client.get_erc20_symbol(event.address).await {
+            Ok(symbol) if is_valid_token_symbol(&symbol) => Some(symbol),
+            Ok(symbol) => {
+                log::warn!(
+                    "Token {:?} has an invalid symbol '{}'",
+                    event.address,
+                    symbol
+                );
+                None
+            }
+            Err(error) if self.is_backoff_requested(&error) => return Err(error),
+            Err(error) => {
+                log::warn!(
+                    "Unable to get symbol of token {:?}: {}",
+                    event.address,
+                    error
+                );
+                None
+            }
+        };
+        // Tokens can be requested by the symbol, so it must be unique.
+        let symbol = match symbol {
+            Some(symbol)
+                if self
+                    .storage
+                    .get_token(TokenLike::Symbol(symbol.clone()))
+                    .await?
+                    .is_none() =>
+            {
+                symbol
+            }
+            _ => fallback_token_symbol(event.id),
+        };
+
+        let decimals = match self.client.get_erc20_decimals(event.address).await {
+            Ok(decimals) => decimals,
+            Err(error) if self.is_backoff_requested(&error) => return Err(error),
+            Err(error) => {
+                log::warn!(
+                    "Unable to get decimals of token {:?}: {}",
+                    event.address,
+                    error
+                );
+                DEFAULT_TOKEN_DECIMALS
+            }
+        };
+
+        let token = Token::new(event.id, event.address, &symbol, decimals);
+        self.storage.store_token(token.clone()).await?;
+        log::info!("New token listed: {:?}", token);
+        Ok(())
+    }
+
     fn get_priority_requests(&self, first_serial_id: u64, max_chunks: usize) -> Vec<PriorityOp> {
         let mut result = Vec::new();
 
@@ -365,6 +483,17 @@ impl<W: EthClient, S: Storage> EthWatch<W, S> {
     }
 }
 
+fn is_valid_token_symbol(symbol: &str) -> bool {
+    !symbol.is_empty()
+        && symbol.len() <= MAX_TOKEN_SYMBOL_LENGTH
+        && symbol.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Symbol of the listed token used if the one provided by the token contract can't be used.
+fn fallback_token_symbol(id: TokenId) -> String {
+    format!("ERC20-{}", id)
+}
+
 #[must_use]
 pub fn start_eth_watch(
     config_options: ConfigurationOptions,
@@ -374,7 +503,11 @@ pub fn start_eth_watch(
 ) -> JoinHandle<()> {
     let transport = web3::transports::Http::new(&config_options.web3_url).unwrap();
     let web3 = web3::Web3::new(transport);
-    let eth_client = EthHttpClient::new(web3, config_options.contract_eth_addr);
+    let eth_client = EthHttpClient::new(
+        web3,
+        config_options.contract_eth_addr,
+        config_options.governance_eth_addr,
+    );
 
     let storage = DBStorage::new(db_pool);
 
@@ -1,5 +1,5 @@
 use zksync_storage::ConnectionPool;
-use zksync_types::ethereum::CompleteWithdrawalsTx;
+use zksync_types::{ethereum::CompleteWithdrawalsTx, Token, TokenLike};
 
 #[async_trait::async_trait]
 pub trait Storage {
@@ -7,6 +7,10 @@ pub trait Storage {
         &mut self,
         complete_withdrawals_txs: Vec<CompleteWithdrawalsTx>,
     ) -> anyhow::Result<()>;
+
+    async fn get_token(&mut self, token: TokenLike) -> anyhow::Result<Option<Token>>;
+
+    async fn store_token(&mut self, token: Token) -> anyhow::Result<()>;
 }
 
 pub struct DBStorage {
@@ -27,4 +31,14 @@ impl Storage for DBStorage {
     ) -> anyhow::Result<()> {
         unreachable!()
     }
+
+    async fn get_token(&mut self, token: TokenLike) -> anyhow::Result<Option<Token>> {
+        let mut storage = self.db_pool.access_storage().await?;
+        storage.tokens_schema().get_token(token).await
+    }
+
+    async fn store_token(&mut self, token: Token) -> anyhow::Result<()> {
+        let mut storage = self.db_pool.access_storage().await?;
+        storage.tokens_schema().store_token(token).await
+    }
 }
@@ -3,20 +3,25 @@ use std::collections::HashMap;
 
 use web3::types::{Address, BlockNumber};
 
-use zksync_types::{ethereum::CompleteWithdrawalsTx, Deposit, PriorityOp, ZkSyncPriorityOp};
+use zksync_types::{
+    ethereum::CompleteWithdrawalsTx, tokens::NewTokenEvent, Deposit, PriorityOp, Token, TokenId,
+    TokenLike, ZkSyncPriorityOp,
+};
 
-use crate::eth_watch::{client::EthClient, storage::Storage, EthWatch};
+use crate::eth_watch::{client::EthClient, storage::Storage, EthWatch, DEFAULT_TOKEN_DECIMALS};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 struct FakeStorage {
     withdrawal_txs: Vec<CompleteWithdrawalsTx>,
+    tokens: HashMap<TokenId, Token>,
 }
 
 impl FakeStorage {
     fn new() -> Self {
         Self {
             withdrawal_txs: vec![],
+            tokens: HashMap::new(),
         }
     }
 }
@@ -30,11 +35,34 @@ impl Storage for FakeStorage {
         self.withdrawal_txs.extend(complete_withdrawals_txs);
         Ok(())
     }
+
+    async fn get_token(&mut self, token: TokenLike) -> anyhow::Result<Option<Token>> {
+        let token = self.tokens.values().find(|stored| match &token {
+            TokenLike::Id(id) => stored.id == *id,
+            TokenLike::Address(address) => stored.address == *address,
+            TokenLike::Symbol(symbol) => stored.symbol == *symbol,
+        });
+        Ok(token.cloned())
+    }
+
+    async fn store_token(&mut self, token: Token) -> anyhow::Result<()> {
+        self.tokens.insert(token.id, token);
+        Ok(())
+    }
+}
+
+/// Metadata returned by the fake token contract, `None` means that the call fails.
+#[derive(Clone)]
+struct FakeTokenContract {
+    symbol: Option<String>,
+    decimals: Option<u8>,
 }
 
 struct FakeEthClientData {
     priority_ops: HashMap<u64, Vec<PriorityOp>>,
     withdrawals: HashMap<u64, Vec<CompleteWithdrawalsTx>>,
+    new_tokens: HashMap<u64, Vec<NewTokenEvent>>,
+    token_contracts: HashMap<Address, FakeTokenContract>,
     last_block_number: u64,
 }
 
@@ -43,10 +71,18 @@ impl FakeEthClientData {
         Self {
             priority_ops: Default::default(),
             withdrawals: Default::default(),
+            new_tokens: Default::default(),
+            token_contracts: Default::default(),
             last_block_number: 0,
         }
     }
 
+    fn add_token(&mut self, event: NewTokenEvent, contract: FakeTokenContract, eth_block: u64) {
+        self.last_block_number = max(eth_block, self.last_block_number);
+        self.new_tokens.entry(eth_block).or_default().push(event);
+        self.token_contracts.insert(event.address, contract);
+    }
+
     fn add_operations(&mut self, ops: &[PriorityOp]) {
         for op in ops {
             self.last_block_number = max(op.eth_block, self.last_block_number);
@@ -74,6 +110,28 @@ impl FakeEthClient {
         self.inner.write().await.add_operations(ops);
     }
 
+    async fn add_token(
+        &mut self,
+        event: NewTokenEvent,
+        contract: FakeTokenContract,
+        eth_block: u64,
+    ) {
+        self.inner
+            .write()
+            .await
+            .add_token(event, contract, eth_block);
+    }
+
+    async fn token_contract(&self, token: Address) -> anyhow::Result<FakeTokenContract> {
+        self.inner
+            .read()
+            .await
+            .token_contracts
+            .get(&token)
+            .cloned()
+            .ok_or_else(|| anyhow::format_err!("Unknown token contract"))
+    }
+
     async fn block_to_number(&self, block: &BlockNumber) -> u64 {
         match block {
             BlockNumber::Latest => self.inner.read().await.last_block_number,
@@ -118,10 +176,40 @@ impl EthClient for FakeEthClient {
         Ok(withdrawals)
     }
 
+    async fn get_new_token_events(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Result<Vec<NewTokenEvent>, anyhow::Error> {
+        let from = self.block_to_number(&from).await;
+        let to = self.block_to_number(&to).await;
+        let mut events = vec![];
+        for number in from..=to {
+            if let Some(block_events) = self.inner.read().await.new_tokens.get(&number) {
+                events.extend_from_slice(block_events);
+            }
+        }
+        Ok(events)
+    }
+
     async fn block_number(&self) -> Result<u64, anyhow::Error> {
         Ok(self.inner.read().await.last_block_number)
     }
 
+    async fn get_erc20_symbol(&self, token: Address) -> Result<String, anyhow::Error> {
+        self.token_contract(token)
+            .await?
+            .symbol
+            .ok_or_else(|| anyhow::format_err!("symbol() reverted"))
+    }
+
+    async fn get_erc20_decimals(&self, token: Address) -> Result<u8, anyhow::Error> {
+        self.token_contract(token)
+            .await?
+            .decimals
+            .ok_or_else(|| anyhow::format_err!("decimals() reverted"))
+    }
+
     async fn get_auth_fact(
         &self,
         _address: Address,
@@ -263,3 +351,92 @@ async fn test_restore_and_poll() {
     let deposits = watcher.get_ongoing_deposits_for([2u8; 20].into());
     assert_eq!(deposits.len(), 1);
 }
+
+/// Checks that tokens listed in the Governance contract are stored once confirmed,
+/// and that the fallback metadata is used for the non-standard tokens.
+#[tokio::test]
+async fn test_new_tokens_listing() {
+    let mut client = FakeEthClient::new();
+    let tokens = vec![
+        (
+            NewTokenEvent {
+                address: [1u8; 20].into(),
+                id: 1,
+            },
+            FakeTokenContract {
+                symbol: Some("DAI".to_string()),
+                decimals: Some(18),
+            },
+            2,
+        ),
+        // Token without metadata methods.
+        (
+            NewTokenEvent {
+                address: [2u8; 20].into(),
+                id: 2,
+            },
+            FakeTokenContract {
+                symbol: None,
+                decimals: None,
+            },
+            3,
+        ),
+        // Token with the symbol that is already taken.
+        (
+            NewTokenEvent {
+                address: [3u8; 20].into(),
+                id: 3,
+            },
+            FakeTokenContract {
+                symbol: Some("DAI".to_string()),
+                decimals: Some(6),
+            },
+            4,
+        ),
+    ];
+    for (event, contract, eth_block) in tokens {
+        client.add_token(event, contract, eth_block).await;
+    }
+
+    let mut watcher = create_watcher(client.clone());
+    watcher.restore_state_from_eth(4).await.unwrap();
+    // The last token doesn't have enough confirmations yet.
+    assert_eq!(watcher.storage.tokens.len(), 2);
+    assert_eq!(
+        watcher.storage.tokens[&1],
+        Token::new(1, [1u8; 20].into(), "DAI", 18)
+    );
+    assert_eq!(
+        watcher.storage.tokens[&2],
+        Token::new(2, [2u8; 20].into(), "ERC20-2", DEFAULT_TOKEN_DECIMALS)
+    );
+
+    // Once the block with the token is confirmed, token is listed.
+    client
+        .add_token(
+            NewTokenEvent {
+                address: [4u8; 20].into(),
+                id: 4,
+            },
+            FakeTokenContract {
+                symbol: Some("USDC".to_string()),
+                decimals: Some(6),
+            },
+            5,
+        )
+        .await;
+    watcher.poll_eth_node().await.unwrap();
+    assert_eq!(watcher.storage.tokens.len(), 3);
+    assert_eq!(
+        watcher.storage.tokens[&3],
+        Token::new(3, [3u8; 20].into(), "ERC20-3", 6)
+    );
+
+    // Tokens that are already stored are not overwritten.
+    watcher
+        .storage
+        .tokens
+        .insert(1, Token::new(1, [1u8; 20].into(), "DAI2", 18));
+    watcher.restore_state_from_eth(5).await.unwrap();
+    assert_eq!(watcher.storage.tokens[&1].symbol, "DAI2");
+}
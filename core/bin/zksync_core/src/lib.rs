@@ -11,6 +11,7 @@ use crate::{
 use futures::{channel::mpsc, future};
 use tokio::task::JoinHandle;
 use zksync_config::{ApiServerOptions, ConfigurationOptions};
+use zksync_crypto::merkle_tree::disk_storage::DiskTreeStorage;
 use zksync_storage::ConnectionPool;
 
 const DEFAULT_CHANNEL_CAPACITY: usize = 32_768;
@@ -112,7 +113,13 @@ pub async fn run_core(
     let mut storage_processor = connection_pool.access_storage().await?;
 
     // Start State Keeper.
-    let state_keeper_init = ZkSyncStateInitParams::restore_from_db(&mut storage_processor).await?;
+    let tree_storage = config_opts
+        .account_tree_storage_path
+        .as_ref()
+        .map(DiskTreeStorage::open)
+        .transpose()?;
+    let state_keeper_init =
+        ZkSyncStateInitParams::restore_from_db(&mut storage_processor, tree_storage).await?;
     let pending_block = state_keeper_init
         .get_pending_block(&mut storage_processor)
         .await;
@@ -11,6 +11,7 @@ use tokio::task::JoinHandle;
 // Workspace uses
 use zksync_crypto::ff;
 use zksync_crypto::ff::{PrimeField, PrimeFieldRepr};
+use zksync_crypto::merkle_tree::disk_storage::DiskTreeStorage;
 use zksync_state::state::{CollectedFee, OpSuccess, ZkSyncState};
 use zksync_storage::ConnectionPool;
 use zksync_types::{
@@ -111,6 +112,9 @@ pub struct ZkSyncStateKeeper {
     fast_miniblock_iterations: usize,
     max_number_of_withdrawals_per_block: usize,
 
+    /// Disk storage of the account tree, see `ZkSyncStateInitParams::tree_storage`.
+    tree_storage: Option<DiskTreeStorage>,
+
     // Two fields below are for optimization: we don't want to overwrite all the block contents over and over.
     // With these fields we'll be able save the diff between two pending block states only.
     /// Amount of succeeded transactions in the pending block at the last pending block synchronization step.
@@ -124,6 +128,8 @@ pub struct ZkSyncStateInitParams {
     pub acc_id_by_addr: HashMap<Address, AccountId>,
     pub last_block_number: BlockNumber,
     pub unprocessed_priority_op: u64,
+    /// Disk storage of the account tree, updated by the state keeper with every sealed block.
+    pub tree_storage: Option<DiskTreeStorage>,
 }
 
 impl Default for ZkSyncStateInitParams {
@@ -139,6 +145,7 @@ impl ZkSyncStateInitParams {
             acc_id_by_addr: HashMap::new(),
             last_block_number: 0,
             unprocessed_priority_op: 0,
+            tree_storage: None,
         }
     }
 
@@ -169,16 +176,80 @@ impl ZkSyncStateInitParams {
         Some(pending_block)
     }
 
+    /// Restores the state keeper state from the database.
+    ///
+    /// If the disk tree storage is provided, the account tree is restored from it (if the stored
+    /// tree is consistent with the database), and the restored tree is written back to it.
+    /// The storage is then passed to the state keeper to persist the tree of every sealed block.
     pub async fn restore_from_db(
         storage: &mut zksync_storage::StorageProcessor<'_>,
+        tree_storage: Option<DiskTreeStorage>,
     ) -> Result<Self, anyhow::Error> {
         let mut init_params = Self::new();
-        init_params.load_from_db(storage).await?;
+        init_params
+            .load_from_db(storage, tree_storage.as_ref())
+            .await?;
+        init_params.tree_storage = tree_storage;
 
         Ok(init_params)
     }
 
-    async fn load_account_tree(
+    /// Loads the account tree from the disk storage.
+    /// Returns the number of the block the loaded tree corresponds to, or `None` if there is
+    /// no stored tree or it doesn't match the committed state.
+    async fn load_account_tree_from_disk(
+        &mut self,
+        storage: &mut zksync_storage::StorageProcessor<'_>,
+        tree_storage: &DiskTreeStorage,
+    ) -> Result<Option<BlockNumber>, anyhow::Error> {
+        let (stored_block, stored_root_hash) = match tree_storage.stored_block()? {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+
+        // Blocks could be reverted after the tree was stored, in which case the stored tree
+        // may not correspond to the committed state anymore.
+        let last_committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_block()
+            .await?;
+        let block = if stored_block <= last_committed_block {
+            storage
+                .chain()
+                .block_schema()
+                .get_block(stored_block)
+                .await?
+        } else {
+            None
+        };
+        if block.map(|block| block.new_root_hash) != Some(stored_root_hash) {
+            log::warn!(
+                "Account tree stored on disk for block {} doesn't match the committed state, \
+                 restoring it from the database",
+                stored_block
+            );
+            return Ok(None);
+        }
+
+        let (block, tree): (BlockNumber, AccountTree) = match tree_storage.load()? {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+        self.acc_id_by_addr = tree
+            .items
+            .iter()
+            .filter(|(_, account)| account.address != Address::zero())
+            .map(|(id, account)| (account.address, *id as AccountId))
+            .collect();
+        self.tree = tree;
+
+        Ok(Some(block))
+    }
+
+    /// Loads the account tree from the cached state stored in the database.
+    /// Returns the number of the block the loaded tree corresponds to.
+    async fn load_account_tree_from_db(
         &mut self,
         storage: &mut zksync_storage::StorageProcessor<'_>,
     ) -> Result<BlockNumber, anyhow::Error> {
@@ -222,6 +293,26 @@ impl ZkSyncStateInitParams {
                 .await?;
         }
 
+        Ok(last_cached_block_number)
+    }
+
+    async fn load_account_tree(
+        &mut self,
+        storage: &mut zksync_storage::StorageProcessor<'_>,
+        tree_storage: Option<&DiskTreeStorage>,
+    ) -> Result<BlockNumber, anyhow::Error> {
+        let disk_block_number = match tree_storage {
+            Some(tree_storage) => {
+                self.load_account_tree_from_disk(storage, tree_storage)
+                    .await?
+            }
+            None => None,
+        };
+        let last_cached_block_number = match disk_block_number {
+            Some(block_number) => block_number,
+            None => self.load_account_tree_from_db(storage).await?,
+        };
+
         let (block_number, accounts) = storage
             .chain()
             .state_schema()
@@ -264,14 +355,21 @@ impl ZkSyncStateInitParams {
                 "restored root_hash is different"
             );
         }
+
+        if let Some(tree_storage) = tree_storage {
+            if disk_block_number != Some(block_number) {
+                tree_storage.save(block_number, &self.tree)?;
+            }
+        }
         Ok(block_number)
     }
 
     async fn load_from_db(
         &mut self,
         storage: &mut zksync_storage::StorageProcessor<'_>,
+        tree_storage: Option<&DiskTreeStorage>,
     ) -> Result<(), anyhow::Error> {
-        let block_number = self.load_account_tree(storage).await?;
+        let block_number = self.load_account_tree(storage, tree_storage).await?;
         self.last_block_number = block_number;
         self.unprocessed_priority_op =
             Self::unprocessed_priority_op_id(storage, block_number).await?;
@@ -403,6 +501,7 @@ impl ZkSyncStateKeeper {
             max_miniblock_iterations,
             fast_miniblock_iterations,
             max_number_of_withdrawals_per_block,
+            tree_storage: initial_state.tree_storage,
 
             success_txs_pending_len: 0,
             failed_txs_pending_len: 0,
@@ -949,6 +1048,10 @@ impl ZkSyncStateKeeper {
             first_update_order_id,
         };
         pending_block.stored_account_updates = pending_block.account_updates.len();
+        self.persist_tree(
+            self.state.block_number,
+            pending_block.account_updates.iter().map(|(id, _)| *id),
+        );
         self.state.block_number += 1;
 
         log::info!(
@@ -968,6 +1071,33 @@ impl ZkSyncStateKeeper {
         metrics::histogram!("state_keeper.seal_pending_block", start.elapsed());
     }
 
+    /// Writes the changes of the sealed block to the disk storage of the account tree.
+    ///
+    /// The block is persisted by the committer after it's sealed, so the stored tree may get
+    /// ahead of the database. Such a tree is discarded on restart, see `load_account_tree_from_disk`.
+    /// If the update fails, the storage is no longer updated, and is restored on restart.
+    fn persist_tree(
+        &mut self,
+        block_number: BlockNumber,
+        updated_accounts: impl Iterator<Item = AccountId>,
+    ) {
+        let tree_storage = match &self.tree_storage {
+            Some(tree_storage) => tree_storage,
+            None => return,
+        };
+
+        let start = Instant::now();
+        if let Err(err) = tree_storage.update(block_number, self.state.tree(), updated_accounts) {
+            log::error!(
+                "Failed to persist the account tree for block {}: {}",
+                block_number,
+                err
+            );
+            self.tree_storage = None;
+        }
+        metrics::histogram!("state_keeper.persist_tree", start.elapsed());
+    }
+
     /// Stores intermediate representation of a pending block in the database,
     /// so the executed transactions are persisted and won't be lost.
     async fn store_pending_block(&mut self) {
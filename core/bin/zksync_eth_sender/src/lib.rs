@@ -729,6 +729,11 @@ impl<ETH: EthereumInterface, DB: DatabaseInterface> ETHSender<ETH, DB> {
                 self.ethereum
                     .encode_tx_data("executeBlocks", args.as_slice())
             }
+            AggregatedOperation::RevertBlocks(operation) => {
+                let args = operation.get_eth_tx_args();
+                self.ethereum
+                    .encode_tx_data("revertBlocks", args.as_slice())
+            }
         }
     }
 
@@ -1,5 +1,7 @@
 // Built-in deps
-use std::{collections::HashSet, env, net::SocketAddr, str::FromStr, time::Duration};
+use std::{
+    collections::HashSet, env, net::SocketAddr, path::PathBuf, str::FromStr, time::Duration,
+};
 // External uses
 use url::Url;
 // Workspace uses
@@ -193,9 +195,18 @@ pub struct FeeTickerOptions {
     pub disabled_tokens: HashSet<Address>,
     /// Tokens for which subsidies are disabled.
     pub not_subsidized_tokens: HashSet<Address>,
+    /// How long the history of the token and gas prices is kept.
+    pub price_history_retention: Duration,
+    /// Secret used to sign the fee quotes. Fee quotes are disabled if it's not set.
+    pub fee_quote_secret: Option<String>,
+    /// How long the issued fee quote is accepted by the server.
+    pub fee_quote_validity: Duration,
 }
 
 impl FeeTickerOptions {
+    const DEFAULT_PRICE_HISTORY_RETENTION_DAYS: u64 = 90;
+    const DEFAULT_FEE_QUOTE_VALIDITY_SECS: u64 = 60;
+
     fn comma_separated_addresses(name: &str) -> HashSet<Address> {
         get_env(name)
             .split(',')
@@ -204,11 +215,31 @@ impl FeeTickerOptions {
     }
 
     pub fn from_env() -> Self {
+        let fee_quote_secret = parse_env_if_exists::<String>("TICKER_FEE_QUOTE_SECRET");
+        let network = get_env("ETH_NETWORK");
+
+        // Anyone can issue quotes signed with the untrusted key, so it can't be used for production.
+        if fee_quote_secret.as_deref() == Some("sample") && &network != "localhost" {
+            panic!("Fee quote signing secret set to 'sample', this is an incorrect value for production");
+        }
+
         Self {
             token_price_source: TokenPriceSource::from_env(),
             fast_processing_coeff: parse_env("TICKER_FAST_PROCESSING_COEFF"),
             disabled_tokens: Self::comma_separated_addresses("TICKER_DISABLED_TOKENS"),
             not_subsidized_tokens: Self::comma_separated_addresses("NOT_SUBSIDIZED_TOKENS"),
+            price_history_retention: Duration::from_secs(
+                parse_env_if_exists::<u64>("TICKER_PRICE_HISTORY_RETENTION_DAYS")
+                    .unwrap_or(Self::DEFAULT_PRICE_HISTORY_RETENTION_DAYS)
+                    * 24
+                    * 60
+                    * 60,
+            ),
+            fee_quote_secret,
+            fee_quote_validity: Duration::from_secs(
+                parse_env_if_exists("TICKER_FEE_QUOTE_VALIDITY_SECS")
+                    .unwrap_or(Self::DEFAULT_FEE_QUOTE_VALIDITY_SECS),
+            ),
         }
     }
 }
@@ -227,9 +258,16 @@ pub struct ApiServerOptions {
     // Limit the number of both transactions and Ethereum signatures per batch.
     pub max_number_of_transactions_per_batch: usize,
     pub max_number_of_authors_per_batch: usize,
+    /// Base interval between the webhook delivery attempts, doubled after each failed attempt.
+    pub webhook_retry_interval: Duration,
+    /// Number of attempts after which the webhook delivery is considered failed.
+    pub webhook_max_attempts: u32,
 }
 
 impl ApiServerOptions {
+    const DEFAULT_WEBHOOK_RETRY_INTERVAL_SECS: u64 = 10;
+    const DEFAULT_WEBHOOK_MAX_ATTEMPTS: u32 = 10;
+
     pub fn from_env() -> Self {
         let forced_exit_minimum_account_age =
             Duration::from_secs(parse_env::<u64>("FORCED_EXIT_MINIMUM_ACCOUNT_AGE_SECS"));
@@ -251,6 +289,12 @@ impl ApiServerOptions {
                 .unwrap_or(true),
             max_number_of_transactions_per_batch: parse_env("MAX_TRANSACTIONS_PER_BATCH"),
             max_number_of_authors_per_batch: parse_env("MAX_ETH_SIGNATURES_PER_BATCH"),
+            webhook_retry_interval: Duration::from_secs(
+                parse_env_if_exists("WEBHOOK_RETRY_INTERVAL_SECS")
+                    .unwrap_or(Self::DEFAULT_WEBHOOK_RETRY_INTERVAL_SECS),
+            ),
+            webhook_max_attempts: parse_env_if_exists("WEBHOOK_MAX_ATTEMPTS")
+                .unwrap_or(Self::DEFAULT_WEBHOOK_MAX_ATTEMPTS),
         }
     }
 }
@@ -270,6 +314,9 @@ pub struct ConfigurationOptions {
     pub miniblock_timings: MiniblockTimings,
     pub prometheus_export_port: u16,
     pub aggregated_proof_sizes: Vec<usize>,
+    /// Path to the disk storage of the account tree used by the state keeper.
+    /// If not set, the account tree is restored from the database on every start.
+    pub account_tree_storage_path: Option<PathBuf>,
 }
 
 impl ConfigurationOptions {
@@ -315,6 +362,7 @@ impl ConfigurationOptions {
             miniblock_timings: MiniblockTimings::from_env(),
             prometheus_export_port: parse_env("PROMETHEUS_EXPORT_PORT"),
             aggregated_proof_sizes,
+            account_tree_storage_path: parse_env_if_exists("ACCOUNT_TREE_STORAGE_PATH"),
         }
     }
 }
@@ -1,16 +1,16 @@
 // Built-in deps
 // External uses
 use num::BigUint;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 // Workspace uses
-use zksync_types::{AccountId, Address, Nonce, PubKeyHash, TokenId};
+use zksync_types::{tx::TxHash, AccountId, Address, Nonce, PubKeyHash, TokenId};
 use zksync_utils::{
     BigUintSerdeAsRadix10Str, OptionBytesToHexSerde, ZeroPrefixHexSerde, ZeroxPrefix,
 };
 // Local uses
 use super::{config_path, load_json};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Config {
     #[serde(rename = "cryptoPrimitivesTest")]
     pub crypto_primitives: TestSet<CryptoPrimitiveInput, CryptoPrimitiveOutput>,
@@ -26,19 +26,19 @@ impl Config {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestSet<I, O> {
     pub description: String,
     pub items: Vec<TestEntry<I, O>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestEntry<I, O> {
     pub inputs: I,
     pub outputs: O,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CryptoPrimitiveInput {
     #[serde(with = "ZeroPrefixHexSerde")]
     pub seed: Vec<u8>,
@@ -46,7 +46,7 @@ pub struct CryptoPrimitiveInput {
     pub message: Vec<u8>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CryptoPrimitiveOutput {
     #[serde(with = "ZeroPrefixHexSerde")]
@@ -55,7 +55,7 @@ pub struct CryptoPrimitiveOutput {
     pub signature: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TxInput {
     #[serde(with = "ZeroPrefixHexSerde")]
@@ -64,7 +64,7 @@ pub struct TxInput {
     pub data: TxData,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum TxData {
     #[serde(rename_all = "camelCase")]
@@ -86,7 +86,7 @@ pub enum TxData {
     ForcedExit { data: Box<ForcedExit> },
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
     pub account_id: AccountId,
@@ -100,7 +100,7 @@ pub struct Transfer {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangePubKey {
     pub account_id: AccountId,
@@ -112,7 +112,7 @@ pub struct ChangePubKey {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Withdraw {
     pub account_id: AccountId,
@@ -126,7 +126,7 @@ pub struct Withdraw {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ForcedExit {
     pub initiator_account_id: AccountId,
@@ -138,7 +138,7 @@ pub struct ForcedExit {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransferSignatureInputs {
     pub string_amount: String,
@@ -149,7 +149,7 @@ pub struct TransferSignatureInputs {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawSignatureInputs {
     pub string_amount: String,
@@ -160,7 +160,7 @@ pub struct WithdrawSignatureInputs {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangePubKeySignatureInputs {
     pub pub_key_hash: PubKeyHash,
@@ -168,25 +168,27 @@ pub struct ChangePubKeySignatureInputs {
     pub nonce: Nonce,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TxOutput {
     #[serde(with = "ZeroPrefixHexSerde")]
     pub sign_bytes: Vec<u8>,
     pub signature: Signature,
+    /// Message signed by the Ethereum key. Binary messages are represented in the hexadecimal form.
     pub eth_sign_message: Option<String>,
     #[serde(with = "OptionBytesToHexSerde::<ZeroxPrefix>")]
     pub eth_signature: Option<Vec<u8>>,
+    pub tx_hash: TxHash,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Signature {
     pub pub_key: String,
     pub signature: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UtilsTests {
     pub amount_packing: TestSet<PackingInput, PackingOutput>,
@@ -194,13 +196,13 @@ pub struct UtilsTests {
     pub token_formatting: TestSet<TokenFormattingInput, TokenFormattingOutput>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PackingInput {
     #[serde(with = "BigUintSerdeAsRadix10Str")]
     pub value: BigUint,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PackingOutput {
     pub packable: bool,
@@ -210,7 +212,7 @@ pub struct PackingOutput {
     pub packed_value: Vec<u8>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TokenFormattingInput {
     pub token: String,
     pub decimals: u8,
@@ -218,7 +220,7 @@ pub struct TokenFormattingInput {
     pub amount: BigUint,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TokenFormattingOutput {
     pub formatted: String,
 }
@@ -36,6 +36,29 @@ pub struct CircuitAccount<E: RescueEngine> {
 
 impl<E: RescueEngine> GetBits for CircuitAccount<E> {
     fn get_bits_le(&self) -> Vec<bool> {
+        Self::leaf_bits(
+            &self.subtree.hasher,
+            self.nonce,
+            self.pub_key_hash,
+            self.address,
+            self.subtree.root_hash(),
+        )
+    }
+}
+
+impl<E: RescueEngine> CircuitAccount<E> {
+    /// Returns the content of the account tree leaf for the account with the provided fields
+    /// and the root hash of its balance tree.
+    ///
+    /// Unlike `get_bits_le`, doesn't require the whole balance tree, so it can be used to
+    /// check the Merkle proofs for the account having only the balance tree path.
+    pub fn leaf_bits(
+        hasher: &RescueHasher<E>,
+        nonce: E::Fr,
+        pub_key_hash: E::Fr,
+        address: E::Fr,
+        balance_root: E::Fr,
+    ) -> Vec<bool> {
         debug_assert_eq!(
             params::FR_BIT_WIDTH,
             E::Fr::NUM_BITS as usize,
@@ -43,17 +66,16 @@ impl<E: RescueEngine> GetBits for CircuitAccount<E> {
         );
         let mut leaf_content = Vec::new();
 
-        leaf_content.extend(self.nonce.get_bits_le_fixed(params::NONCE_BIT_WIDTH)); //32
+        leaf_content.extend(nonce.get_bits_le_fixed(params::NONCE_BIT_WIDTH)); //32
         leaf_content.extend(
-            self.pub_key_hash
-                .get_bits_le_fixed(params::NEW_PUBKEY_HASH_WIDTH), //160
+            pub_key_hash.get_bits_le_fixed(params::NEW_PUBKEY_HASH_WIDTH), //160
         );
         leaf_content.extend(
-            self.address.get_bits_le_fixed(params::ADDRESS_WIDTH), //160
+            address.get_bits_le_fixed(params::ADDRESS_WIDTH), //160
         );
 
         // calculate hash of the subroot using algebraic hash
-        let state_root = self.get_state_root();
+        let state_root = Self::state_root(hasher, balance_root);
 
         let mut state_tree_hash_bits = state_root.get_bits_le_fixed(params::FR_BIT_WIDTH);
         state_tree_hash_bits.resize(params::FR_BIT_WIDTH_PADDED, false);
@@ -68,17 +90,11 @@ impl<E: RescueEngine> GetBits for CircuitAccount<E> {
 
         leaf_content
     }
-}
-
-impl<E: RescueEngine> CircuitAccount<E> {
-    fn get_state_root(&self) -> E::Fr {
-        let balance_root = self.subtree.root_hash();
 
+    fn state_root(hasher: &RescueHasher<E>, balance_root: E::Fr) -> E::Fr {
         let state_root_padding = E::Fr::zero();
 
-        self.subtree
-            .hasher
-            .hash_elements(vec![balance_root, state_root_padding])
+        hasher.hash_elements(vec![balance_root, state_root_padding])
     }
 }
 
@@ -0,0 +1,348 @@
+//! Disk storage of the sparse Merkle tree backed by RocksDB.
+//!
+//! Building the tree from scratch requires calculating the hashes of all its nodes, which
+//! gets slower as the number of items grows. Storage keeps the tree items together with
+//! the calculated node hashes, so the tree can be restored without any hashing.
+
+use std::{collections::HashSet, path::Path};
+
+use anyhow::{ensure, format_err};
+use rocksdb::{ColumnFamily, IteratorMode, Options, WriteBatch, DB};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{hasher::Hasher, parallel_smt::SparseMerkleTree};
+use crate::convert::FeConvert;
+use crate::primitives::GetBits;
+use crate::Fr;
+
+/// Column family with the serialized tree items, indexed by the item index.
+const ITEMS_CF: &str = "items";
+/// Column family with the node hashes, indexed by the node position in the full tree.
+const HASHES_CF: &str = "hashes";
+/// Column family with the stored tree metadata.
+const META_CF: &str = "meta";
+
+const BLOCK_KEY: &[u8] = b"block";
+const ROOT_HASH_KEY: &[u8] = b"root_hash";
+const DEPTH_KEY: &[u8] = b"depth";
+
+/// Disk storage of the sparse Merkle tree.
+///
+/// Storage keeps a single snapshot of the tree, associated with the number of the
+/// block the tree state corresponds to. Snapshot is always written atomically.
+#[derive(Debug)]
+pub struct DiskTreeStorage {
+    db: DB,
+}
+
+impl DiskTreeStorage {
+    /// Opens the storage at the provided path, creating it if required.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        let db = DB::open_cf(&options, path, &[ITEMS_CF, HASHES_CF, META_CF])?;
+        Ok(Self { db })
+    }
+
+    /// Returns the number of the block and the root hash of the stored tree, if any.
+    pub fn stored_block(&self) -> Result<Option<(u32, Fr)>, anyhow::Error> {
+        let meta = self.cf(META_CF)?;
+        let block = match self.db.get_cf(meta, BLOCK_KEY)? {
+            Some(block) => decode_u32(&block)?,
+            None => return Ok(None),
+        };
+        let root_hash = self
+            .db
+            .get_cf(meta, ROOT_HASH_KEY)?
+            .ok_or_else(|| format_err!("Root hash of the stored tree is missing"))?;
+
+        Ok(Some((block, Fr::from_bytes(&root_hash)?)))
+    }
+
+    /// Loads the stored tree together with the number of the block it corresponds to.
+    ///
+    /// Returns an error if the stored tree is malformed, or its root hash doesn't match
+    /// the stored one.
+    pub fn load<T, H>(&self) -> Result<Option<(u32, SparseMerkleTree<T, Fr, H>)>, anyhow::Error>
+    where
+        T: GetBits + Default + Sync + DeserializeOwned,
+        H: Hasher<Fr> + Default + Sync,
+    {
+        let (block, expected_root_hash) = match self.stored_block()? {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+        let depth = self
+            .db
+            .get_cf(self.cf(META_CF)?, DEPTH_KEY)?
+            .ok_or_else(|| format_err!("Depth of the stored tree is missing"))?;
+        let mut tree = SparseMerkleTree::new(decode_u32(&depth)? as usize);
+
+        for (key, value) in self.db.iterator_cf(self.cf(ITEMS_CF)?, IteratorMode::Start) {
+            tree.insert(decode_u32(&key)?, serde_json::from_slice(&value)?);
+        }
+
+        let mut hashes = Vec::new();
+        for (key, value) in self
+            .db
+            .iterator_cf(self.cf(HASHES_CF)?, IteratorMode::Start)
+        {
+            hashes.push((decode_u64(&key)?, Fr::from_bytes(&value)?));
+        }
+        tree.extend_cache(hashes);
+
+        ensure!(
+            tree.root_hash() == expected_root_hash,
+            "Root hash of the stored tree doesn't match the stored items"
+        );
+        Ok(Some((block, tree)))
+    }
+
+    /// Replaces the stored tree with the provided one.
+    ///
+    /// The whole tree is rewritten, so it's only meant for the initial save. Use `update`
+    /// to persist the changes of every following block.
+    pub fn save<T, H>(
+        &self,
+        block: u32,
+        tree: &SparseMerkleTree<T, Fr, H>,
+    ) -> Result<(), anyhow::Error>
+    where
+        T: GetBits + Default + Sync + Serialize,
+        H: Hasher<Fr> + Sync,
+    {
+        let mut batch = WriteBatch::default();
+        self.clear_cf(&mut batch, ITEMS_CF)?;
+        self.clear_cf(&mut batch, HASHES_CF)?;
+
+        let items = self.cf(ITEMS_CF)?;
+        for (index, item) in &tree.items {
+            batch.put_cf(
+                items,
+                &(*index as u32).to_be_bytes(),
+                serde_json::to_vec(item)?,
+            );
+        }
+        let hashes = self.cf(HASHES_CF)?;
+        for (index, hash) in tree.cached_hashes() {
+            batch.put_cf(hashes, &index.to_be_bytes(), hash.to_bytes());
+        }
+
+        self.put_meta(&mut batch, block, tree)?;
+        self.db.write(batch)?;
+        Ok(())
+    }
+
+    /// Updates the stored tree to the provided one, writing only the provided updated items
+    /// and the node hashes on their paths to the root.
+    ///
+    /// The stored tree is expected to correspond to the previous block, and `updated_items`
+    /// to contain the indices of all the items changed since then. Returns an error if
+    /// the stored tree corresponds to another block, in which case it should be
+    /// replaced via `save`.
+    pub fn update<T, H>(
+        &self,
+        block: u32,
+        tree: &SparseMerkleTree<T, Fr, H>,
+        updated_items: impl IntoIterator<Item = u32>,
+    ) -> Result<(), anyhow::Error>
+    where
+        T: GetBits + Default + Sync + Serialize,
+        H: Hasher<Fr> + Sync,
+    {
+        let stored_block = self.stored_block()?.map(|(block, _)| block);
+        ensure!(
+            block > 0 && stored_block == Some(block - 1),
+            "Stored tree corresponds to the block {:?}, can't update it to the block {}",
+            stored_block,
+            block
+        );
+
+        let mut updated_items: Vec<_> = updated_items.into_iter().collect();
+        updated_items.sort_unstable();
+        updated_items.dedup();
+
+        let mut batch = WriteBatch::default();
+        let items = self.cf(ITEMS_CF)?;
+        let hashes = self.cf(HASHES_CF)?;
+        let mut updated_nodes = HashSet::new();
+        for index in updated_items {
+            match tree.get(index) {
+                Some(item) => batch.put_cf(items, &index.to_be_bytes(), serde_json::to_vec(item)?),
+                None => batch.delete_cf(items, &index.to_be_bytes()),
+            }
+
+            // Paths of the updated items share the nodes closer to the root.
+            for (node, hash) in tree.cached_path_hashes(index) {
+                if !updated_nodes.insert(node) {
+                    continue;
+                }
+                match hash {
+                    Some(hash) => batch.put_cf(hashes, &node.to_be_bytes(), hash.to_bytes()),
+                    None => batch.delete_cf(hashes, &node.to_be_bytes()),
+                }
+            }
+        }
+
+        self.put_meta(&mut batch, block, tree)?;
+        self.db.write(batch)?;
+        Ok(())
+    }
+
+    /// Removes the stored tree.
+    pub fn clear(&self) -> Result<(), anyhow::Error> {
+        let mut batch = WriteBatch::default();
+        for cf in &[ITEMS_CF, HASHES_CF, META_CF] {
+            self.clear_cf(&mut batch, cf)?;
+        }
+        self.db.write(batch)?;
+        Ok(())
+    }
+
+    fn put_meta<T, H>(
+        &self,
+        batch: &mut WriteBatch,
+        block: u32,
+        tree: &SparseMerkleTree<T, Fr, H>,
+    ) -> Result<(), anyhow::Error>
+    where
+        T: GetBits + Default + Sync,
+        H: Hasher<Fr> + Sync,
+    {
+        let meta = self.cf(META_CF)?;
+        batch.put_cf(meta, BLOCK_KEY, &block.to_be_bytes());
+        batch.put_cf(meta, ROOT_HASH_KEY, tree.root_hash().to_bytes());
+        batch.put_cf(
+            meta,
+            DEPTH_KEY,
+            &tree.capacity().trailing_zeros().to_be_bytes(),
+        );
+        Ok(())
+    }
+
+    fn clear_cf(&self, batch: &mut WriteBatch, name: &str) -> Result<(), anyhow::Error> {
+        let cf = self.cf(name)?;
+        for (key, _) in self.db.iterator_cf(cf, IteratorMode::Start) {
+            batch.delete_cf(cf, key);
+        }
+        Ok(())
+    }
+
+    fn cf(&self, name: &str) -> Result<&ColumnFamily, anyhow::Error> {
+        self.db
+            .cf_handle(name)
+            .ok_or_else(|| format_err!("Column family {} is missing", name))
+    }
+}
+
+fn decode_u32(bytes: &[u8]) -> Result<u32, anyhow::Error> {
+    let mut buf = [0u8; 4];
+    ensure!(bytes.len() == buf.len(), "Malformed stored u32 value");
+    buf.copy_from_slice(bytes);
+    Ok(u32::from_be_bytes(buf))
+}
+
+fn decode_u64(bytes: &[u8]) -> Result<u64, anyhow::Error> {
+    let mut buf = [0u8; 8];
+    ensure!(bytes.len() == buf.len(), "Malformed stored u64 value");
+    buf.copy_from_slice(bytes);
+    Ok(u64::from_be_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::RescueHasher;
+    use crate::Engine;
+
+    type TestTree = SparseMerkleTree<u64, Fr, RescueHasher<Engine>>;
+
+    /// Checks that the stored tree is restored with the same root hash and Merkle paths.
+    #[test]
+    fn tree_save_load() {
+        let dir = std::env::temp_dir().join(format!("zksync_tree_storage_{}", std::process::id()));
+        let storage = DiskTreeStorage::open(&dir).unwrap();
+        assert!(storage
+            .load::<u64, RescueHasher<Engine>>()
+            .unwrap()
+            .is_none());
+
+        let mut tree = TestTree::new(8);
+        for (idx, item) in [(0, 5), (3, 7), (200, 11), (255, 13)].iter() {
+            tree.insert(*idx, *item);
+        }
+        tree.remove(3);
+        storage.save(10, &tree).unwrap();
+
+        let (block, restored) = storage
+            .load::<u64, RescueHasher<Engine>>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(block, 10);
+        assert_eq!(restored.root_hash(), tree.root_hash());
+        for idx in &[0, 3, 100, 200] {
+            assert_eq!(restored.merkle_path(*idx), tree.merkle_path(*idx));
+        }
+
+        // Saving a tree replaces the previous one.
+        let mut tree = TestTree::new(8);
+        tree.insert(1, 1);
+        storage.save(11, &tree).unwrap();
+        let (block, restored) = storage
+            .load::<u64, RescueHasher<Engine>>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(block, 11);
+        assert_eq!(restored.items.len(), 1);
+        assert_eq!(restored.root_hash(), tree.root_hash());
+
+        storage.clear().unwrap();
+        assert!(storage.stored_block().unwrap().is_none());
+
+        drop(storage);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// Checks that the incrementally updated tree is restored the same as the saved one.
+    #[test]
+    fn tree_update() {
+        let dir =
+            std::env::temp_dir().join(format!("zksync_tree_storage_update_{}", std::process::id()));
+        let storage = DiskTreeStorage::open(&dir).unwrap();
+
+        let mut tree = TestTree::new(8);
+        tree.insert(0, 5);
+        tree.insert(200, 11);
+        storage.save(1, &tree).unwrap();
+
+        // Update an existing item, insert new ones (including a new split node) and remove one.
+        tree.insert(0, 6);
+        tree.insert(1, 7);
+        tree.insert(255, 13);
+        tree.remove(200);
+        storage.update(2, &tree, vec![0, 1, 255, 200]).unwrap();
+
+        let (block, restored) = storage
+            .load::<u64, RescueHasher<Engine>>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(block, 2);
+        assert_eq!(restored.items, tree.items);
+        assert_eq!(restored.root_hash(), tree.root_hash());
+        let mut expected_hashes = tree.cached_hashes();
+        expected_hashes.sort_unstable_by_key(|(idx, _)| *idx);
+        let mut restored_hashes = restored.cached_hashes();
+        restored_hashes.sort_unstable_by_key(|(idx, _)| *idx);
+        assert_eq!(restored_hashes, expected_hashes);
+
+        // Only the tree of the previous block can be updated.
+        tree.insert(2, 1);
+        assert!(storage.update(4, &tree, vec![2]).is_err());
+        assert_eq!(storage.stored_block().unwrap().unwrap().0, 2);
+
+        drop(storage);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
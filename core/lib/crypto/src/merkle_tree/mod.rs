@@ -1,3 +1,8 @@
+use self::hasher::Hasher;
+use crate::primitives::GetBits;
+
+#[cfg(feature = "disk_storage")]
+pub mod disk_storage;
 pub mod hasher;
 pub mod parallel_smt;
 pub mod rescue_hasher;
@@ -10,31 +15,54 @@ pub type SparseMerkleTree<T, H, HH> = parallel_smt::SparseMerkleTree<T, H, HH>;
 /// Default hasher used in the zkSync network for state hash calculations.
 pub type RescueHasher<T> = rescue_hasher::RescueHasher<T>;
 
-// TODO: return the code below and uncomment asserts (ZKS-107)
-
-// pub fn verify_proof<E: Account>(&self, index: u32, item: Account, proof: Vec<(E::Fr, bool)>) -> bool {
-//     use crate::sparse_merkle_tree::hasher::Hasher;
-
-//     assert!(index < self.capacity());
-//     let item_bits = item.get_bits_le();
-//     let mut hash = self.hasher.hash_bits(item_bits);
-//     let mut proof_index: u32 = 0;
-
-//     for (i, e) in proof.clone().into_iter().enumerate() {
-//         if e.1 {
-//             // current is right
-//             proof_index |= 1 << i;
-//             hash = self.hasher.compress(&e.0, &hash, i);
-//         } else {
-//             // current is left
-//             hash = self.hasher.compress(&hash, &e.0, i);
-//         }
-//         // print!("This level hash is {}\n", hash);
-//     }
+/// Computes the root hash of the tree from the leaf hash and its Merkle path.
+///
+/// Path is expected in the format returned by `SparseMerkleTree::merkle_path`: sibling
+/// hashes from the leaf level up, each paired with the flag set if the current node is
+/// the right child. Depth of the tree is equal to the length of the path.
+///
+/// Returns `None` if the path doesn't lead to the leaf at the `index` position.
+pub fn merkle_path_root<Hash, H>(
+    hasher: &H,
+    index: u64,
+    leaf_hash: Hash,
+    path: &[(Hash, bool)],
+) -> Option<Hash>
+where
+    H: Hasher<Hash>,
+{
+    let mut path_index = 0u64;
+    let mut hash = leaf_hash;
+    for (level, (sibling, is_right)) in path.iter().enumerate() {
+        hash = if *is_right {
+            path_index |= 1 << level;
+            hasher.compress(sibling, &hash, level)
+        } else {
+            hasher.compress(&hash, sibling, level)
+        };
+    }
 
-//     if proof_index != index {
-//         return false;
-//     }
+    if path_index == index {
+        Some(hash)
+    } else {
+        None
+    }
+}
 
-//     hash == self.root_hash()
-// }
+/// Checks that the Merkle path proves the inclusion of `item` at the `index` position
+/// of the tree with the `root` hash. See `merkle_path_root` for the path format.
+pub fn verify_proof<T, Hash, H>(
+    hasher: &H,
+    index: u64,
+    item: &T,
+    proof: &[(Hash, bool)],
+    root: &Hash,
+) -> bool
+where
+    T: GetBits,
+    Hash: PartialEq,
+    H: Hasher<Hash>,
+{
+    let leaf_hash = hasher.hash_bits(item.get_bits_le());
+    merkle_path_root(hasher, index, leaf_hash, proof).as_ref() == Some(root)
+}
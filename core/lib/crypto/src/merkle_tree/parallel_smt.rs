@@ -5,6 +5,7 @@ use crate::primitives::GetBits;
 use crate::Fr;
 
 use fnv::FnvHashMap;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::sync::{RwLock, RwLockReadGuard};
@@ -354,6 +355,32 @@ where
         }
     }
 
+    /// Inserts multiple elements to the tree.
+    ///
+    /// If the same index is updated several times, only the last element is inserted.
+    /// Hashes of the updated leaves are calculated in parallel, so the following `root_hash`
+    /// call only has to recalculate the nodes on the paths from these leaves to the root,
+    /// and every shared node is calculated once.
+    pub fn insert_batch(&mut self, items: impl IntoIterator<Item = (u32, T)>) {
+        // Only the last update for each index matters.
+        let items: FnvHashMap<u32, T> = items.into_iter().collect();
+        let indices: Vec<ItemIndex> = items.keys().map(|&index| index as ItemIndex).collect();
+
+        for (index, item) in items {
+            self.insert(index, item);
+        }
+
+        let leaf_hashes: Vec<(NodeIndex, Hash)> = indices
+            .par_iter()
+            .map(|item_index| {
+                let leaf_index = NodeIndex((1 << self.tree_depth) + item_index);
+                let item_hash = self.hasher.hash_bits(self.items[item_index].get_bits_le());
+                (leaf_index, item_hash)
+            })
+            .collect();
+        self.cache.write().expect("write lock").extend(leaf_hashes);
+    }
+
     /// Removes an element with a given index, and returns the removed
     /// element (if it existed in the tree).
     pub fn remove(&mut self, index: u32) -> Option<T> {
@@ -384,6 +411,52 @@ where
         root_hash
     }
 
+    /// Returns the calculated hashes of the tree nodes, indexed by the node position
+    /// in the full binary tree (root has index 1). Hashes are calculated if required.
+    ///
+    /// Together with the tree items, these hashes are enough to restore the tree
+    /// without recalculating them, see `extend_cache`.
+    pub fn cached_hashes(&self) -> Vec<(u64, Hash)> {
+        self.root_hash();
+        self.cache
+            .read()
+            .expect("Read lock")
+            .iter()
+            .map(|(idx, hash)| (idx.0, hash.clone()))
+            .collect()
+    }
+
+    /// Returns the cached hashes of the nodes on the path from the item with the provided index
+    /// to the root, indexed by the node position in the full binary tree. Nodes without the
+    /// cached hash (e.g. the ones absent in the sparse tree) are returned with `None`.
+    /// Hashes are calculated if required.
+    ///
+    /// Updating an item only affects the hashes on its path, so these are the only
+    /// entries of `cached_hashes` that have to be refreshed after the update.
+    pub fn cached_path_hashes(&self, index: u32) -> Vec<(u64, Option<Hash>)> {
+        assert!((index as ItemIndex) < self.capacity());
+        self.root_hash();
+
+        let cache = self.cache.read().expect("Read lock");
+        let mut node_index = NodeIndex((1 << self.tree_depth) + index as ItemIndex);
+        let mut hashes = Vec::with_capacity(self.tree_depth + 1);
+        while node_index.0 > 0 {
+            hashes.push((node_index.0, cache.get(&node_index).cloned()));
+            node_index.0 >>= 1;
+        }
+        hashes
+    }
+
+    /// Adds the node hashes obtained from `cached_hashes` to the cache.
+    /// Hashes are expected to correspond to the current tree items, otherwise
+    /// the root hash and Merkle paths will be incorrect.
+    pub fn extend_cache(&mut self, hashes: impl IntoIterator<Item = (u64, Hash)>) {
+        self.cache
+            .write()
+            .expect("Write lock")
+            .extend(hashes.into_iter().map(|(idx, hash)| (NodeIndex(idx), hash)));
+    }
+
     /// Returns the capacity of the tree (how many items can the tree hold).
     pub fn capacity(&self) -> u64 {
         1 << self.tree_depth
@@ -604,8 +677,18 @@ where
                 // leaf node: return item hash
                 let item_index: ItemIndex = (node.index.0 - (1 << self.tree_depth)) as ItemIndex;
 
-                let item_bits = self.items[&item_index].get_bits_le();
-                let item_hash = self.hasher.hash_bits(item_bits);
+                // Leaf hash is removed from the cache once the item is updated,
+                // so the cached value (if any) is always actual.
+                let cached_hash = self
+                    .cache
+                    .read()
+                    .expect("Read lock")
+                    .get(&node.index)
+                    .cloned();
+                let item_hash = cached_hash.unwrap_or_else(|| {
+                    let item_bits = self.items[&item_index].get_bits_le();
+                    self.hasher.hash_bits(item_bits)
+                });
 
                 // There are no underlying updates for leaf node.
                 let updates = vec![];
@@ -186,30 +186,6 @@ where
             .collect()
     }
 
-    // pub fn verify_proof(&self, index: ItemIndex, item: T, proof: Vec<(Hash, bool)>) -> bool {
-    //     assert!(index < self.capacity());
-    //     let item_bits = item.get_bits_le();
-    //     let mut hash = self.hasher.hash_bits(item_bits);
-    //     let mut proof_index: ItemIndex = 0;
-
-    //     for (i, e) in proof.clone().into_iter().enumerate() {
-    //         if e.1 {
-    //             // current is right
-    //             proof_index |= 1 << i;
-    //             hash = self.hasher.compress(&e.0, &hash, i);
-    //         } else {
-    //             // current is left
-    //             hash = self.hasher.compress(&hash, &e.0, i);
-    //         }
-    //     }
-
-    //     if proof_index != index {
-    //         return false;
-    //     }
-
-    //     hash == self.root_hash()
-    // }
-
     /// Returns the Merkle root hash of the tree. This operation is O(1).
     pub fn root_hash(&self) -> Hash {
         // Root hash is stored at layer 0 and index 0.
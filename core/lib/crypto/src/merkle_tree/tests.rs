@@ -1,4 +1,4 @@
-use super::hasher::Hasher;
+use crate::ff::Field;
 use crate::merkle_tree::{parallel_smt, sequential_smt, RescueHasher};
 use crate::primitives::GetBits;
 use crate::rand::{Rng, SeedableRng, XorShiftRng};
@@ -16,30 +16,33 @@ fn verify_proof<T>(
 ) where
     T: GetBits,
 {
-    // To check the proof, we fold it starting from the hash of the value
-    // and updating with the hashes from the proof.
-    // We should obtain the root hash at the end if the proof is correct.
-    let mut proof_index = 0;
-    let mut aggregated_hash = hasher.hash_bits(element.get_bits_le());
-    for (level, (hash, dir)) in merkle_proof.into_iter().enumerate() {
-        let (lhs, rhs) = if dir {
-            proof_index |= 1 << level;
-            (hash, aggregated_hash)
-        } else {
-            (aggregated_hash, hash)
-        };
-
-        aggregated_hash = hasher.compress(&lhs, &rhs, level);
-    }
-
-    assert_eq!(
-        proof_index, element_index,
-        "Got unexpected element index while verifying the proof"
-    );
-    assert_eq!(
-        aggregated_hash, expected_root,
-        "Got unexpected root hash while verifying the proof"
+    assert!(
+        super::verify_proof(
+            &hasher,
+            element_index,
+            &element,
+            &merkle_proof,
+            &expected_root
+        ),
+        "Merkle proof verification failed, element idx: {}",
+        element_index
     );
+
+    // Proof must not be accepted for another position or root.
+    assert!(!super::verify_proof(
+        &hasher,
+        element_index ^ 1,
+        &element,
+        &merkle_proof,
+        &expected_root
+    ));
+    assert!(!super::verify_proof(
+        &hasher,
+        element_index,
+        &element,
+        &merkle_proof,
+        &Fr::zero()
+    ));
 }
 
 /// Verifies that for a randomly-chosen sequence of elements
@@ -277,3 +280,32 @@ fn big_test() {
     let root_hash: Fr = crate::ff::from_hex(&input.root_hash).unwrap();
     assert_eq!(root_hash, par_tree.root_hash());
 }
+
+/// Checks that the batch insertion leads to the same tree as the sequential one,
+/// including the repeated updates of the same element and updates of the already hashed tree.
+#[test]
+fn batch_insert() {
+    let depth = 8;
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    let mut seq_tree = parallel_smt::SparseMerkleTree::<u64, Fr, RescueHasher<Engine>>::new(depth);
+    let mut batch_tree =
+        parallel_smt::SparseMerkleTree::<u64, Fr, RescueHasher<Engine>>::new(depth);
+
+    for _ in 0..3 {
+        let updates: Vec<(u32, u64)> = (0..50)
+            .map(|_| (rng.gen_range(0, 1 << depth), rng.gen()))
+            .collect();
+
+        for (idx, item) in updates.iter() {
+            seq_tree.insert(*idx, *item);
+        }
+        batch_tree.insert_batch(updates.iter().cloned());
+
+        assert_eq!(seq_tree.items, batch_tree.items);
+        assert_eq!(seq_tree.root_hash(), batch_tree.root_hash());
+        for (idx, _) in updates.iter().take(5) {
+            assert_eq!(seq_tree.merkle_path(*idx), batch_tree.merkle_path(*idx));
+        }
+    }
+}
@@ -195,6 +195,50 @@ impl VecFrSerde {
     }
 }
 
+/// Blanket structure implementing serializing/deserializing methods for the Merkle path
+/// `Vec<(Fr, bool)>` as returned by `SparseMerkleTree::merkle_path`.
+///
+/// ## Example:
+///
+/// ```
+/// use zksync_crypto::serialization::MerklePathSerde;
+/// use zksync_crypto::Fr;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Clone, Debug, Serialize, Deserialize)]
+/// pub struct SomeStructure {
+///     #[serde(with = "MerklePathSerde")]
+///     pub path: Vec<(Fr, bool)>,
+/// }
+/// ```
+pub struct MerklePathSerde;
+
+impl MerklePathSerde {
+    pub fn serialize<S>(path: &[(Fr, bool)], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let res: Vec<_> = path
+            .iter()
+            .map(|(hash, is_right)| (hash.to_hex(), *is_right))
+            .collect();
+        Vec::serialize(&res, ser)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<(Fr, bool)>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let str_vec: Vec<(String, bool)> = Vec::deserialize(deserializer)?;
+        let mut res = Vec::with_capacity(str_vec.len());
+        for (s, is_right) in str_vec.into_iter() {
+            let v = Fr::from_hex(&s).map_err(de::Error::custom)?;
+            res.push((v, is_right));
+        }
+        Ok(res)
+    }
+}
+
 pub struct SingleProofSerde;
 
 impl SingleProofSerde {
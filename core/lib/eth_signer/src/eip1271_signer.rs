@@ -0,0 +1,84 @@
+use crate::raw_ethereum_tx::RawTransaction;
+use crate::{EthereumSigner, SignerError};
+
+use zksync_types::tx::{EIP1271Signature, TxEthSignature};
+use zksync_types::Address;
+
+/// Signer of the smart contract wallet messages, producing the EIP1271 signatures.
+///
+/// Messages are signed by the owner signer, which can be any `EthereumSigner`, and the owner
+/// signature is passed to the `isValidSignature` method of the wallet contract as is.
+/// That matches the wallet contracts checking that the message hash is signed by the owner.
+///
+/// Wallet contract can't sign Ethereum transactions, so they are signed by the owner.
+#[derive(Debug, Clone)]
+pub struct EIP1271Signer<S: EthereumSigner> {
+    wallet_address: Address,
+    owner_signer: S,
+}
+
+impl<S: EthereumSigner> EIP1271Signer<S> {
+    pub fn new(wallet_address: Address, owner_signer: S) -> Self {
+        Self {
+            wallet_address,
+            owner_signer,
+        }
+    }
+
+    /// Returns the signer of the wallet owner.
+    pub fn owner_signer(&self) -> &S {
+        &self.owner_signer
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: EthereumSigner + Send + Sync> EthereumSigner for EIP1271Signer<S> {
+    /// Signs the message with the owner key, returning the signature
+    /// to be verified by the wallet contract.
+    async fn sign_message(&self, message: &[u8]) -> Result<TxEthSignature, SignerError> {
+        let signature = match self.owner_signer.sign_message(message).await? {
+            TxEthSignature::EthereumSignature(signature) => signature.serialize_packed().to_vec(),
+            TxEthSignature::EIP1271Signature(signature) => signature.0,
+        };
+        Ok(TxEthSignature::EIP1271Signature(EIP1271Signature(
+            signature,
+        )))
+    }
+
+    /// Signs and returns the RLP-encoded transaction sent from the owner address.
+    async fn sign_transaction(&self, raw_tx: RawTransaction) -> Result<Vec<u8>, SignerError> {
+        self.owner_signer.sign_transaction(raw_tx).await
+    }
+
+    /// Returns the address of the wallet contract.
+    async fn get_address(&self) -> Result<Address, SignerError> {
+        Ok(self.wallet_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EIP1271Signer;
+    use crate::{EthereumSigner, PrivateKeySigner};
+    use zksync_types::{
+        tx::{PackedEthSignature, TxEthSignature},
+        Address, H256,
+    };
+
+    #[tokio::test]
+    async fn test_eip1271_signature() {
+        let private_key = H256::from([5; 32]);
+        let wallet_address = Address::repeat_byte(0x11);
+        let signer = EIP1271Signer::new(wallet_address, PrivateKeySigner::new(private_key));
+        assert_eq!(signer.get_address().await.unwrap(), wallet_address);
+
+        let msg = b"some_text_message";
+        match signer.sign_message(msg).await.unwrap() {
+            TxEthSignature::EIP1271Signature(signature) => {
+                let owner_signature = PackedEthSignature::sign(&private_key, msg).unwrap();
+                assert_eq!(signature.0, owner_signature.serialize_packed().to_vec());
+            }
+            signature => panic!("Wrong signature type: {:?}", signature),
+        }
+    }
+}
@@ -29,6 +29,10 @@ pub enum SignerError {
     DefineAddress,
     #[error("Recover address from signature failed: {0}")]
     RecoverAddress(String),
+    #[error("Keystore decryption failed: {0}")]
+    KeystoreError(String),
+    #[error("Key derivation from mnemonic failed: {0}")]
+    MnemonicError(String),
     #[error("{0}")]
     CustomError(String),
 }
@@ -0,0 +1,261 @@
+use crate::raw_ethereum_tx::RawTransaction;
+use crate::{EthereumSigner, PrivateKeySigner, SignerError};
+
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use parity_crypto::{aes, derive_key_iterations, derive_mac, is_equal, scrypt, Keccak256};
+
+use zksync_types::tx::{PackedEthSignature, TxEthSignature};
+use zksync_types::{Address, H256};
+
+/// Key derivation function used to encrypt the keystore.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+enum Kdf {
+    Pbkdf2 {
+        c: u32,
+        dklen: u32,
+        prf: String,
+        salt: String,
+    },
+    Scrypt {
+        n: u32,
+        r: u32,
+        p: u32,
+        dklen: u32,
+        salt: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    #[serde(flatten)]
+    kdf: Kdf,
+    mac: String,
+}
+
+/// Encrypted private key in the JSON V3 format (Web3 Secret Storage),
+/// as stored by geth, parity, MyCrypto and the like.
+#[derive(Debug, Deserialize)]
+struct Keystore {
+    version: u32,
+    address: Option<String>,
+    #[serde(alias = "Crypto")]
+    crypto: KeystoreCrypto,
+}
+
+/// Signer using the private key decrypted from the JSON V3 keystore.
+#[derive(Clone)]
+pub struct KeystoreSigner {
+    signer: PrivateKeySigner,
+    address: Address,
+}
+
+impl std::fmt::Debug for KeystoreSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "KeystoreSigner {{ address: {:?} }}", self.address)
+    }
+}
+
+impl KeystoreSigner {
+    /// Decrypts the keystore with the provided password.
+    /// Returns an error if the keystore is malformed or the password is incorrect.
+    pub fn from_json(keystore: &str, password: &str) -> Result<Self, SignerError> {
+        let keystore: Keystore = serde_json::from_str(keystore)
+            .map_err(|err| SignerError::KeystoreError(err.to_string()))?;
+        if keystore.version != 3 {
+            return Err(SignerError::KeystoreError(format!(
+                "Unsupported keystore version: {}",
+                keystore.version
+            )));
+        }
+
+        let private_key = decrypt_private_key(&keystore.crypto, password)?;
+        let address = PackedEthSignature::address_from_private_key(&private_key)
+            .map_err(|_| SignerError::DefineAddress)?;
+        if let Some(keystore_address) = keystore.address {
+            let keystore_address: Address = keystore_address
+                .trim_start_matches("0x")
+                .parse()
+                .map_err(|_| SignerError::DefineAddress)?;
+            if keystore_address != address {
+                return Err(SignerError::KeystoreError(
+                    "Decrypted key doesn't match the keystore address".to_string(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            signer: PrivateKeySigner::new(private_key),
+            address,
+        })
+    }
+
+    /// Reads the keystore from the file and decrypts it with the provided password.
+    pub fn from_file(path: impl AsRef<Path>, password: &str) -> Result<Self, SignerError> {
+        let keystore = std::fs::read_to_string(path)
+            .map_err(|err| SignerError::KeystoreError(err.to_string()))?;
+        Self::from_json(&keystore, password)
+    }
+
+    /// Get Ethereum address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+}
+
+#[async_trait::async_trait]
+impl EthereumSigner for KeystoreSigner {
+    async fn sign_message(&self, message: &[u8]) -> Result<TxEthSignature, SignerError> {
+        self.signer.sign_message(message).await
+    }
+
+    async fn sign_transaction(&self, raw_tx: RawTransaction) -> Result<Vec<u8>, SignerError> {
+        self.signer.sign_transaction(raw_tx).await
+    }
+
+    async fn get_address(&self) -> Result<Address, SignerError> {
+        Ok(self.address)
+    }
+}
+
+fn decode_hex(value: &str, field: &str) -> Result<Vec<u8>, SignerError> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|err| SignerError::KeystoreError(format!("Invalid {}: {}", field, err)))
+}
+
+fn decrypt_private_key(crypto: &KeystoreCrypto, password: &str) -> Result<H256, SignerError> {
+    if crypto.cipher != "aes-128-ctr" {
+        return Err(SignerError::KeystoreError(format!(
+            "Unsupported cipher: {}",
+            crypto.cipher
+        )));
+    }
+
+    // Derived key is split into the encryption key (first 16 bytes)
+    // and the key used to calculate the MAC (last 16 bytes).
+    let (encryption_key, mac_key) = match &crypto.kdf {
+        Kdf::Pbkdf2 {
+            c,
+            dklen,
+            prf,
+            salt,
+        } => {
+            if *dklen != 32 || prf != "hmac-sha256" {
+                return Err(SignerError::KeystoreError(
+                    "Unsupported pbkdf2 parameters".to_string(),
+                ));
+            }
+            let iterations = NonZeroU32::new(*c).ok_or_else(|| {
+                SignerError::KeystoreError("Invalid pbkdf2 iterations count".to_string())
+            })?;
+            derive_key_iterations(password.as_bytes(), &decode_hex(salt, "salt")?, iterations)
+        }
+        Kdf::Scrypt {
+            n,
+            r,
+            p,
+            dklen,
+            salt,
+        } => {
+            if *dklen != 32 {
+                return Err(SignerError::KeystoreError(
+                    "Unsupported scrypt parameters".to_string(),
+                ));
+            }
+            scrypt::derive_key(password.as_bytes(), &decode_hex(salt, "salt")?, *n, *p, *r)
+                .map_err(|err| SignerError::KeystoreError(err.to_string()))?
+        }
+    };
+
+    let ciphertext = decode_hex(&crypto.ciphertext, "ciphertext")?;
+    let mac = derive_mac(&mac_key, &ciphertext).keccak256();
+    if !is_equal(&mac, &decode_hex(&crypto.mac, "mac")?) {
+        return Err(SignerError::KeystoreError(
+            "Incorrect keystore password".to_string(),
+        ));
+    }
+    if ciphertext.len() != 32 {
+        return Err(SignerError::KeystoreError(
+            "Encrypted private key should be 32 bytes long".to_string(),
+        ));
+    }
+
+    let mut private_key = H256::zero();
+    aes::decrypt_128_ctr(
+        &encryption_key,
+        &decode_hex(&crypto.cipherparams.iv, "iv")?,
+        &ciphertext,
+        private_key.as_bytes_mut(),
+    )
+    .map_err(|err| SignerError::KeystoreError(err.to_string()))?;
+
+    Ok(private_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeystoreSigner;
+    use crate::{EthereumSigner, SignerError};
+    use zksync_types::{
+        tx::{PackedEthSignature, TxEthSignature},
+        H256,
+    };
+
+    // Test vector from the Web3 Secret Storage Definition.
+    const KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+    const PASSWORD: &str = "testpassword";
+    const PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    #[tokio::test]
+    async fn test_keystore_decryption() {
+        let signer = KeystoreSigner::from_json(KEYSTORE, PASSWORD).unwrap();
+        let private_key: H256 = PRIVATE_KEY.parse().unwrap();
+        assert_eq!(
+            signer.address(),
+            PackedEthSignature::address_from_private_key(&private_key).unwrap()
+        );
+
+        let msg = b"some_text_message";
+        if let TxEthSignature::EthereumSignature(signature) =
+            signer.sign_message(msg).await.unwrap()
+        {
+            assert_eq!(
+                signature,
+                PackedEthSignature::sign(&private_key, msg).unwrap()
+            );
+        } else {
+            panic!("Wrong signature type")
+        }
+
+        match KeystoreSigner::from_json(KEYSTORE, "wrongpassword") {
+            Err(SignerError::KeystoreError(_)) => {}
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
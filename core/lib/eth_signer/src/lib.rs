@@ -6,14 +6,20 @@ use error::SignerError;
 use zksync_types::tx::TxEthSignature;
 use zksync_types::Address;
 
+pub use eip1271_signer::EIP1271Signer;
 pub use json_rpc_signer::JsonRpcSigner;
+pub use keystore_signer::KeystoreSigner;
 pub use pk_signer::PrivateKeySigner;
 pub use raw_ethereum_tx::RawTransaction;
+pub use remote_signer::RemoteSigner;
 
+pub mod eip1271_signer;
 pub mod error;
 pub mod json_rpc_signer;
+pub mod keystore_signer;
 pub mod pk_signer;
 pub mod raw_ethereum_tx;
+pub mod remote_signer;
 
 #[async_trait]
 pub trait EthereumSigner {
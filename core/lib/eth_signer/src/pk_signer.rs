@@ -1,7 +1,9 @@
 use crate::raw_ethereum_tx::RawTransaction;
 use crate::{EthereumSigner, SignerError};
 
+use bip39::{Language, Mnemonic, Seed};
 use parity_crypto::publickey::sign;
+use tiny_hderive::bip32::ExtendedPrivKey;
 
 use zksync_types::tx::{PackedEthSignature, TxEthSignature};
 use zksync_types::{Address, H256};
@@ -21,6 +23,34 @@ impl PrivateKeySigner {
     pub fn new(private_key: H256) -> Self {
         Self { private_key }
     }
+
+    /// Derives the key from the BIP-39 mnemonic phrase with the BIP-44 path used
+    /// by the most of Ethereum wallets: `m/44'/60'/0'/0/{index}`.
+    ///
+    /// ## Arguments
+    ///
+    /// - `phrase`: Mnemonic phrase in English.
+    /// - `password`: Optional BIP-39 passphrase, empty if not used.
+    /// - `index`: Index of the account, `0` for the first account of the wallet.
+    pub fn from_mnemonic(phrase: &str, password: &str, index: u32) -> Result<Self, SignerError> {
+        Self::from_mnemonic_with_path(phrase, password, &format!("m/44'/60'/0'/0/{}", index))
+    }
+
+    /// Derives the key from the BIP-39 mnemonic phrase with the provided BIP-32 derivation path,
+    /// e.g. `m/44'/60'/0'/0/0`.
+    pub fn from_mnemonic_with_path(
+        phrase: &str,
+        password: &str,
+        path: &str,
+    ) -> Result<Self, SignerError> {
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English)
+            .map_err(|err| SignerError::MnemonicError(err.to_string()))?;
+        let seed = Seed::new(&mnemonic, password);
+        let key = ExtendedPrivKey::derive(seed.as_bytes(), path)
+            .map_err(|err| SignerError::MnemonicError(format!("{:?}", err)))?;
+
+        Ok(Self::new(H256::from(key.secret())))
+    }
 }
 
 #[async_trait::async_trait]
@@ -51,8 +81,8 @@ impl EthereumSigner for PrivateKeySigner {
 mod test {
     use super::PrivateKeySigner;
     use super::RawTransaction;
-    use crate::EthereumSigner;
-    use zksync_types::{H160, H256, U256};
+    use crate::{EthereumSigner, SignerError};
+    use zksync_types::{Address, H160, H256, U256};
 
     #[tokio::test]
     async fn test_generating_signature() {
@@ -82,4 +112,47 @@ mod test {
         ];
         assert_eq!(signature, precalculated_signature);
     }
+
+    #[tokio::test]
+    async fn test_keys_from_mnemonic() {
+        // Keys of the well-known development mnemonic.
+        let phrase = "test test test test test test test test test test test junk";
+        let expected = [
+            (
+                "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+                "f39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            ),
+            (
+                "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+                "70997970c51812dc3a010c7d01b50e0d17dc79c8",
+            ),
+        ];
+        for (index, (private_key, address)) in expected.iter().enumerate() {
+            let signer = PrivateKeySigner::from_mnemonic(phrase, "", index as u32).unwrap();
+            assert_eq!(signer.private_key, private_key.parse::<H256>().unwrap());
+            assert_eq!(
+                signer.get_address().await.unwrap(),
+                address.parse::<Address>().unwrap()
+            );
+        }
+
+        let signer =
+            PrivateKeySigner::from_mnemonic_with_path(phrase, "", "m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(signer.private_key, expected[1].0.parse::<H256>().unwrap());
+
+        // Passphrase changes the derived keys.
+        let signer = PrivateKeySigner::from_mnemonic(phrase, "passphrase", 0).unwrap();
+        assert_ne!(signer.private_key, expected[0].0.parse::<H256>().unwrap());
+
+        // Checksum of the mnemonic is verified.
+        let invalid_phrase = "test test test test test test test test test test test test";
+        assert!(matches!(
+            PrivateKeySigner::from_mnemonic(invalid_phrase, "", 0),
+            Err(SignerError::MnemonicError(_))
+        ));
+        assert!(matches!(
+            PrivateKeySigner::from_mnemonic_with_path(phrase, "", "44'/60'"),
+            Err(SignerError::MnemonicError(_))
+        ));
+    }
 }
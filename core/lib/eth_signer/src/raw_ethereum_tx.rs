@@ -54,14 +54,19 @@ impl RawTransaction {
     }
 
     pub fn hash(&self) -> [u8; 32] {
-        let mut hash = RlpStream::new();
-        hash.begin_unbounded_list();
-        self.encode(&mut hash);
-        hash.append(&vec![self.chain_id]);
-        hash.append(&U256::zero());
-        hash.append(&U256::zero());
-        hash.finalize_unbounded_list();
-        hash.out().keccak256()
+        self.signing_data().keccak256()
+    }
+
+    /// Returns the RLP-encoded transaction data, which hash is signed (EIP-155).
+    pub fn signing_data(&self) -> Vec<u8> {
+        let mut stream = RlpStream::new();
+        stream.begin_unbounded_list();
+        self.encode(&mut stream);
+        stream.append(&vec![self.chain_id]);
+        stream.append(&U256::zero());
+        stream.append(&U256::zero());
+        stream.finalize_unbounded_list();
+        stream.out()
     }
 
     pub fn encode(&self, s: &mut RlpStream) {
@@ -0,0 +1,295 @@
+use crate::error::{RpcSignerError, SignerError};
+use crate::json_rpc_signer::is_signature_from_address;
+use crate::EthereumSigner;
+use crate::RawTransaction;
+
+use parity_crypto::{publickey::Signature, Keccak256};
+use zksync_types::tx::{PackedEthSignature, TxEthSignature};
+use zksync_types::Address;
+
+/// Signer using the keys stored by the remote signing service.
+///
+/// Service is expected to implement the Ethereum signing API of Web3Signer:
+/// - `GET /api/v1/eth1/publicKeys` returns the list of hex-encoded public keys of the service.
+/// - `POST /api/v1/eth1/sign/{public_key}` with the `{"data": "0x..."}` body returns
+///   the hex-encoded signature of `keccak256(data)`.
+///
+/// Private keys never leave the service, so it can be backed by an HSM or a hardware wallet.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: String,
+    client: reqwest::Client,
+    public_key: String,
+    address: Address,
+}
+
+#[async_trait::async_trait]
+impl EthereumSigner for RemoteSigner {
+    /// The sign method calculates an Ethereum specific signature with:
+    /// sign(keccak256("\x19Ethereum Signed Message:\n" + len(message) + message))).
+    async fn sign_message(&self, msg: &[u8]) -> Result<TxEthSignature, SignerError> {
+        let prefix = format!("\x19Ethereum Signed Message:\n{}", msg.len());
+        let mut bytes = Vec::with_capacity(prefix.len() + msg.len());
+        bytes.extend_from_slice(prefix.as_bytes());
+        bytes.extend_from_slice(msg);
+
+        let signature = PackedEthSignature::deserialize_packed(&self.sign(&bytes).await?)
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?;
+
+        // Checks the correctness of the message signature without a prefix
+        if is_signature_from_address(&signature, msg, self.address)? {
+            Ok(TxEthSignature::EthereumSignature(signature))
+        } else {
+            Err(SignerError::SigningFailed(
+                "Invalid signature from RemoteSigner".to_string(),
+            ))
+        }
+    }
+
+    /// Signs and returns the RLP-encoded transaction.
+    async fn sign_transaction(&self, raw_tx: RawTransaction) -> Result<Vec<u8>, SignerError> {
+        let mut signature = self.sign(&raw_tx.signing_data()).await?;
+        if signature[64] >= 27 {
+            signature[64] -= 27;
+        }
+
+        let mut signature_bytes = [0u8; 65];
+        signature_bytes.copy_from_slice(&signature);
+        let signature = Signature::from(signature_bytes);
+        if !signature.is_valid() {
+            return Err(SignerError::SigningFailed(
+                "Invalid signature from RemoteSigner".to_string(),
+            ));
+        }
+
+        Ok(raw_tx.rlp_encode_tx(signature))
+    }
+
+    async fn get_address(&self) -> Result<Address, SignerError> {
+        Ok(self.address)
+    }
+}
+
+impl RemoteSigner {
+    /// Creates a signer using the key of the provided Ethereum address.
+    /// If the address is not specified, the first key returned by the service is used.
+    pub async fn new(
+        url: impl Into<String>,
+        address: Option<Address>,
+    ) -> Result<Self, SignerError> {
+        let url = url.into();
+        let client = reqwest::Client::new();
+
+        let request = client.get(&format!("{}/api/v1/eth1/publicKeys", url));
+        let public_keys: Vec<String> = Self::send(request)
+            .await
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?;
+
+        for public_key in public_keys {
+            let key_address = public_key_to_address(&public_key)?;
+            if address.is_none() || address == Some(key_address) {
+                return Ok(Self {
+                    url,
+                    client,
+                    public_key,
+                    address: key_address,
+                });
+            }
+        }
+
+        Err(SignerError::DefineAddress)
+    }
+
+    /// Get Ethereum address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Requests the signature of `keccak256(data)` from the service,
+    /// returning the signature bytes in the `r || s || v` form.
+    async fn sign(&self, data: &[u8]) -> Result<Vec<u8>, SignerError> {
+        let body = serde_json::json!({ "data": format!("0x{}", hex::encode(data)) });
+        let request = self
+            .client
+            .post(&format!(
+                "{}/api/v1/eth1/sign/{}",
+                self.url, self.public_key
+            ))
+            .json(&body);
+        let signature = Self::send(request)
+            .await
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?
+            .text()
+            .await
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?;
+
+        let signature = hex::decode(signature.trim().trim_start_matches("0x"))
+            .map_err(|err| SignerError::SigningFailed(err.to_string()))?;
+        if signature.len() != 65 {
+            return Err(SignerError::SigningFailed(format!(
+                "Signature should be 65 bytes long, got {}",
+                signature.len()
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    /// Performs the query to the signing service,
+    /// returning the response only if it is successful.
+    async fn send(request: reqwest::RequestBuilder) -> Result<reqwest::Response, RpcSignerError> {
+        let res = request
+            .send()
+            .await
+            .map_err(|err| RpcSignerError::NetworkError(err.to_string()))?;
+        if !res.status().is_success() {
+            let error = format!("Query responded with a non-OK response: {}", res.status());
+            return Err(RpcSignerError::NetworkError(error));
+        }
+
+        Ok(res)
+    }
+}
+
+/// Returns the address of the hex-encoded uncompressed public key,
+/// with or without the `0x04` prefix.
+fn public_key_to_address(public_key: &str) -> Result<Address, SignerError> {
+    let bytes =
+        hex::decode(public_key.trim_start_matches("0x")).map_err(|_| SignerError::DefineAddress)?;
+    let public_key = match bytes.len() {
+        64 => &bytes[..],
+        65 if bytes[0] == 4 => &bytes[1..],
+        _ => return Err(SignerError::DefineAddress),
+    };
+
+    Ok(Address::from_slice(&public_key.keccak256()[12..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{get, post, web, App, HttpResponse, HttpServer, Responder};
+    use futures::future::{AbortHandle, Abortable};
+    use parity_crypto::{
+        publickey::{sign, Generator, KeyPair, Random},
+        Keccak256,
+    };
+    use serde_json::json;
+
+    use zksync_types::{tx::TxEthSignature, H256};
+
+    use super::{is_signature_from_address, RemoteSigner};
+    use crate::{EthereumSigner, PrivateKeySigner, RawTransaction};
+
+    #[derive(Clone)]
+    struct State {
+        key_pairs: Vec<KeyPair>,
+    }
+
+    #[get("/api/v1/eth1/publicKeys")]
+    async fn public_keys(state: web::Data<State>) -> impl Responder {
+        let public_keys: Vec<_> = state
+            .key_pairs
+            .iter()
+            .map(|pair| format!("0x{}", hex::encode(pair.public().as_bytes())))
+            .collect();
+        HttpResponse::Ok().json(json!(public_keys))
+    }
+
+    #[post("/api/v1/eth1/sign/{public_key}")]
+    async fn sign_data(
+        public_key: web::Path<String>,
+        req: web::Json<serde_json::Value>,
+        state: web::Data<State>,
+    ) -> impl Responder {
+        let pair = state
+            .key_pairs
+            .iter()
+            .find(|pair| format!("0x{}", hex::encode(pair.public().as_bytes())) == *public_key);
+        let pair = match pair {
+            Some(pair) => pair,
+            None => return HttpResponse::NotFound().finish(),
+        };
+
+        let data = hex::decode(&req["data"].as_str().unwrap()[2..]).unwrap();
+        let signature = sign(pair.secret(), &data.keccak256().into()).unwrap();
+        HttpResponse::Ok().body(format!("0x{}", hex::encode(signature.into_electrum())))
+    }
+
+    fn run_server(state: State) -> (String, AbortHandle) {
+        let mut url = None;
+        let mut server = None;
+        for i in 9000..9999 {
+            let new_url = format!("127.0.0.1:{}", i);
+            // Try to bind to some port, hope that 999 variants will be enough
+            let tmp_state = state.clone();
+            if let Ok(ser) = HttpServer::new(move || {
+                App::new()
+                    .data(tmp_state.clone())
+                    .service(public_keys)
+                    .service(sign_data)
+            })
+            .bind(new_url.clone())
+            {
+                server = Some(ser);
+                url = Some(new_url);
+                break;
+            }
+        }
+
+        let server = server.expect("Could not bind to port from 9000 to 9999");
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let future = Abortable::new(server.run(), abort_registration);
+        tokio::spawn(future);
+        let address = format!("http://{}", &url.unwrap());
+        (address, abort_handle)
+    }
+
+    #[actix_rt::test]
+    async fn run_client() {
+        let key_pairs = vec![Random.generate(), Random.generate()];
+        let (url, abort_handle) = run_server(State {
+            key_pairs: key_pairs.clone(),
+        });
+
+        let signer = RemoteSigner::new(url.clone(), None).await.unwrap();
+        assert_eq!(signer.address(), key_pairs[0].address());
+        let signer = RemoteSigner::new(url.clone(), Some(key_pairs[1].address()))
+            .await
+            .unwrap();
+        assert_eq!(signer.address(), key_pairs[1].address());
+
+        let msg = b"some_text_message";
+        if let TxEthSignature::EthereumSignature(signature) =
+            signer.sign_message(msg).await.unwrap()
+        {
+            assert!(is_signature_from_address(&signature, msg, signer.address()).unwrap())
+        } else {
+            panic!("Wrong signature type")
+        }
+
+        // Transaction signature must be the same as the one of the local signer.
+        let raw_tx = RawTransaction {
+            chain_id: 4,
+            nonce: 1.into(),
+            to: Some(key_pairs[0].address()),
+            value: 10.into(),
+            gas_price: 1.into(),
+            gas: 2.into(),
+            data: vec![1, 2, 3],
+        };
+        let local_signer =
+            PrivateKeySigner::new(H256::from_slice(key_pairs[1].secret().as_bytes()));
+        assert_eq!(
+            signer.sign_transaction(raw_tx.clone()).await.unwrap(),
+            local_signer.sign_transaction(raw_tx).await.unwrap()
+        );
+
+        assert!(RemoteSigner::new(url, Some(Default::default()))
+            .await
+            .is_err());
+        abort_handle.abort();
+    }
+}
@@ -237,9 +237,7 @@ fn apply_change_pubkey_op(b: &mut Bencher<'_>) {
     );
 
     change_pubkey.eth_auth_data = {
-        let sign_bytes = change_pubkey
-            .get_eth_signed_data()
-            .expect("Failed to construct ChangePubKey signed message.");
+        let sign_bytes = change_pubkey.get_eth_signed_data_with_batch_hash(H256::zero());
         let eth_signature =
             PackedEthSignature::sign(eth_private_key, &sign_bytes).expect("Signing failed");
         ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData {
@@ -98,6 +98,11 @@ impl ZkSyncState {
         hash
     }
 
+    /// Returns the tree of the accounts of the state.
+    pub fn tree(&self) -> &AccountTree {
+        &self.balance_tree
+    }
+
     pub fn get_account(&self, account_id: AccountId) -> Option<Account> {
         let start = std::time::Instant::now();
 
@@ -143,40 +148,51 @@ impl ZkSyncState {
 
     /// Applies account updates.
     /// Assumes that all updates are correct, panics otherwise.
+    ///
+    /// Updated accounts are inserted into the tree as a single batch, so every account
+    /// is inserted once regardless of the number of its updates.
     pub fn apply_account_updates(&mut self, updates: AccountUpdates) {
+        // Accounts updated so far. Deleted accounts are replaced with the default ones,
+        // same as `remove_account` does.
+        let mut updated_accounts: HashMap<AccountId, Account> = HashMap::new();
+
         for (account_id, account_update) in updates {
-            match account_update {
+            let current_account = updated_accounts
+                .get(&account_id)
+                .cloned()
+                .or_else(|| self.get_account(account_id));
+
+            let account = match account_update {
                 AccountUpdate::Create { address, nonce } => {
-                    assert!(self.get_account_by_address(&address).is_none());
+                    assert!(self.account_id_by_address.get(&address).is_none());
 
                     let mut account = Account::default();
                     account.address = address;
                     account.nonce = nonce;
-                    self.insert_account(account_id, account);
+                    self.account_id_by_address.insert(address, account_id);
+                    account
                 }
                 AccountUpdate::Delete { address, nonce } => {
-                    let account = self
-                        .get_account(account_id)
-                        .expect("account to delete must exist");
+                    let account = current_account.expect("account to delete must exist");
                     assert_eq!(account.address, address);
                     assert_eq!(account.nonce, nonce);
 
-                    self.remove_account(account_id);
+                    self.account_id_by_address.remove(&address);
+                    Account::default()
                 }
                 AccountUpdate::UpdateBalance {
                     old_nonce,
                     new_nonce,
                     balance_update: (token_id, old_balance, new_balance),
                 } => {
-                    let mut account = self
-                        .get_account(account_id)
-                        .expect("account to update balance must exist");
+                    let mut account =
+                        current_account.expect("account to update balance must exist");
                     assert_eq!(account.get_balance(token_id), old_balance);
                     assert_eq!(account.nonce, old_nonce);
 
                     account.set_balance(token_id, new_balance.clone());
                     account.nonce = new_nonce;
-                    self.insert_account(account_id, account);
+                    account
                 }
                 AccountUpdate::ChangePubKeyHash {
                     old_pub_key_hash,
@@ -184,18 +200,19 @@ impl ZkSyncState {
                     old_nonce,
                     new_nonce,
                 } => {
-                    let mut account = self
-                        .get_account(account_id)
-                        .expect("account to change pubkey must exist");
+                    let mut account = current_account.expect("account to change pubkey must exist");
                     assert_eq!(account.pub_key_hash, old_pub_key_hash);
                     assert_eq!(account.nonce, old_nonce);
 
                     account.pub_key_hash = new_pub_key_hash;
                     account.nonce = new_nonce;
-                    self.insert_account(account_id, account);
+                    account
                 }
-            }
+            };
+            updated_accounts.insert(account_id, account);
         }
+
+        self.balance_tree.insert_batch(updated_accounts);
     }
 
     pub fn execute_txs_batch(&mut self, txs: &[SignedZkSyncTx]) -> Vec<Result<OpSuccess, Error>> {
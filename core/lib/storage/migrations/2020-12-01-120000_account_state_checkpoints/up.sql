@@ -0,0 +1,10 @@
+-- Snapshots of the account state after the executed blocks.
+-- Used to not apply the whole history of account updates when loading
+-- the account state at some block.
+CREATE TABLE account_state_checkpoints (
+    account_id BIGINT NOT NULL,
+    block_number BIGINT NOT NULL,
+    -- Serialized account, `NULL` if account doesn't exist at the block.
+    account jsonb,
+    PRIMARY KEY (account_id, block_number)
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS token_changes;
+DROP TABLE IF EXISTS token_status;
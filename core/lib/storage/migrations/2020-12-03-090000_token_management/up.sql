@@ -0,0 +1,21 @@
+-- Administrative status of the tokens, missing rows are treated as the default status.
+CREATE TABLE token_status (
+    token_id INTEGER NOT NULL REFERENCES tokens(id) ON UPDATE CASCADE,
+    -- Token is not accepted to pay fees in.
+    deprecated_for_fees BOOLEAN NOT NULL DEFAULT false,
+    -- New transfers in the token are not accepted by the API.
+    paused BOOLEAN NOT NULL DEFAULT false,
+    PRIMARY KEY (token_id)
+);
+
+-- Audit log of the changes made to the tokens metadata and status.
+CREATE TABLE token_changes (
+    id BIGSERIAL PRIMARY KEY,
+    token_id INTEGER NOT NULL,
+    -- Either `metadata` or `status`.
+    change_type TEXT NOT NULL,
+    old_value jsonb NOT NULL,
+    new_value jsonb NOT NULL,
+    created_at TIMESTAMP with time zone NOT NULL
+);
+CREATE INDEX token_changes_token_id_idx ON token_changes (token_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS gas_price_history;
+DROP TABLE IF EXISTS ticker_price_history;
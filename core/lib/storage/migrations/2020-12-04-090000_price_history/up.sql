@@ -0,0 +1,14 @@
+-- Time series of the token prices obtained by the fee ticker.
+CREATE TABLE ticker_price_history (
+    token_id INTEGER NOT NULL REFERENCES tokens(id) ON UPDATE CASCADE,
+    usd_price NUMERIC NOT NULL,
+    created_at TIMESTAMP with time zone NOT NULL,
+    PRIMARY KEY (token_id, created_at)
+);
+
+-- Time series of the gas prices used by the fee ticker.
+CREATE TABLE gas_price_history (
+    gas_price NUMERIC NOT NULL,
+    created_at TIMESTAMP with time zone NOT NULL,
+    PRIMARY KEY (created_at)
+);
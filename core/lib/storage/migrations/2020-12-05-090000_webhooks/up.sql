@@ -0,0 +1,35 @@
+-- Webhooks registered to be notified about the account and transaction events.
+CREATE TABLE webhooks (
+    id BIGSERIAL PRIMARY KEY,
+    url TEXT NOT NULL,
+    -- Secret used to sign the delivered payloads.
+    secret TEXT NOT NULL,
+    -- Type of the watched entity: `account`, `transaction` or `priorityOp`.
+    event_type TEXT NOT NULL,
+    -- Identifier of the watched entity: address, transaction hash or priority operation serial ID.
+    event_id TEXT NOT NULL,
+    action_type TEXT NOT NULL,
+    created_at TIMESTAMP with time zone NOT NULL
+);
+
+CREATE INDEX webhooks_action_type_idx ON webhooks (action_type);
+
+-- Notifications to be delivered to the webhooks.
+CREATE TABLE webhook_deliveries (
+    id BIGSERIAL PRIMARY KEY,
+    webhook_id BIGINT NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    block_number BIGINT NOT NULL,
+    action_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMP with time zone NOT NULL,
+    delivered_at TIMESTAMP with time zone,
+    failed BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP with time zone NOT NULL,
+    -- Webhook is notified about each block event at most once.
+    UNIQUE (webhook_id, block_number, action_type)
+);
+
+CREATE INDEX webhook_deliveries_pending_idx ON webhook_deliveries (next_attempt_at)
+    WHERE delivered_at IS NULL AND NOT failed;
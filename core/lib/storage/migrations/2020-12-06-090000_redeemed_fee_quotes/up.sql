@@ -0,0 +1,8 @@
+-- Fee quotes already used to pay for the transactions, so they can't be redeemed again.
+-- Entries are only needed until the quote expires.
+CREATE TABLE redeemed_fee_quotes (
+    quote_hash BYTEA PRIMARY KEY,
+    expires_at TIMESTAMP with time zone NOT NULL
+);
+
+CREATE INDEX redeemed_fee_quotes_expires_at_idx ON redeemed_fee_quotes (expires_at);
@@ -3,7 +3,7 @@ use std::time::Instant;
 // External imports
 use sqlx::Acquire;
 // Workspace imports
-use zksync_types::{Account, AccountId, AccountUpdates, Address};
+use zksync_types::{Account, AccountId, AccountUpdates, Address, BlockNumber};
 // Local imports
 use self::records::*;
 use crate::diff::StorageAccountDiff;
@@ -16,6 +16,11 @@ mod stored_state;
 pub(crate) use self::restore_account::restore_account;
 pub use self::stored_state::StoredAccountState;
 
+/// Interval between the account state checkpoints (in blocks) used to speed up
+/// the historical account state queries. Checkpoints are stored once the state update
+/// of every `ACCOUNT_CHECKPOINT_INTERVAL`-th block is applied.
+pub const ACCOUNT_CHECKPOINT_INTERVAL: BlockNumber = 1000;
+
 /// Account schema contains interfaces to interact with the stored
 /// ZKSync accounts.
 #[derive(Debug)]
@@ -225,6 +230,191 @@ impl<'a, 'c> AccountSchema<'a, 'c> {
         result
     }
 
+    /// Loads the state of the account after the block with the provided number.
+    ///
+    /// State is reconstructed from the stored account updates, starting from the closest
+    /// checkpoint, so at most `ACCOUNT_CHECKPOINT_INTERVAL` blocks of the account updates
+    /// are applied. Returns `None` if the account didn't exist at the block.
+    pub async fn account_state_at_block(
+        &mut self,
+        account_id: AccountId,
+        block: BlockNumber,
+    ) -> QueryResult<Option<Account>> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let checkpoint = sqlx::query_as!(
+            StorageAccountCheckpoint,
+            "
+                SELECT * FROM account_state_checkpoints
+                WHERE account_id = $1 AND block_number <= $2
+                ORDER BY block_number DESC
+                LIMIT 1
+            ",
+            i64::from(account_id),
+            i64::from(block)
+        )
+        .fetch_optional(transaction.conn())
+        .await?;
+        let (checkpoint_block, mut account) = match checkpoint {
+            Some(checkpoint) => (
+                checkpoint.block_number,
+                checkpoint
+                    .account
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .expect("Stored account checkpoint is malformed"),
+            ),
+            None => (0, None),
+        };
+
+        let account_diff = AccountSchema(&mut transaction)
+            .load_account_diff(account_id, checkpoint_block, i64::from(block))
+            .await?;
+        for diff in account_diff {
+            let (_, update): (AccountId, _) = diff.into();
+            account = Account::apply_update(account, update);
+        }
+
+        transaction.commit().await?;
+        metrics::histogram!("sql.chain.account.account_state_at_block", start.elapsed());
+        Ok(account)
+    }
+
+    /// Stores the checkpoints of the state after the provided block for all the accounts
+    /// updated during the last `ACCOUNT_CHECKPOINT_INTERVAL` blocks. The state of the other
+    /// accounts can be reconstructed from their previous checkpoints, since they have
+    /// no updates after them.
+    ///
+    /// Must be called once the state update of the block is applied, i.e. the verified state
+    /// of the accounts corresponds to the provided block. Since the state update is only
+    /// applied to the executed blocks, the stored checkpoints are never affected by reverts.
+    pub(crate) async fn store_account_checkpoints(
+        &mut self,
+        block: BlockNumber,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let from_block = i64::from(block.saturating_sub(ACCOUNT_CHECKPOINT_INTERVAL));
+        let updated_accounts = sqlx::query!(
+            r#"
+                SELECT account_id AS "account_id!" FROM account_balance_updates
+                WHERE block_number > $1 AND block_number <= $2
+                UNION
+                SELECT account_id FROM account_creates
+                WHERE block_number > $1 AND block_number <= $2
+                UNION
+                SELECT account_id FROM account_pubkey_updates
+                WHERE block_number > $1 AND block_number <= $2
+            "#,
+            from_block,
+            i64::from(block)
+        )
+        .fetch_all(transaction.conn())
+        .await?;
+
+        for record in updated_accounts {
+            let account = AccountSchema(&mut transaction)
+                .last_verified_state_for_account(record.account_id as AccountId)
+                .await?;
+            sqlx::query!(
+                "
+                    INSERT INTO account_state_checkpoints (account_id, block_number, account)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (account_id, block_number) DO NOTHING
+                ",
+                record.account_id,
+                i64::from(block),
+                account.map(|account| {
+                    serde_json::to_value(account).expect("Account serialization failed")
+                })
+            )
+            .execute(transaction.conn())
+            .await?;
+        }
+
+        transaction.commit().await?;
+        metrics::histogram!(
+            "sql.chain.account.store_account_checkpoints",
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    /// Loads the updates of the account in the blocks range `(from_block, to_block]`,
+    /// ordered in the way they were applied.
+    async fn load_account_diff(
+        &mut self,
+        account_id: AccountId,
+        from_block: i64,
+        to_block: i64,
+    ) -> QueryResult<Vec<StorageAccountDiff>> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let account_balance_diff = sqlx::query_as!(
+            StorageAccountUpdate,
+            "
+                SELECT * FROM account_balance_updates
+                WHERE account_id = $1 AND block_number > $2 AND block_number <= $3
+            ",
+            i64::from(account_id),
+            from_block,
+            to_block
+        )
+        .fetch_all(transaction.conn())
+        .await?;
+
+        let account_creation_diff = sqlx::query_as!(
+            StorageAccountCreation,
+            "
+                SELECT * FROM account_creates
+                WHERE account_id = $1 AND block_number > $2 AND block_number <= $3
+            ",
+            i64::from(account_id),
+            from_block,
+            to_block
+        )
+        .fetch_all(transaction.conn())
+        .await?;
+
+        let account_pubkey_diff = sqlx::query_as!(
+            StorageAccountPubkeyUpdate,
+            "
+                SELECT * FROM account_pubkey_updates
+                WHERE account_id = $1 AND block_number > $2 AND block_number <= $3
+            ",
+            i64::from(account_id),
+            from_block,
+            to_block
+        )
+        .fetch_all(transaction.conn())
+        .await?;
+
+        let mut account_diff = Vec::new();
+        account_diff.extend(
+            account_balance_diff
+                .into_iter()
+                .map(StorageAccountDiff::from),
+        );
+        account_diff.extend(
+            account_creation_diff
+                .into_iter()
+                .map(StorageAccountDiff::from),
+        );
+        account_diff.extend(
+            account_pubkey_diff
+                .into_iter()
+                .map(StorageAccountDiff::from),
+        );
+        account_diff.sort_by(StorageAccountDiff::cmp_order);
+
+        transaction.commit().await?;
+        metrics::histogram!("sql.chain.account.load_account_diff", start.elapsed());
+        Ok(account_diff)
+    }
+
     pub async fn account_id_by_address(
         &mut self,
         address: Address,
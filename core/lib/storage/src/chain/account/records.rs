@@ -51,3 +51,10 @@ pub struct StorageBalance {
     pub coin_id: i32,
     pub balance: BigDecimal,
 }
+
+#[derive(Debug, FromRow)]
+pub struct StorageAccountCheckpoint {
+    pub account_id: i64,
+    pub block_number: i64,
+    pub account: Option<serde_json::Value>,
+}
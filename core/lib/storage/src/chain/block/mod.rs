@@ -1,26 +1,34 @@
 // Built-in deps
 use std::time::Instant;
 // External imports
+use sqlx::Done;
 use zksync_basic_types::{H256, U256};
 // Workspace imports
 use zksync_crypto::convert::FeConvert;
-use zksync_types::{block::PendingBlock, Action, ActionType, Fr, Operation};
 use zksync_types::{
+    aggregated_operations::{AggregatedActionType, BlocksRevertOperation},
     block::{Block, ExecutedOperations},
     AccountId, BlockNumber, ZkSyncOp,
 };
+use zksync_types::{block::PendingBlock, Action, ActionType, Fr, Operation};
 // Local imports
 use self::records::{
-    AccountTreeCache, BlockDetails, BlockTransactionItem, StorageBlock, StoragePendingBlock,
+    AccountTreeCache, BlockDetails, BlockTransactionItem, BlocksRevertSummary, StorageBlock,
+    StoragePendingBlock,
 };
 use crate::{
-    chain::operations::{
-        records::{
-            NewExecutedPriorityOperation, NewExecutedTransaction, NewOperation,
-            StoredExecutedPriorityOperation, StoredExecutedTransaction, StoredOperation,
+    chain::{
+        operations::{
+            records::{
+                NewExecutedPriorityOperation, NewExecutedTransaction, NewOperation,
+                StoredExecutedPriorityOperation, StoredExecutedTransaction, StoredOperation,
+            },
+            OperationsSchema,
         },
-        OperationsSchema,
+        state::StateSchema,
     },
+    ethereum::EthereumSchema,
+    prover::ProverSchema,
     QueryResult, StorageProcessor,
 };
 
@@ -151,6 +159,35 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
         Ok(result)
     }
 
+    /// Loads the root hashes of the blocks in the range `[from_block, to_block]`.
+    /// Unlike `get_block`, this method doesn't load block transactions, so it's suitable
+    /// to check the state of many blocks at once.
+    pub async fn load_block_root_hashes(
+        &mut self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> QueryResult<Vec<(BlockNumber, Fr)>> {
+        let start = Instant::now();
+        let root_hashes = sqlx::query!(
+            "SELECT number, root_hash FROM blocks
+            WHERE number >= $1 AND number <= $2
+            ORDER BY number ASC",
+            i64::from(from_block),
+            i64::from(to_block)
+        )
+        .fetch_all(self.0.conn())
+        .await?
+        .into_iter()
+        .map(|row| {
+            let root_hash = FeConvert::from_bytes(&row.root_hash).expect("Unparsable root hash");
+            (row.number as BlockNumber, root_hash)
+        })
+        .collect();
+
+        metrics::histogram!("sql.chain.block.load_block_root_hashes", start.elapsed());
+        Ok(root_hashes)
+    }
+
     /// Same as `get_block_executed_ops`, but returns a vector of `ZkSyncOp` instead
     /// of `ExecutedOperations`.
     pub async fn get_block_operations(&mut self, block: BlockNumber) -> QueryResult<Vec<ZkSyncOp>> {
@@ -771,6 +808,170 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
         }))
     }
 
+    /// Reverts all the blocks with numbers greater than `last_block`.
+    ///
+    /// This method removes the blocks themselves, the pending block, executed transactions,
+    /// account updates, aggregated operations and prover jobs related to the reverted blocks.
+    /// If some of the reverted blocks were already committed on Ethereum, the `RevertBlocks`
+    /// aggregated operation is stored, so `eth_sender` will revert them in the contract as well.
+    ///
+    /// Only blocks that are not executed (and not scheduled for execution) can be reverted.
+    /// Also there should be no Ethereum operations awaiting confirmation, since they may
+    /// affect the reverted blocks.
+    ///
+    /// Note that components keeping the chain state in memory (e.g. state keeper and
+    /// `eth_sender`) must be restarted after the revert.
+    ///
+    /// The changes can be inspected without being applied by invoking this method within
+    /// a database transaction which is not committed afterwards.
+    pub async fn revert_blocks(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<BlocksRevertSummary> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let last_executed_block = OperationsSchema(&mut transaction)
+            .get_last_affected_block_by_aggregated_action(AggregatedActionType::ExecuteBlocks)
+            .await?;
+        anyhow::ensure!(
+            last_block >= last_executed_block,
+            "Blocks up to #{} are executed (or scheduled for execution) and cannot be reverted",
+            last_executed_block
+        );
+
+        let unconfirmed_eth_ops = EthereumSchema(&mut transaction)
+            .load_unconfirmed_operations()
+            .await?
+            .len();
+        anyhow::ensure!(
+            unconfirmed_eth_ops == 0,
+            "There are {} Ethereum operations awaiting confirmation, blocks cannot be reverted until they are confirmed",
+            unconfirmed_eth_ops
+        );
+
+        let crossing_ops = OperationsSchema(&mut transaction)
+            .count_aggregated_ops_crossing_block(last_block)
+            .await?;
+        anyhow::ensure!(
+            crossing_ops == 0,
+            "Block #{} is not the last block of an aggregated operation, blocks cannot be reverted partially",
+            last_block
+        );
+
+        let last_committed_block = BlockSchema(&mut transaction)
+            .get_last_committed_block()
+            .await?;
+        let last_committed_on_chain = OperationsSchema(&mut transaction)
+            .get_last_block_by_action(ActionType::COMMIT, Some(true))
+            .await?;
+
+        // Blocks have to be loaded before removal, since they are required to build
+        // the arguments for the `revertBlocks` contract call.
+        let mut blocks_to_revert = Vec::new();
+        for block_number in (last_block + 1..=last_committed_on_chain).rev() {
+            let block = BlockSchema(&mut transaction)
+                .get_block(block_number)
+                .await?
+                .ok_or_else(|| anyhow::format_err!("Block #{} is not stored", block_number))?;
+            blocks_to_revert.push(block);
+        }
+
+        let pending_block = BlockSchema(&mut transaction)
+            .load_storage_pending_block()
+            .await?
+            .map(|block| block.number as BlockNumber)
+            .filter(|&number| number > last_block);
+
+        let (removed_transactions, removed_priority_operations) =
+            OperationsSchema(&mut transaction)
+                .remove_executed_operations(last_block)
+                .await?;
+        let removed_account_updates = StateSchema(&mut transaction)
+            .remove_account_updates(last_block)
+            .await?;
+        let removed_aggregated_operations = OperationsSchema(&mut transaction)
+            .remove_aggregated_operations(last_block)
+            .await?;
+        OperationsSchema(&mut transaction)
+            .remove_operations(last_block)
+            .await?;
+        let removed_prover_jobs = ProverSchema(&mut transaction)
+            .remove_prover_jobs(last_block)
+            .await?;
+        BlockSchema(&mut transaction)
+            .remove_pending_block(last_block)
+            .await?;
+        BlockSchema(&mut transaction)
+            .remove_blocks(last_block)
+            .await?;
+
+        let reverted_on_chain = blocks_to_revert
+            .iter()
+            .map(|block| block.block_number)
+            .collect();
+        if !blocks_to_revert.is_empty() {
+            OperationsSchema(&mut transaction)
+                .store_aggregated_action(
+                    BlocksRevertOperation {
+                        blocks: blocks_to_revert,
+                    }
+                    .into(),
+                )
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        metrics::histogram!("sql.chain.block.revert_blocks", start.elapsed());
+        Ok(BlocksRevertSummary {
+            last_block,
+            last_committed_block,
+            pending_block,
+            reverted_on_chain,
+            removed_transactions,
+            removed_priority_operations,
+            removed_account_updates,
+            removed_aggregated_operations,
+            removed_prover_jobs,
+        })
+    }
+
+    /// Removes the block headers for all the blocks with numbers greater than `last_block`.
+    /// Witnesses and account tree caches for these blocks are removed as well.
+    pub(crate) async fn remove_blocks(&mut self, last_block: BlockNumber) -> QueryResult<usize> {
+        let start = Instant::now();
+        let removed_blocks = sqlx::query!(
+            "DELETE FROM blocks WHERE number > $1",
+            i64::from(last_block)
+        )
+        .execute(self.0.conn())
+        .await?
+        .rows_affected() as usize;
+
+        metrics::histogram!("sql.chain.block.remove_blocks", start.elapsed());
+        Ok(removed_blocks)
+    }
+
+    /// Removes the pending block header if its number is greater than `last_block`.
+    /// Transactions of the pending block are stored separately and have to be removed
+    /// via `OperationsSchema`.
+    pub(crate) async fn remove_pending_block(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM pending_block WHERE number > $1",
+            i64::from(last_block)
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.chain.block.remove_pending_block", start.elapsed());
+        Ok(())
+    }
+
     pub async fn save_genesis_block(&mut self, root_hash: Fr) -> QueryResult<()> {
         let block = Block {
             block_number: 0,
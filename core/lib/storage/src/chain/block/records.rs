@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::value::Value;
 use sqlx::FromRow;
 // Workspace imports
+use zksync_types::BlockNumber;
 use zksync_utils::{BytesToHexSerde, OptionBytesToHexSerde, SyncBlockPrefix, ZeroxPrefix};
 // Local imports
 
@@ -67,6 +68,25 @@ pub struct AccountTreeCache {
     pub tree_cache: String,
 }
 
+/// Summary of the data removed from the storage by the blocks revert.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BlocksRevertSummary {
+    /// The last block that remains in the storage.
+    pub last_block: BlockNumber,
+    /// The last committed block before the revert.
+    pub last_committed_block: BlockNumber,
+    /// Number of the removed pending block, if there was one.
+    pub pending_block: Option<BlockNumber>,
+    /// Blocks which commitment is confirmed on Ethereum, in the descending order.
+    /// These blocks have to be reverted in the contract via `revertBlocks` call.
+    pub reverted_on_chain: Vec<BlockNumber>,
+    pub removed_transactions: usize,
+    pub removed_priority_operations: usize,
+    pub removed_account_updates: usize,
+    pub removed_aggregated_operations: usize,
+    pub removed_prover_jobs: usize,
+}
+
 impl BlockDetails {
     /// Checks if block is finalized, meaning that
     /// both Verify operation is performed for it, and this
@@ -1,6 +1,7 @@
 // Built-in deps
 use std::time::Instant;
 // External imports
+use sqlx::Done;
 // Workspace imports
 use zksync_types::{tx::TxHash, ActionType, BlockNumber};
 // Local imports
@@ -296,6 +297,55 @@ impl<'a, 'c> OperationsSchema<'a, 'c> {
         Ok(())
     }
 
+    /// Removes the executed transactions and priority operations for all the blocks
+    /// with numbers greater than `last_block` (including the pending block).
+    /// Returns the amount of removed transactions and priority operations.
+    pub(crate) async fn remove_executed_operations(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<(usize, usize)> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let removed_txs = sqlx::query!(
+            "DELETE FROM executed_transactions WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected() as usize;
+
+        let removed_priority_ops = sqlx::query!(
+            "DELETE FROM executed_priority_operations WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected() as usize;
+
+        transaction.commit().await?;
+        metrics::histogram!(
+            "sql.chain.operations.remove_executed_operations",
+            start.elapsed()
+        );
+        Ok((removed_txs, removed_priority_ops))
+    }
+
+    /// Removes the `COMMIT`/`VERIFY` operations for all the blocks with numbers
+    /// greater than `last_block`.
+    pub(crate) async fn remove_operations(&mut self, last_block: BlockNumber) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM operations WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.chain.operations.remove_operations", start.elapsed());
+        Ok(())
+    }
+
     pub async fn eth_tx_for_withdrawal(
         &mut self,
         withdrawal_hash: &TxHash,
@@ -365,6 +415,63 @@ impl<'a, 'c> OperationsSchema<'a, 'c> {
         Ok(block_number)
     }
 
+    /// Returns the amount of aggregated operations (other than blocks revert) that affect
+    /// both the `last_block` and blocks after it. Such operations cannot be partially removed.
+    pub async fn count_aggregated_ops_crossing_block(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<i64> {
+        let count = sqlx::query!(
+            r#"SELECT count(*) as "count!" FROM aggregate_operations
+            WHERE from_block <= $1 AND to_block > $1 AND action_type != $2"#,
+            i64::from(last_block),
+            AggregatedActionType::RevertBlocks.to_string(),
+        )
+        .fetch_one(self.0.conn())
+        .await?
+        .count;
+
+        Ok(count)
+    }
+
+    /// Removes aggregated operations (other than blocks revert) which start after `last_block`,
+    /// together with their bindings to the Ethereum operations.
+    /// Returns the amount of removed aggregated operations.
+    pub(crate) async fn remove_aggregated_operations(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<usize> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        sqlx::query!(
+            "DELETE FROM eth_aggregated_ops_binding
+            WHERE op_id IN (
+                SELECT id FROM aggregate_operations WHERE from_block > $1 AND action_type != $2
+            )",
+            i64::from(last_block),
+            AggregatedActionType::RevertBlocks.to_string(),
+        )
+        .execute(transaction.conn())
+        .await?;
+
+        let removed_ops = sqlx::query!(
+            "DELETE FROM aggregate_operations WHERE from_block > $1 AND action_type != $2",
+            i64::from(last_block),
+            AggregatedActionType::RevertBlocks.to_string(),
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected() as usize;
+
+        transaction.commit().await?;
+        metrics::histogram!(
+            "sql.chain.operations.remove_aggregated_operations",
+            start.elapsed()
+        );
+        Ok(removed_ops)
+    }
+
     pub async fn get_aggregated_op_that_affects_block(
         &mut self,
         aggregated_action: AggregatedActionType,
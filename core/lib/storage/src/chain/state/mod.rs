@@ -2,11 +2,11 @@
 use std::{cmp, collections::HashMap, time::Instant};
 // External imports
 use num::BigInt;
-use sqlx::types::BigDecimal;
+use sqlx::{types::BigDecimal, Done};
 // Workspace imports
 use zksync_types::{
     helpers::{apply_updates, reverse_updates},
-    AccountId, AccountMap, AccountUpdate, AccountUpdates, PubKeyHash,
+    AccountId, AccountMap, AccountUpdate, AccountUpdates, BlockNumber, PubKeyHash,
 };
 // Local imports
 use crate::chain::{
@@ -15,7 +15,7 @@ use crate::chain::{
             StorageAccount, StorageAccountCreation, StorageAccountPubkeyUpdate,
             StorageAccountUpdate, StorageBalance,
         },
-        restore_account,
+        restore_account, AccountSchema, ACCOUNT_CHECKPOINT_INTERVAL,
     },
     block::BlockSchema,
 };
@@ -310,12 +310,67 @@ impl<'a, 'c> StateSchema<'a, 'c> {
             }
         }
 
+        if block_number > 0 && block_number % ACCOUNT_CHECKPOINT_INTERVAL == 0 {
+            AccountSchema(&mut transaction)
+                .store_account_checkpoints(block_number)
+                .await?;
+        }
+
         transaction.commit().await?;
 
         metrics::histogram!("sql.chain.state.apply_state_update", start.elapsed());
         Ok(())
     }
 
+    /// Removes the stored (not applied yet) account updates for all the blocks with numbers
+    /// greater than `last_block`. Since these updates are applied to the `accounts` and `balances`
+    /// tables only after the block is executed, the verified state is not affected.
+    /// Returns the amount of removed updates.
+    pub(crate) async fn remove_account_updates(
+        &mut self,
+        last_block: BlockNumber,
+    ) -> QueryResult<usize> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let removed_balance_updates = sqlx::query!(
+            "DELETE FROM account_balance_updates WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected();
+
+        let removed_creates = sqlx::query!(
+            "DELETE FROM account_creates WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected();
+
+        let removed_pubkey_updates = sqlx::query!(
+            "DELETE FROM account_pubkey_updates WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected();
+
+        // Checkpoints are only stored for the executed blocks, but it doesn't hurt to be sure
+        // that there is no state of the removed blocks left.
+        sqlx::query!(
+            "DELETE FROM account_state_checkpoints WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?;
+
+        transaction.commit().await?;
+        metrics::histogram!("sql.chain.state.remove_account_updates", start.elapsed());
+        Ok((removed_balance_updates + removed_creates + removed_pubkey_updates) as usize)
+    }
+
     /// Loads the committed (not necessarily verified) account map state along
     /// with a block number to which this state applies.
     /// If the provided block number is `None`, then the latest committed
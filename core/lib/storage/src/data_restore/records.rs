@@ -3,16 +3,11 @@ use serde_json::Value;
 // Workspace imports
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use zksync_types::{AccountId, Address, BlockNumber, TokenId, ZkSyncOp};
+pub use zksync_types::tokens::NewTokenEvent;
+use zksync_types::{AccountId, BlockNumber, ZkSyncOp};
 // Workspace imports
 // Local imports
 
-#[derive(Debug)]
-pub struct NewTokenEvent {
-    pub address: Address,
-    pub id: TokenId,
-}
-
 #[derive(Debug, Clone, FromRow)]
 pub struct StoredRollupOpsBlock {
     pub block_num: BlockNumber,
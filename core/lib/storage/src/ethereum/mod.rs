@@ -1,6 +1,7 @@
 // Built-in deps
 use std::{collections::VecDeque, convert::TryFrom, str::FromStr, time::Instant};
 // External imports
+use chrono::{DateTime, Utc};
 use num::{BigInt, BigUint};
 use sqlx::types::BigDecimal;
 use zksync_basic_types::{H256, U256};
@@ -9,6 +10,7 @@ use zksync_types::ethereum::{ETHOperation, InsertedOperationResponse};
 // Local imports
 use self::records::{ETHParams, ETHStats, ETHTxHash, StorageETHOperation};
 use crate::chain::operations::records::StoredAggregatedOperation;
+use crate::tokens::records::DbPriceHistoryEntry;
 use crate::{QueryResult, StorageProcessor};
 use zksync_types::aggregated_operations::{AggregatedActionType, AggregatedOperation};
 
@@ -359,6 +361,76 @@ impl<'a, 'c> EthereumSchema<'a, 'c> {
         Ok(average_gas_price)
     }
 
+    /// Appends the gas price used by the fee ticker to the gas price history.
+    pub async fn store_gas_price_history(
+        &mut self,
+        gas_price: BigUint,
+        timestamp: DateTime<Utc>,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            r#"
+            INSERT INTO gas_price_history ( gas_price, created_at )
+            VALUES ( $1, $2 )
+            ON CONFLICT (created_at) DO NOTHING
+            "#,
+            BigDecimal::from(BigInt::from(gas_price)),
+            timestamp
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.ethereum.store_gas_price_history", start.elapsed());
+        Ok(())
+    }
+
+    /// Loads the gas price history for the `[from, to)` time range, aggregated into intervals
+    /// of `interval_secs` seconds. Intervals without stored prices are skipped.
+    pub async fn load_gas_price_history(
+        &mut self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_secs: u64,
+    ) -> QueryResult<Vec<DbPriceHistoryEntry>> {
+        let start = Instant::now();
+        let entries = sqlx::query_as!(
+            DbPriceHistoryEntry,
+            r#"
+            SELECT
+                to_timestamp(floor(extract(epoch FROM created_at) / $3) * $3) AS "timestamp!",
+                MIN(gas_price) AS "min_price!",
+                AVG(gas_price) AS "avg_price!",
+                MAX(gas_price) AS "max_price!"
+            FROM gas_price_history
+            WHERE created_at >= $1 AND created_at < $2
+            GROUP BY 1
+            ORDER BY 1 ASC
+            "#,
+            from,
+            to,
+            interval_secs as f64,
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.ethereum.load_gas_price_history", start.elapsed());
+        Ok(entries)
+    }
+
+    /// Removes the gas prices stored before the provided moment from the history.
+    pub async fn remove_gas_price_history(&mut self, before: DateTime<Utc>) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM gas_price_history WHERE created_at < $1",
+            before
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.ethereum.remove_gas_price_history", start.elapsed());
+        Ok(())
+    }
+
     /// Loads the stored Ethereum operations stats.
     pub async fn load_stats(&mut self) -> QueryResult<ETHStats> {
         let params = self.load_eth_params().await?;
@@ -35,6 +35,7 @@
 //! - ethereum, for the data associated with the Ethereum blockchain.
 //! - prover, for the data on prover jobs, proofs, etc.
 //! - tokens, for storing and loading known tokens.
+//! - webhooks, for the registered webhooks and the state of their notifications.
 //! - chain - the biggest one, which includes several schemas for the ZKSync sidechain itself.
 //!
 //! The chain module includes the following schemas:
@@ -94,6 +95,7 @@ pub mod ethereum;
 pub mod prover;
 pub mod test_data;
 pub mod tokens;
+pub mod webhooks;
 
 pub use crate::connection::ConnectionPool;
 pub type QueryResult<T> = Result<T, anyhow::Error>;
@@ -195,6 +197,11 @@ impl<'a> StorageProcessor<'a> {
         tokens::TokensSchema(self)
     }
 
+    /// Gains access to the `Webhooks` schema.
+    pub fn webhooks_schema(&mut self) -> webhooks::WebhooksSchema<'_, 'a> {
+        webhooks::WebhooksSchema(self)
+    }
+
     fn conn(&mut self) -> &mut PgConnection {
         match &mut self.conn {
             ConnectionHolder::Pooled(conn) => conn,
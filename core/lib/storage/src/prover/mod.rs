@@ -269,6 +269,40 @@ impl<'a, 'c> ProverSchema<'a, 'c> {
         Ok(proof)
     }
 
+    /// Removes the prover jobs and proofs which affect blocks with numbers greater than `last_block`.
+    /// Witnesses are removed together with the blocks they belong to.
+    /// Returns the amount of removed prover jobs.
+    pub async fn remove_prover_jobs(&mut self, last_block: BlockNumber) -> QueryResult<usize> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let removed_jobs = sqlx::query!(
+            "DELETE FROM prover_job_queue WHERE last_block > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected() as usize;
+
+        sqlx::query!(
+            "DELETE FROM aggregated_proofs WHERE last_block > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM proofs WHERE block_number > $1",
+            i64::from(last_block)
+        )
+        .execute(transaction.conn())
+        .await?;
+
+        transaction.commit().await?;
+        metrics::histogram!("sql", start.elapsed(), "prover" => "remove_prover_jobs");
+        Ok(removed_jobs)
+    }
+
     /// Stores witness for a block
     pub async fn store_witness(
         &mut self,
@@ -1,7 +1,7 @@
 // External imports
 // Workspace imports
-use zksync_types::AccountMap;
-use zksync_types::Action;
+use zksync_crypto::rand::Rng;
+use zksync_types::{helpers::apply_updates, AccountMap, AccountUpdate, Action, PubKeyHash};
 // Local imports
 use super::{block::apply_random_updates, utils::get_operation};
 use crate::chain::state::StateSchema;
@@ -129,3 +129,80 @@ async fn stored_accounts(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
     //
     // Ok(())
 }
+
+/// Checks that the state of every account loaded for the block `N` matches the `N-1`-th
+/// element of `states`.
+async fn check_account_states(
+    storage: &mut StorageProcessor<'_>,
+    states: &[AccountMap],
+) -> QueryResult<()> {
+    for (block_number, state) in (1..).zip(states.iter()) {
+        for (&account_id, account) in state {
+            let stored_account = AccountSchema(storage)
+                .account_state_at_block(account_id, block_number)
+                .await?
+                .expect("Account should exist at the block");
+            assert_eq!(&stored_account, account);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the account state can be loaded for any block it was updated in.
+#[db_test]
+async fn account_state_at_block(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let mut rng = create_rng();
+
+    // Commit several blocks with updates and remember the state after each of them.
+    let mut accounts = AccountMap::default();
+    let mut states = Vec::new();
+    for block_number in 1..=3 {
+        // Besides creating new accounts, change the public key of an already existing one.
+        let mut pubkey_updates = Vec::new();
+        if let Some((&account_id, account)) = accounts.iter().next() {
+            pubkey_updates.push((
+                account_id,
+                AccountUpdate::ChangePubKeyHash {
+                    old_nonce: account.nonce,
+                    old_pub_key_hash: account.pub_key_hash,
+                    new_nonce: account.nonce + 1,
+                    new_pub_key_hash: PubKeyHash { data: rng.gen() },
+                },
+            ));
+        }
+        let (mut new_accounts, mut updates) = apply_random_updates(accounts, &mut rng);
+        apply_updates(&mut new_accounts, pubkey_updates.clone());
+        updates.extend(pubkey_updates);
+        accounts = new_accounts;
+        StateSchema(&mut storage)
+            .commit_state_update(block_number, &updates, 0)
+            .await?;
+        states.push(accounts.clone());
+    }
+
+    check_account_states(&mut storage, &states).await?;
+
+    // Apply the first two blocks and store the checkpoints after the second one.
+    // The state must be reconstructed from the checkpoints the same way.
+    for block_number in 1..=2 {
+        StateSchema(&mut storage)
+            .apply_state_update(block_number)
+            .await?;
+    }
+    AccountSchema(&mut storage)
+        .store_account_checkpoints(2)
+        .await?;
+    check_account_states(&mut storage, &states).await?;
+
+    // Accounts created in the last block didn't exist at the first one.
+    for account_id in states[2].keys() {
+        if !states[0].contains_key(account_id) {
+            assert!(AccountSchema(&mut storage)
+                .account_state_at_block(*account_id, 1)
+                .await?
+                .is_none());
+        }
+    }
+
+    Ok(())
+}
@@ -955,3 +955,123 @@ async fn test_operations_counter(mut storage: StorageProcessor<'_>) -> QueryResu
 
     Ok(())
 }
+
+/// Checks that blocks revert removes all the data related to the reverted blocks
+/// and schedules the `revertBlocks` operation for blocks committed on Ethereum.
+#[db_test]
+async fn blocks_revert(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    use zksync_types::{
+        aggregated_operations::{AggregatedOperation, BlocksCommitOperation},
+        block::PendingBlock,
+        prover::ProverJobType,
+    };
+
+    let mut rng = create_rng();
+
+    // Create blocks 1, 2 and 3 together with the account updates for them.
+    let mut accounts = AccountMap::default();
+    let mut blocks = Vec::new();
+    for block_number in 1..=3 {
+        let (new_accounts, updates) = apply_random_updates(accounts, &mut rng);
+        accounts = new_accounts;
+
+        let operation = BlockSchema(&mut storage)
+            .execute_operation(get_operation(
+                block_number,
+                Action::Commit,
+                BLOCK_SIZE_CHUNKS,
+            ))
+            .await?;
+        StateSchema(&mut storage)
+            .commit_state_update(block_number, &updates, 0)
+            .await?;
+        ProverSchema(&mut storage)
+            .add_prover_job_to_job_queue(
+                block_number,
+                block_number,
+                serde_json::Value::Null,
+                0,
+                ProverJobType::SingleProof,
+            )
+            .await?;
+        blocks.push(operation.block);
+    }
+
+    // Block 1 is committed by one aggregated operation and blocks 2 and 3 by another one.
+    storage
+        .chain()
+        .operations_schema()
+        .store_aggregated_action(AggregatedOperation::CommitBlocks(BlocksCommitOperation {
+            last_committed_block: blocks[0].clone(),
+            blocks: vec![blocks[0].clone()],
+        }))
+        .await?;
+    storage
+        .chain()
+        .operations_schema()
+        .store_aggregated_action(AggregatedOperation::CommitBlocks(BlocksCommitOperation {
+            last_committed_block: blocks[0].clone(),
+            blocks: vec![blocks[1].clone(), blocks[2].clone()],
+        }))
+        .await?;
+    storage
+        .chain()
+        .operations_schema()
+        .confirm_operations(1, 3, ActionType::COMMIT)
+        .await?;
+
+    BlockSchema(&mut storage)
+        .save_pending_block(PendingBlock {
+            number: 4,
+            chunks_left: 10,
+            unprocessed_priority_op_before: 0,
+            pending_block_iteration: 1,
+            success_operations: Vec::new(),
+            failed_txs: Vec::new(),
+            previous_block_root_hash: H256::default(),
+            timestamp: 0,
+        })
+        .await?;
+
+    // Block 2 is in the middle of the aggregated operation, so it cannot be the last block.
+    assert!(BlockSchema(&mut storage).revert_blocks(2).await.is_err());
+
+    let summary = BlockSchema(&mut storage).revert_blocks(1).await?;
+    assert_eq!(summary.last_block, 1);
+    assert_eq!(summary.last_committed_block, 3);
+    assert_eq!(summary.pending_block, Some(4));
+    assert_eq!(summary.reverted_on_chain, vec![3, 2]);
+    assert_eq!(summary.removed_aggregated_operations, 1);
+    assert_eq!(summary.removed_prover_jobs, 2);
+    assert!(summary.removed_account_updates > 0);
+
+    // Only block 1 should remain.
+    assert_eq!(
+        BlockSchema(&mut storage).get_last_committed_block().await?,
+        1
+    );
+    assert!(BlockSchema(&mut storage).get_block(2).await?.is_none());
+    assert!(BlockSchema(&mut storage)
+        .load_pending_block()
+        .await?
+        .is_none());
+    assert_eq!(ProverSchema(&mut storage).pending_jobs_count().await?, 1);
+    assert!(StateSchema(&mut storage)
+        .load_state_diff_for_block(2)
+        .await?
+        .is_empty());
+
+    // The only unprocessed operations are the commitment of block 1 and the revert of blocks 2 and 3.
+    let unprocessed_ops = EthereumSchema(&mut storage)
+        .load_unprocessed_operations()
+        .await?;
+    assert_eq!(unprocessed_ops.len(), 2);
+    match &unprocessed_ops[1].1 {
+        AggregatedOperation::RevertBlocks(revert) => {
+            assert_eq!(revert.block_range(), (2, 3));
+        }
+        op => panic!("Unexpected aggregated operation: {:?}", op),
+    }
+
+    Ok(())
+}
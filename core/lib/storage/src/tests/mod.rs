@@ -36,6 +36,7 @@ mod data_restore;
 mod ethereum;
 mod prover;
 mod tokens;
+mod webhooks;
 
 pub use db_test_macro::test as db_test;
 
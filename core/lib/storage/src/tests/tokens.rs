@@ -1,12 +1,16 @@
 // External imports
+use chrono::{Duration, TimeZone, Utc};
 use num::{rational::Ratio, BigUint};
+use sqlx::types::BigDecimal;
 // Workspace imports
-use zksync_types::{Token, TokenId, TokenLike, TokenPrice};
+use zksync_types::{Token, TokenId, TokenLike, TokenPrice, TokenStatus};
 use zksync_utils::{big_decimal_to_ratio, ratio_to_big_decimal};
 // Local imports
 use crate::tests::db_test;
 use crate::{
-    tokens::{TokensSchema, STORED_USD_PRICE_PRECISION},
+    tokens::{
+        TokensSchema, STORED_USD_PRICE_PRECISION, TOKEN_METADATA_CHANGE, TOKEN_STATUS_CHANGE,
+    },
     QueryResult, StorageProcessor,
 };
 
@@ -150,3 +154,203 @@ async fn test_ticker_price(mut storage: StorageProcessor<'_>) -> QueryResult<()>
 
     Ok(())
 }
+
+/// Checks the token metadata and status updates, and that they are recorded in the audit log.
+#[db_test]
+async fn token_management(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let token = Token::new(
+        1,
+        "0000000000000000000000000000000000000001".parse().unwrap(),
+        "ABC",
+        9,
+    );
+    storage.tokens_schema().store_token(token.clone()).await?;
+
+    // Update of the token that doesn't exist has no effect.
+    let updated = storage
+        .tokens_schema()
+        .update_token_metadata(2, Some("DEF".into()), None)
+        .await?;
+    assert!(updated.is_none());
+
+    let updated = storage
+        .tokens_schema()
+        .update_token_metadata(token.id, Some("DEF".into()), None)
+        .await?
+        .expect("token must exist");
+    let expected_token = Token::new(token.id, token.address, "DEF", token.decimals);
+    assert_eq!(updated, expected_token);
+    assert_eq!(
+        storage
+            .tokens_schema()
+            .get_token(TokenLike::Id(token.id))
+            .await?,
+        Some(expected_token.clone())
+    );
+
+    // Tokens have the default status until it's changed.
+    assert_eq!(
+        storage.tokens_schema().get_token_status(token.id).await?,
+        TokenStatus::default()
+    );
+    let status = TokenStatus {
+        deprecated_for_fees: true,
+        paused: false,
+    };
+    storage
+        .tokens_schema()
+        .update_token_status(token.id, status)
+        .await?;
+    assert_eq!(
+        storage.tokens_schema().get_token_status(token.id).await?,
+        status
+    );
+    assert_eq!(
+        storage.tokens_schema().get_token_status(0).await?,
+        TokenStatus::default()
+    );
+
+    let changes = storage.tokens_schema().load_token_changes(token.id).await?;
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].change_type, TOKEN_METADATA_CHANGE);
+    assert_eq!(changes[0].old_value, serde_json::to_value(&token)?);
+    assert_eq!(changes[0].new_value, serde_json::to_value(&expected_token)?);
+    assert_eq!(changes[1].change_type, TOKEN_STATUS_CHANGE);
+    assert_eq!(
+        changes[1].old_value,
+        serde_json::to_value(TokenStatus::default())?
+    );
+    assert_eq!(changes[1].new_value, serde_json::to_value(status)?);
+
+    Ok(())
+}
+
+/// Checks that the token prices are stored in the history, aggregated by intervals and pruned.
+#[db_test]
+async fn ticker_price_history(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    const TOKEN_ID: TokenId = 0;
+    const INTERVAL_SECS: u64 = 60;
+    // Timestamp aligned by the interval.
+    let interval_start = Utc.timestamp(1_600_000_020, 0);
+
+    for (price, offset_secs) in &[(1u32, 0), (3, 10), (5, 70)] {
+        let price = TokenPrice {
+            usd_price: Ratio::from_integer(BigUint::from(*price)),
+            last_updated: interval_start + Duration::seconds(*offset_secs),
+        };
+        storage
+            .tokens_schema()
+            .update_historical_ticker_price(TOKEN_ID, price.clone())
+            .await?;
+        // The same price is stored once.
+        storage
+            .tokens_schema()
+            .update_historical_ticker_price(TOKEN_ID, price)
+            .await?;
+    }
+
+    let history = storage
+        .tokens_schema()
+        .load_ticker_price_history(
+            TOKEN_ID,
+            interval_start,
+            interval_start + Duration::days(1),
+            INTERVAL_SECS,
+        )
+        .await?;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].timestamp, interval_start);
+    assert_eq!(history[0].min_price, BigDecimal::from(1));
+    assert_eq!(history[0].avg_price, BigDecimal::from(2));
+    assert_eq!(history[0].max_price, BigDecimal::from(3));
+    assert_eq!(
+        history[1].timestamp,
+        interval_start + Duration::seconds(INTERVAL_SECS as i64)
+    );
+    assert_eq!(history[1].avg_price, BigDecimal::from(5));
+
+    // The latest price is still stored separately.
+    let latest_price = storage
+        .tokens_schema()
+        .get_historical_ticker_price(TOKEN_ID)
+        .await?
+        .expect("price must be stored");
+    assert_eq!(latest_price.usd_price, Ratio::from_integer(5u32.into()));
+
+    storage
+        .tokens_schema()
+        .remove_ticker_price_history(interval_start + Duration::seconds(INTERVAL_SECS as i64))
+        .await?;
+    let history = storage
+        .tokens_schema()
+        .load_ticker_price_history(
+            TOKEN_ID,
+            interval_start,
+            interval_start + Duration::days(1),
+            INTERVAL_SECS,
+        )
+        .await?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].avg_price, BigDecimal::from(5));
+
+    Ok(())
+}
+
+/// Checks that the fee quote can only be redeemed once, unless it's released or expired.
+#[db_test]
+async fn redeemed_fee_quotes(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let quote_hash = [1u8; 32];
+    let expires_at = Utc.timestamp(1_600_000_000, 0);
+
+    assert!(
+        storage
+            .tokens_schema()
+            .redeem_fee_quote(&quote_hash, expires_at)
+            .await?
+    );
+    assert!(
+        !storage
+            .tokens_schema()
+            .redeem_fee_quote(&quote_hash, expires_at)
+            .await?
+    );
+
+    // Released quote can be redeemed again.
+    storage
+        .tokens_schema()
+        .release_fee_quote(&quote_hash)
+        .await?;
+    assert!(
+        storage
+            .tokens_schema()
+            .redeem_fee_quote(&quote_hash, expires_at)
+            .await?
+    );
+
+    // Expired quotes are removed, but the other ones are kept.
+    let other_quote_hash = [2u8; 32];
+    assert!(
+        storage
+            .tokens_schema()
+            .redeem_fee_quote(&other_quote_hash, expires_at + Duration::seconds(60))
+            .await?
+    );
+    storage
+        .tokens_schema()
+        .remove_expired_fee_quotes(expires_at + Duration::seconds(1))
+        .await?;
+    assert!(
+        storage
+            .tokens_schema()
+            .redeem_fee_quote(&quote_hash, expires_at)
+            .await?
+    );
+    assert!(
+        !storage
+            .tokens_schema()
+            .redeem_fee_quote(&other_quote_hash, expires_at)
+            .await?
+    );
+
+    Ok(())
+}
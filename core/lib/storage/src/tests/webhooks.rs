@@ -0,0 +1,171 @@
+// External imports
+use chrono::{Duration, Utc};
+use serde_json::json;
+// Workspace imports
+use zksync_types::ActionType;
+// Local imports
+use crate::tests::db_test;
+use crate::{webhooks::records::NewWebhookDelivery, QueryResult, StorageProcessor};
+
+/// Checks that the webhooks can be registered, loaded and removed.
+#[db_test]
+async fn webhooks_storage(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let commit_hook = storage
+        .webhooks_schema()
+        .store_webhook(
+            "http://127.0.0.1/commit",
+            "secret",
+            "account",
+            "0x0000000000000000000000000000000000000001",
+            ActionType::COMMIT,
+        )
+        .await?;
+    let verify_hook = storage
+        .webhooks_schema()
+        .store_webhook(
+            "http://127.0.0.1/verify",
+            "secret",
+            "priorityOp",
+            "10",
+            ActionType::VERIFY,
+        )
+        .await?;
+
+    let webhooks = storage.webhooks_schema().load_webhooks().await?;
+    assert_eq!(webhooks, vec![commit_hook.clone(), verify_hook.clone()]);
+
+    let webhooks = storage
+        .webhooks_schema()
+        .load_webhooks_for_action(ActionType::VERIFY)
+        .await?;
+    assert_eq!(webhooks, vec![verify_hook.clone()]);
+
+    assert!(
+        storage
+            .webhooks_schema()
+            .remove_webhook(verify_hook.id)
+            .await?
+    );
+    assert!(
+        !storage
+            .webhooks_schema()
+            .remove_webhook(verify_hook.id)
+            .await?
+    );
+    let webhooks = storage.webhooks_schema().load_webhooks().await?;
+    assert_eq!(webhooks, vec![commit_hook]);
+
+    Ok(())
+}
+
+/// Checks the delivery state transitions: deliveries are claimed once until the lease expires,
+/// failed attempts are retried and deliveries are not claimed once they are sent or failed.
+#[db_test]
+async fn webhook_deliveries(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let webhook = storage
+        .webhooks_schema()
+        .store_webhook(
+            "http://127.0.0.1/hook",
+            "secret",
+            "account",
+            "0x0000000000000000000000000000000000000001",
+            ActionType::COMMIT,
+        )
+        .await?;
+    let delivery = |block_number| NewWebhookDelivery {
+        webhook_id: webhook.id,
+        block_number,
+        action_type: ActionType::COMMIT,
+        payload: json!({ "blockNumber": block_number }),
+    };
+
+    // The same event is stored once.
+    storage
+        .webhooks_schema()
+        .store_deliveries(vec![delivery(1), delivery(2), delivery(1)])
+        .await?;
+    let deliveries = storage
+        .webhooks_schema()
+        .load_deliveries(webhook.id, 10)
+        .await?;
+    assert_eq!(deliveries.len(), 2);
+    assert_eq!(deliveries[0].block_number, 2);
+    assert_eq!(deliveries[1].payload, json!({ "blockNumber": 1 }));
+
+    let now = Utc::now() + Duration::seconds(1);
+    let lease_until = now + Duration::minutes(1);
+    let claimed = storage
+        .webhooks_schema()
+        .claim_pending_deliveries(1, now, lease_until)
+        .await?;
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].url, webhook.url);
+    assert_eq!(claimed[0].secret, webhook.secret);
+    assert_eq!(claimed[0].attempts, 0);
+    let first_id = claimed[0].id;
+
+    // Claimed delivery is not claimed again until the lease expires.
+    let claimed = storage
+        .webhooks_schema()
+        .claim_pending_deliveries(10, now, lease_until)
+        .await?;
+    assert_eq!(claimed.len(), 1);
+    let second_id = claimed[0].id;
+    assert_ne!(first_id, second_id);
+    assert!(storage
+        .webhooks_schema()
+        .claim_pending_deliveries(10, now, lease_until)
+        .await?
+        .is_empty());
+
+    storage
+        .webhooks_schema()
+        .record_delivery_success(first_id)
+        .await?;
+    storage
+        .webhooks_schema()
+        .record_delivery_failure(second_id, "timeout", Some(lease_until))
+        .await?;
+
+    // Only the failed delivery is retried.
+    let claimed = storage
+        .webhooks_schema()
+        .claim_pending_deliveries(10, lease_until, lease_until + Duration::minutes(1))
+        .await?;
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, second_id);
+    assert_eq!(claimed[0].attempts, 1);
+
+    // Delivery that ran out of attempts is not retried.
+    storage
+        .webhooks_schema()
+        .record_delivery_failure(second_id, "timeout", None)
+        .await?;
+    assert!(storage
+        .webhooks_schema()
+        .claim_pending_deliveries(10, lease_until + Duration::days(1), lease_until)
+        .await?
+        .is_empty());
+
+    let deliveries = storage
+        .webhooks_schema()
+        .load_deliveries(webhook.id, 10)
+        .await?;
+    let failed = deliveries.iter().find(|d| d.id == second_id).unwrap();
+    assert!(failed.failed);
+    assert_eq!(failed.attempts, 2);
+    assert_eq!(failed.last_error.as_deref(), Some("timeout"));
+    let delivered = deliveries.iter().find(|d| d.id == first_id).unwrap();
+    assert!(delivered.delivered_at.is_some());
+    assert_eq!(delivered.attempts, 1);
+
+    // Deliveries are removed with the webhook.
+    storage.webhooks_schema().remove_webhook(webhook.id).await?;
+    assert!(storage
+        .webhooks_schema()
+        .load_deliveries(webhook.id, 10)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
@@ -2,11 +2,13 @@
 use std::collections::HashMap;
 use std::time::Instant;
 // External imports
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 // Workspace imports
-use zksync_types::{Token, TokenId, TokenLike, TokenPrice};
+use zksync_types::{Token, TokenId, TokenLike, TokenPrice, TokenStatus};
 use zksync_utils::ratio_to_big_decimal;
 // Local imports
-use self::records::{DbTickerPrice, DbToken};
+use self::records::{DbPriceHistoryEntry, DbTickerPrice, DbToken, DbTokenChange, DbTokenStatus};
 use crate::tokens::utils::address_to_stored_string;
 use crate::{QueryResult, StorageProcessor};
 
@@ -16,6 +18,11 @@ mod utils;
 /// Precision of the USD price per token
 pub(crate) const STORED_USD_PRICE_PRECISION: usize = 6;
 
+/// Type of the token audit log entry for the token metadata changes.
+pub const TOKEN_METADATA_CHANGE: &str = "metadata";
+/// Type of the token audit log entry for the token status changes.
+pub const TOKEN_STATUS_CHANGE: &str = "status";
+
 /// Tokens schema handles the `tokens` table, providing methods to
 /// get and store new tokens.
 #[derive(Debug)]
@@ -137,6 +144,151 @@ impl<'a, 'c> TokensSchema<'a, 'c> {
         Ok(db_token.map(|t| t.into()))
     }
 
+    /// Updates the symbol and/or decimals of the token and records the change in the
+    /// audit log. Returns the updated token, or `None` if there is no such token.
+    pub async fn update_token_metadata(
+        &mut self,
+        token_id: TokenId,
+        symbol: Option<String>,
+        decimals: Option<u8>,
+    ) -> QueryResult<Option<Token>> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let old_token = match TokensSchema(&mut transaction)
+            .get_token(TokenLike::Id(token_id))
+            .await?
+        {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+        let mut new_token = old_token.clone();
+        if let Some(symbol) = symbol {
+            new_token.symbol = symbol;
+        }
+        if let Some(decimals) = decimals {
+            new_token.decimals = decimals;
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE tokens SET symbol = $2, decimals = $3
+            WHERE id = $1
+            "#,
+            i32::from(token_id),
+            new_token.symbol,
+            i16::from(new_token.decimals),
+        )
+        .execute(transaction.conn())
+        .await?;
+        TokensSchema(&mut transaction)
+            .record_token_change(token_id, TOKEN_METADATA_CHANGE, &old_token, &new_token)
+            .await?;
+
+        transaction.commit().await?;
+        metrics::histogram!("sql.token.update_token_metadata", start.elapsed());
+        Ok(Some(new_token))
+    }
+
+    /// Returns the status of the token. Tokens without the stored status have the default one.
+    pub async fn get_token_status(&mut self, token_id: TokenId) -> QueryResult<TokenStatus> {
+        let start = Instant::now();
+        let status = sqlx::query_as!(
+            DbTokenStatus,
+            r#"
+            SELECT * FROM token_status
+            WHERE token_id = $1
+            "#,
+            i32::from(token_id)
+        )
+        .fetch_optional(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.get_token_status", start.elapsed());
+        Ok(status.map(Into::into).unwrap_or_default())
+    }
+
+    /// Sets the status of the token and records the change in the audit log.
+    /// The token is expected to exist.
+    pub async fn update_token_status(
+        &mut self,
+        token_id: TokenId,
+        status: TokenStatus,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let old_status = TokensSchema(&mut transaction)
+            .get_token_status(token_id)
+            .await?;
+        sqlx::query!(
+            r#"
+            INSERT INTO token_status ( token_id, deprecated_for_fees, paused )
+            VALUES ( $1, $2, $3 )
+            ON CONFLICT (token_id)
+            DO
+              UPDATE SET deprecated_for_fees = $2, paused = $3
+            "#,
+            i32::from(token_id),
+            status.deprecated_for_fees,
+            status.paused,
+        )
+        .execute(transaction.conn())
+        .await?;
+        TokensSchema(&mut transaction)
+            .record_token_change(token_id, TOKEN_STATUS_CHANGE, &old_status, &status)
+            .await?;
+
+        transaction.commit().await?;
+        metrics::histogram!("sql.token.update_token_status", start.elapsed());
+        Ok(())
+    }
+
+    /// Loads the audit log of the token changes, ordered from the oldest change to the newest one.
+    pub async fn load_token_changes(
+        &mut self,
+        token_id: TokenId,
+    ) -> QueryResult<Vec<DbTokenChange>> {
+        let start = Instant::now();
+        let changes = sqlx::query_as!(
+            DbTokenChange,
+            r#"
+            SELECT * FROM token_changes
+            WHERE token_id = $1
+            ORDER BY id ASC
+            "#,
+            i32::from(token_id)
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.load_token_changes", start.elapsed());
+        Ok(changes)
+    }
+
+    async fn record_token_change<T: Serialize>(
+        &mut self,
+        token_id: TokenId,
+        change_type: &str,
+        old_value: &T,
+        new_value: &T,
+    ) -> QueryResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO token_changes ( token_id, change_type, old_value, new_value, created_at )
+            VALUES ( $1, $2, $3, $4, $5 )
+            "#,
+            i32::from(token_id),
+            change_type,
+            serde_json::to_value(old_value)?,
+            serde_json::to_value(new_value)?,
+            Utc::now(),
+        )
+        .execute(self.0.conn())
+        .await?;
+        Ok(())
+    }
+
     pub async fn get_historical_ticker_price(
         &mut self,
         token_id: TokenId,
@@ -158,12 +310,15 @@ impl<'a, 'c> TokensSchema<'a, 'c> {
         Ok(db_price.map(|p| p.into()))
     }
 
+    /// Stores the latest price of the token and appends it to the token price history.
     pub async fn update_historical_ticker_price(
         &mut self,
         token_id: TokenId,
         price: TokenPrice,
     ) -> QueryResult<()> {
         let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
         let usd_price_rounded = ratio_to_big_decimal(&price.usd_price, STORED_USD_PRICE_PRECISION);
         sqlx::query!(
             r#"
@@ -177,10 +332,130 @@ impl<'a, 'c> TokensSchema<'a, 'c> {
             usd_price_rounded.clone(),
             price.last_updated
         )
-        .fetch_optional(self.0.conn())
+        .fetch_optional(transaction.conn())
+        .await?;
+
+        // Price sources may report the same price several times, it's stored only once.
+        sqlx::query!(
+            r#"
+            INSERT INTO ticker_price_history ( token_id, usd_price, created_at )
+            VALUES ( $1, $2, $3 )
+            ON CONFLICT (token_id, created_at) DO NOTHING
+            "#,
+            i32::from(token_id),
+            usd_price_rounded,
+            price.last_updated
+        )
+        .execute(transaction.conn())
         .await?;
 
+        transaction.commit().await?;
         metrics::histogram!("sql.token.update_historical_ticker_price", start.elapsed());
         Ok(())
     }
+
+    /// Loads the token price history for the `[from, to)` time range.
+    ///
+    /// Prices are aggregated into intervals of `interval_secs` seconds, intervals without
+    /// stored prices are skipped.
+    pub async fn load_ticker_price_history(
+        &mut self,
+        token_id: TokenId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_secs: u64,
+    ) -> QueryResult<Vec<DbPriceHistoryEntry>> {
+        let start = Instant::now();
+        let entries = sqlx::query_as!(
+            DbPriceHistoryEntry,
+            r#"
+            SELECT
+                to_timestamp(floor(extract(epoch FROM created_at) / $4) * $4) AS "timestamp!",
+                MIN(usd_price) AS "min_price!",
+                AVG(usd_price) AS "avg_price!",
+                MAX(usd_price) AS "max_price!"
+            FROM ticker_price_history
+            WHERE token_id = $1 AND created_at >= $2 AND created_at < $3
+            GROUP BY 1
+            ORDER BY 1 ASC
+            "#,
+            i32::from(token_id),
+            from,
+            to,
+            interval_secs as f64,
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.load_ticker_price_history", start.elapsed());
+        Ok(entries)
+    }
+
+    /// Removes the token prices stored before the provided moment from the history.
+    pub async fn remove_ticker_price_history(&mut self, before: DateTime<Utc>) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM ticker_price_history WHERE created_at < $1",
+            before
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.remove_ticker_price_history", start.elapsed());
+        Ok(())
+    }
+
+    /// Marks the fee quote with the provided hash as redeemed.
+    /// Returns `false` if the quote was already redeemed.
+    pub async fn redeem_fee_quote(
+        &mut self,
+        quote_hash: &[u8],
+        expires_at: DateTime<Utc>,
+    ) -> QueryResult<bool> {
+        let start = Instant::now();
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO redeemed_fee_quotes ( quote_hash, expires_at )
+            VALUES ( $1, $2 )
+            ON CONFLICT (quote_hash) DO NOTHING
+            "#,
+            quote_hash,
+            expires_at
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.redeem_fee_quote", start.elapsed());
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Removes the redemption of the fee quote, so it can be used again.
+    /// Intended for the quotes of the transactions rejected after the redemption.
+    pub async fn release_fee_quote(&mut self, quote_hash: &[u8]) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM redeemed_fee_quotes WHERE quote_hash = $1",
+            quote_hash
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.release_fee_quote", start.elapsed());
+        Ok(())
+    }
+
+    /// Removes the redeemed fee quotes expired before the provided moment.
+    /// Expired quotes are rejected anyway, so there is no need to keep them.
+    pub async fn remove_expired_fee_quotes(&mut self, before: DateTime<Utc>) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            "DELETE FROM redeemed_fee_quotes WHERE expires_at < $1",
+            before
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.token.remove_expired_fee_quotes", start.elapsed());
+        Ok(())
+    }
 }
@@ -6,7 +6,7 @@ use sqlx::{types::BigDecimal, FromRow};
 use crate::tokens::utils::{address_to_stored_string, stored_str_address_to_address};
 use chrono::{DateTime, Utc};
 use zksync_types::tokens::TokenPrice;
-use zksync_types::{Token, TokenId};
+use zksync_types::{Token, TokenId, TokenStatus};
 use zksync_utils::big_decimal_to_ratio;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
@@ -54,3 +54,42 @@ impl Into<TokenPrice> for DbTickerPrice {
         }
     }
 }
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbTokenStatus {
+    pub token_id: i32,
+    pub deprecated_for_fees: bool,
+    pub paused: bool,
+}
+
+impl Into<TokenStatus> for DbTokenStatus {
+    fn into(self) -> TokenStatus {
+        TokenStatus {
+            deprecated_for_fees: self.deprecated_for_fees,
+            paused: self.paused,
+        }
+    }
+}
+
+/// Entry of the tokens audit log.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DbTokenChange {
+    pub id: i64,
+    pub token_id: i32,
+    /// Either `metadata` (`old_value` and `new_value` are `Token`s)
+    /// or `status` (`old_value` and `new_value` are `TokenStatus`es).
+    pub change_type: String,
+    pub old_value: serde_json::Value,
+    pub new_value: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Aggregated prices for the time interval starting at `timestamp`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct DbPriceHistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub min_price: BigDecimal,
+    pub avg_price: BigDecimal,
+    pub max_price: BigDecimal,
+}
@@ -0,0 +1,235 @@
+// Built-in deps
+use std::time::Instant;
+// External imports
+use chrono::{DateTime, Utc};
+// Workspace imports
+use zksync_types::ActionType;
+// Local imports
+use self::records::{
+    NewWebhookDelivery, PendingWebhookDelivery, StoredWebhook, StoredWebhookDelivery,
+};
+use crate::{QueryResult, StorageProcessor};
+
+pub mod records;
+
+/// Webhooks schema handles the `webhooks` table with the registered webhooks and
+/// the `webhook_deliveries` table with the state of the notifications sent to them.
+#[derive(Debug)]
+pub struct WebhooksSchema<'a, 'c>(pub &'a mut StorageProcessor<'c>);
+
+impl<'a, 'c> WebhooksSchema<'a, 'c> {
+    /// Registers a new webhook.
+    pub async fn store_webhook(
+        &mut self,
+        url: &str,
+        secret: &str,
+        event_type: &str,
+        event_id: &str,
+        action_type: ActionType,
+    ) -> QueryResult<StoredWebhook> {
+        let start = Instant::now();
+        let webhook = sqlx::query_as!(
+            StoredWebhook,
+            r#"
+            INSERT INTO webhooks ( url, secret, event_type, event_id, action_type, created_at )
+            VALUES ( $1, $2, $3, $4, $5, $6 )
+            RETURNING *
+            "#,
+            url,
+            secret,
+            event_type,
+            event_id,
+            action_type.to_string(),
+            Utc::now(),
+        )
+        .fetch_one(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.store_webhook", start.elapsed());
+        Ok(webhook)
+    }
+
+    /// Loads all the registered webhooks.
+    pub async fn load_webhooks(&mut self) -> QueryResult<Vec<StoredWebhook>> {
+        let start = Instant::now();
+        let webhooks = sqlx::query_as!(StoredWebhook, "SELECT * FROM webhooks ORDER BY id ASC")
+            .fetch_all(self.0.conn())
+            .await?;
+
+        metrics::histogram!("sql.webhooks.load_webhooks", start.elapsed());
+        Ok(webhooks)
+    }
+
+    /// Loads the webhooks that should be notified about the blocks events of the given type.
+    pub async fn load_webhooks_for_action(
+        &mut self,
+        action_type: ActionType,
+    ) -> QueryResult<Vec<StoredWebhook>> {
+        let start = Instant::now();
+        let webhooks = sqlx::query_as!(
+            StoredWebhook,
+            "SELECT * FROM webhooks WHERE action_type = $1",
+            action_type.to_string()
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.load_webhooks_for_action", start.elapsed());
+        Ok(webhooks)
+    }
+
+    /// Removes the webhook together with all its deliveries.
+    /// Returns `false` if there is no webhook with the given ID.
+    pub async fn remove_webhook(&mut self, id: i64) -> QueryResult<bool> {
+        let start = Instant::now();
+        let removed = sqlx::query!("DELETE FROM webhooks WHERE id = $1 RETURNING id", id)
+            .fetch_optional(self.0.conn())
+            .await?;
+
+        metrics::histogram!("sql.webhooks.remove_webhook", start.elapsed());
+        Ok(removed.is_some())
+    }
+
+    /// Stores the notifications to be delivered. Notification about the block event
+    /// that was already stored for the webhook is ignored, so the same events may be
+    /// safely reported more than once.
+    pub async fn store_deliveries(
+        &mut self,
+        deliveries: Vec<NewWebhookDelivery>,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+        let now = Utc::now();
+        for delivery in deliveries {
+            sqlx::query!(
+                r#"
+                INSERT INTO webhook_deliveries ( webhook_id, block_number, action_type, payload, next_attempt_at, created_at )
+                VALUES ( $1, $2, $3, $4, $5, $5 )
+                ON CONFLICT (webhook_id, block_number, action_type) DO NOTHING
+                "#,
+                delivery.webhook_id,
+                i64::from(delivery.block_number),
+                delivery.action_type.to_string(),
+                delivery.payload,
+                now,
+            )
+            .execute(transaction.conn())
+            .await?;
+        }
+        transaction.commit().await?;
+
+        metrics::histogram!("sql.webhooks.store_deliveries", start.elapsed());
+        Ok(())
+    }
+
+    /// Claims up to `limit` deliveries that are due at `now`.
+    ///
+    /// Next attempt of the claimed deliveries is postponed until `lease_until`, so they
+    /// are not claimed again while being sent, but are retried if the sender has failed
+    /// to record the delivery result.
+    pub async fn claim_pending_deliveries(
+        &mut self,
+        limit: i64,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> QueryResult<Vec<PendingWebhookDelivery>> {
+        let start = Instant::now();
+        let deliveries = sqlx::query_as!(
+            PendingWebhookDelivery,
+            r#"
+            WITH due AS (
+                SELECT id FROM webhook_deliveries
+                WHERE delivered_at IS NULL AND NOT failed AND next_attempt_at <= $2
+                ORDER BY next_attempt_at ASC
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $3
+            FROM due, webhooks
+            WHERE webhook_deliveries.id = due.id AND webhooks.id = webhook_deliveries.webhook_id
+            RETURNING webhook_deliveries.id, webhooks.url, webhooks.secret,
+                webhook_deliveries.payload, webhook_deliveries.attempts
+            "#,
+            limit,
+            now,
+            lease_until,
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.claim_pending_deliveries", start.elapsed());
+        Ok(deliveries)
+    }
+
+    /// Marks the delivery as successfully sent.
+    pub async fn record_delivery_success(&mut self, id: i64) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET attempts = attempts + 1, last_error = NULL, delivered_at = $2
+            WHERE id = $1
+            "#,
+            id,
+            Utc::now(),
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.record_delivery_success", start.elapsed());
+        Ok(())
+    }
+
+    /// Records the failed delivery attempt. Delivery is retried at `next_attempt_at`,
+    /// or is marked as failed if it is not set.
+    pub async fn record_delivery_failure(
+        &mut self,
+        id: i64,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> QueryResult<()> {
+        let start = Instant::now();
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET attempts = attempts + 1, last_error = $2, next_attempt_at = $3, failed = $4
+            WHERE id = $1
+            "#,
+            id,
+            error,
+            next_attempt_at.unwrap_or_else(Utc::now),
+            next_attempt_at.is_none(),
+        )
+        .execute(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.record_delivery_failure", start.elapsed());
+        Ok(())
+    }
+
+    /// Loads up to `limit` latest deliveries of the webhook, ordered from the newest one.
+    pub async fn load_deliveries(
+        &mut self,
+        webhook_id: i64,
+        limit: i64,
+    ) -> QueryResult<Vec<StoredWebhookDelivery>> {
+        let start = Instant::now();
+        let deliveries = sqlx::query_as!(
+            StoredWebhookDelivery,
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE webhook_id = $1
+            ORDER BY id DESC
+            LIMIT $2
+            "#,
+            webhook_id,
+            limit
+        )
+        .fetch_all(self.0.conn())
+        .await?;
+
+        metrics::histogram!("sql.webhooks.load_deliveries", start.elapsed());
+        Ok(deliveries)
+    }
+}
@@ -0,0 +1,52 @@
+// External imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+// Workspace imports
+use zksync_types::{ActionType, BlockNumber};
+// Local imports
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct StoredWebhook {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,
+    pub event_type: String,
+    pub event_id: String,
+    pub action_type: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Notification to be delivered to the webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewWebhookDelivery {
+    pub webhook_id: i64,
+    pub block_number: BlockNumber,
+    pub action_type: ActionType,
+    pub payload: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct StoredWebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub block_number: i64,
+    pub action_type: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub failed: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Delivery claimed to be sent, together with the webhook data required to send it.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct PendingWebhookDelivery {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+}
@@ -131,10 +131,66 @@ fn smt_root_hash_cached(b: &mut Bencher<'_>) {
     );
 }
 
+/// Creates a tree filled with accounts, with the root hash already calculated,
+/// and a list of updates for it, in which every account is updated twice.
+fn filled_tree_with_updates() -> (RealSMT, Vec<(u32, CircuitAccount<Engine>)>) {
+    let depth = zksync_crypto::params::account_tree_depth();
+
+    let mut tree = RealSMT::new(depth);
+    for id in 0..N_ACCOUNTS {
+        tree.insert(id, gen_account(id));
+    }
+    let _ = tree.root_hash();
+
+    let updates = (0..N_ACCOUNTS * 2)
+        .map(|i| (i % N_ACCOUNTS, gen_account(N_ACCOUNTS + i)))
+        .collect();
+    (tree, updates)
+}
+
+/// Measures the time of applying the updates one by one and obtaining the root hash.
+fn smt_update_sequential(b: &mut Bencher<'_>) {
+    let (tree, updates) = filled_tree_with_updates();
+
+    let setup = || (tree.clone(), updates.clone());
+
+    b.iter_batched(
+        setup,
+        |(mut tree, updates)| {
+            for (id, account) in updates {
+                tree.insert(id, account);
+            }
+            let _hash = black_box(tree.root_hash());
+        },
+        BatchSize::SmallInput,
+    );
+}
+
+/// Measures the time of applying the updates as a batch and obtaining the root hash.
+///
+/// This bench is expected to get better results than `smt_update_sequential`, since
+/// repeated updates of the same account are inserted once, and the leaves are hashed in parallel.
+fn smt_update_batch(b: &mut Bencher<'_>) {
+    let (tree, updates) = filled_tree_with_updates();
+
+    let setup = || (tree.clone(), updates.clone());
+
+    b.iter_batched(
+        setup,
+        |(mut tree, updates)| {
+            tree.insert_batch(updates);
+            let _hash = black_box(tree.root_hash());
+        },
+        BatchSize::SmallInput,
+    );
+}
+
 pub fn bench_merkle_tree(c: &mut Criterion) {
     c.bench_function("Parallel SMT create", smt_create);
     c.bench_function("Parallel SMT insert (empty)", smt_insert_empty);
     c.bench_function("Parallel SMT insert (filled)", smt_insert_filled);
     c.bench_function("Parallel SMT root hash", smt_root_hash);
     c.bench_function("Parallel SMT root hash (cached)", smt_root_hash_cached);
+    c.bench_function("Parallel SMT update (sequential)", smt_update_sequential);
+    c.bench_function("Parallel SMT update (batch)", smt_update_batch);
 }
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use zksync_basic_types::{Address, H256};
+use zksync_crypto::circuit::account::{Balance, CircuitAccount};
+use zksync_crypto::circuit::utils::eth_address_to_fr;
+use zksync_crypto::convert::FeConvert;
+use zksync_crypto::franklin_crypto::bellman::pairing::ff::PrimeField;
+use zksync_crypto::merkle_tree::{hasher::Hasher, merkle_path_root, RescueHasher};
+use zksync_crypto::primitives::GetBits;
+use zksync_crypto::serialization::MerklePathSerde;
+use zksync_utils::BigUintSerdeWrapper;
+
+use super::PubKeyHash;
+use crate::aggregated_operations::StoredBlockInfo;
+use crate::{AccountId, AccountTree, Engine, Fr, Nonce, TokenId};
+
+/// Merkle proof of the account token balance in the state of the block.
+///
+/// Proof consists of the account tree leaf data, the path of the token balance in the account
+/// balance tree and the path of the account in the account tree. Root hash of the account tree
+/// is the `state_hash` of the block data stored by the zkSync contract, so the proof can be
+/// checked against the `storedBlockHashes` contract mapping without trusting the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    pub stored_block: StoredBlockInfo,
+    pub account_id: AccountId,
+    pub address: Address,
+    pub nonce: Nonce,
+    pub pub_key_hash: PubKeyHash,
+    pub token_id: TokenId,
+    pub balance: BigUintSerdeWrapper,
+    #[serde(with = "MerklePathSerde")]
+    pub balance_path: Vec<(Fr, bool)>,
+    #[serde(with = "MerklePathSerde")]
+    pub account_path: Vec<(Fr, bool)>,
+}
+
+impl AccountProof {
+    /// Creates a proof of the `token_id` balance of the account from the state tree of
+    /// the block. Proof for the account that doesn't exist proves the empty leaf.
+    ///
+    /// Root hash of the `tree` is expected to be equal to the state hash of the `stored_block`.
+    pub fn new(
+        tree: &AccountTree,
+        account_id: AccountId,
+        token_id: TokenId,
+        stored_block: StoredBlockInfo,
+    ) -> Self {
+        let account = tree.get(account_id).cloned().unwrap_or_default();
+        let balance = account.get_balance(token_id);
+        let balance_path = CircuitAccount::<Engine>::from(account.clone())
+            .subtree
+            .merkle_path(u32::from(token_id));
+
+        Self {
+            stored_block,
+            account_id,
+            address: account.address,
+            nonce: account.nonce,
+            pub_key_hash: account.pub_key_hash,
+            token_id,
+            balance: balance.into(),
+            balance_path,
+            account_path: tree.merkle_path(account_id),
+        }
+    }
+
+    /// Returns the root hash of the account tree obtained from the proof, or `None`
+    /// if the paths don't match the account and token ids.
+    pub fn root_hash(&self) -> Option<Fr> {
+        let hasher = RescueHasher::<Engine>::default();
+
+        let balance = Balance::<Engine> {
+            value: Fr::from_str(&self.balance.0.to_string())?,
+        };
+        let balance_root = merkle_path_root(
+            &hasher,
+            u64::from(self.token_id),
+            hasher.hash_bits(balance.get_bits_le()),
+            &self.balance_path,
+        )?;
+
+        let account_leaf = CircuitAccount::<Engine>::leaf_bits(
+            &hasher,
+            Fr::from_str(&self.nonce.to_string())?,
+            self.pub_key_hash.to_fr(),
+            eth_address_to_fr(&self.address),
+            balance_root,
+        );
+        merkle_path_root(
+            &hasher,
+            u64::from(self.account_id),
+            hasher.hash_bits(account_leaf),
+            &self.account_path,
+        )
+    }
+
+    /// Checks that the proof is consistent, i.e. the account balance paths lead to the
+    /// state hash of the block.
+    ///
+    /// This check alone doesn't prove anything about the zkSync state, use `verify_stored_block`
+    /// to check the proof against the data stored by the zkSync contract.
+    pub fn verify(&self) -> bool {
+        self.balance_path.len() == zksync_crypto::params::balance_tree_depth()
+            && self.account_path.len() == zksync_crypto::params::account_tree_depth()
+            && self.root_hash().map(|root| root.to_bytes())
+                == Some(self.stored_block.state_hash.as_bytes().to_vec())
+    }
+
+    /// Checks the proof against the block hash from the `storedBlockHashes` mapping of the
+    /// zkSync contract.
+    pub fn verify_stored_block(&self, stored_block_hash: H256) -> bool {
+        self.stored_block.hash() == stored_block_hash && self.verify()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Account;
+    use zksync_crypto::params::account_tree_depth;
+
+    fn stored_block(tree: &AccountTree) -> StoredBlockInfo {
+        StoredBlockInfo {
+            block_number: 1,
+            priority_operations: 0,
+            pending_onchain_operations_hash: H256::zero(),
+            timestamp: 0,
+            state_hash: H256::from_slice(&tree.root_hash().to_bytes()),
+            commitment: H256::zero(),
+        }
+    }
+
+    /// Checks that the proofs of existing and absent balances are verified, and that
+    /// the verification fails once the proved data is changed.
+    #[test]
+    fn account_proof_verification() {
+        let mut tree = AccountTree::new(account_tree_depth());
+        let mut account = Account::default_with_address(&Address::repeat_byte(0x11));
+        account.nonce = 5;
+        account.set_balance(1, 100u32.into());
+        account.set_balance(7, 200u32.into());
+        tree.insert(3, account);
+        tree.insert(
+            4,
+            Account::default_with_address(&Address::repeat_byte(0x22)),
+        );
+        let stored_block = stored_block(&tree);
+
+        let proof = AccountProof::new(&tree, 3, 7, stored_block.clone());
+        assert_eq!(proof.balance.0, 200u32.into());
+        assert!(proof.verify());
+        assert!(proof.verify_stored_block(stored_block.hash()));
+        assert!(!proof.verify_stored_block(H256::zero()));
+
+        // Absent token balance and absent account are proved as empty leaves.
+        assert!(AccountProof::new(&tree, 3, 2, stored_block.clone()).verify());
+        assert!(AccountProof::new(&tree, 10, 0, stored_block.clone()).verify());
+
+        let mut wrong_balance = proof.clone();
+        wrong_balance.balance = num::BigUint::from(201u32).into();
+        assert!(!wrong_balance.verify());
+
+        let mut wrong_account = proof.clone();
+        wrong_account.account_id = 4;
+        assert!(!wrong_account.verify());
+
+        let mut wrong_block = proof;
+        wrong_block.stored_block.state_hash = H256::zero();
+        assert!(!wrong_block.verify());
+    }
+}
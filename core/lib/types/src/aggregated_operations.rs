@@ -1,7 +1,8 @@
 use crate::block::Block;
 use ethabi::Token;
+use parity_crypto::Keccak256;
 use serde::{Deserialize, Serialize};
-use zksync_basic_types::{BlockNumber, U256};
+use zksync_basic_types::{BlockNumber, H256, U256};
 use zksync_crypto::proof::EncodedAggregatedProof;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,21 +11,54 @@ pub struct BlocksCommitOperation {
     pub blocks: Vec<Block>,
 }
 
+/// Block data stored by the zkSync contract for the committed blocks.
+///
+/// Contract keeps only the `hash` of this structure in the `storedBlockHashes` mapping, so
+/// the structure itself has to be provided to check the data of the block against it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredBlockInfo {
+    pub block_number: BlockNumber,
+    pub priority_operations: u64,
+    pub pending_onchain_operations_hash: H256,
+    pub timestamp: u64,
+    /// Root hash of the state tree after the block.
+    pub state_hash: H256,
+    pub commitment: H256,
+}
+
+impl StoredBlockInfo {
+    pub fn new(block: &Block) -> Self {
+        Self {
+            block_number: block.block_number,
+            priority_operations: block.number_of_processed_prior_ops(),
+            pending_onchain_operations_hash: block.get_onchain_operations_block_info().1,
+            timestamp: block.timestamp,
+            state_hash: block.get_eth_encoded_root(),
+            commitment: block.block_commitment,
+        }
+    }
+
+    pub fn to_token(&self) -> Token {
+        Token::Tuple(vec![
+            Token::Uint(U256::from(self.block_number)),
+            Token::Uint(U256::from(self.priority_operations)),
+            Token::FixedBytes(self.pending_onchain_operations_hash.as_bytes().to_vec()),
+            Token::Uint(U256::from(self.timestamp)),
+            Token::FixedBytes(self.state_hash.as_bytes().to_vec()),
+            Token::FixedBytes(self.commitment.as_bytes().to_vec()),
+        ])
+    }
+
+    /// Returns the hash of the block data as it's stored in the `storedBlockHashes` mapping
+    /// of the zkSync contract.
+    pub fn hash(&self) -> H256 {
+        ethabi::encode(&[self.to_token()]).keccak256().into()
+    }
+}
+
 pub fn stored_block_info(block: &Block) -> Token {
-    Token::Tuple(vec![
-        Token::Uint(U256::from(block.block_number)),
-        Token::Uint(U256::from(block.number_of_processed_prior_ops())),
-        Token::FixedBytes(
-            block
-                .get_onchain_operations_block_info()
-                .1
-                .as_bytes()
-                .to_vec(),
-        ),
-        Token::Uint(U256::from(block.timestamp)),
-        Token::FixedBytes(block.get_eth_encoded_root().as_bytes().to_vec()),
-        Token::FixedBytes(block.block_commitment.as_bytes().to_vec()),
-    ])
+    StoredBlockInfo::new(block).to_token()
 }
 
 impl BlocksCommitOperation {
@@ -136,12 +170,38 @@ impl BlocksExecuteOperation {
     }
 }
 
+/// Operation reverting committed, but not executed blocks on the Ethereum.
+/// Blocks are expected to be stored in the descending order, starting from the
+/// last committed block, since this is the order expected by the `revertBlocks`
+/// contract method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocksRevertOperation {
+    pub blocks: Vec<Block>,
+}
+
+impl BlocksRevertOperation {
+    pub fn get_eth_tx_args(&self) -> Vec<Token> {
+        vec![Token::Array(
+            self.blocks.iter().map(stored_block_info).collect(),
+        )]
+    }
+
+    pub fn block_range(&self) -> (BlockNumber, BlockNumber) {
+        let BlocksRevertOperation { blocks } = self;
+        (
+            blocks.last().map(|b| b.block_number).unwrap_or_default(),
+            blocks.first().map(|b| b.block_number).unwrap_or_default(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum AggregatedActionType {
     CommitBlocks,
     CreateProofBlocks,
     PublishProofBlocksOnchain,
     ExecuteBlocks,
+    RevertBlocks,
 }
 impl std::string::ToString for AggregatedActionType {
     fn to_string(&self) -> String {
@@ -152,6 +212,7 @@ impl std::string::ToString for AggregatedActionType {
                 "PublishProofBlocksOnchain".to_owned()
             }
             AggregatedActionType::ExecuteBlocks => "ExecuteBlocks".to_owned(),
+            AggregatedActionType::RevertBlocks => "RevertBlocks".to_owned(),
         }
     }
 }
@@ -165,6 +226,7 @@ impl std::str::FromStr for AggregatedActionType {
             "CreateProofBlocks" => Ok(Self::CreateProofBlocks),
             "PublishProofBlocksOnchain" => Ok(Self::PublishProofBlocksOnchain),
             "ExecuteBlocks" => Ok(Self::ExecuteBlocks),
+            "RevertBlocks" => Ok(Self::RevertBlocks),
             _ => Err("Incorrect aggregated action type".to_owned()),
         }
     }
@@ -176,6 +238,7 @@ pub enum AggregatedOperation {
     CreateProofBlocks(BlocksCreateProofOperation),
     PublishProofBlocksOnchain(BlocksProofOperation),
     ExecuteBlocks(BlocksExecuteOperation),
+    RevertBlocks(BlocksRevertOperation),
 }
 
 impl AggregatedOperation {
@@ -187,6 +250,7 @@ impl AggregatedOperation {
                 AggregatedActionType::PublishProofBlocksOnchain
             }
             AggregatedOperation::ExecuteBlocks(..) => AggregatedActionType::ExecuteBlocks,
+            AggregatedOperation::RevertBlocks(..) => AggregatedActionType::RevertBlocks,
         }
     }
 
@@ -201,6 +265,7 @@ impl AggregatedOperation {
             ),
             AggregatedOperation::PublishProofBlocksOnchain(op) => op.block_range(),
             AggregatedOperation::ExecuteBlocks(op) => op.block_range(),
+            AggregatedOperation::RevertBlocks(op) => op.block_range(),
         }
     }
 }
@@ -227,3 +292,9 @@ impl From<BlocksExecuteOperation> for AggregatedOperation {
         Self::ExecuteBlocks(other)
     }
 }
+
+impl From<BlocksRevertOperation> for AggregatedOperation {
+    fn from(other: BlocksRevertOperation) -> Self {
+        Self::RevertBlocks(other)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{Address, TokenId};
+use anyhow::format_err;
 use chrono::{DateTime, Utc};
 use num::{rational::Ratio, BigUint};
 use serde::{Deserialize, Serialize};
-use std::{fmt, fs::read_to_string, path::PathBuf, str::FromStr};
+use std::{convert::TryFrom, fmt, fs::read_to_string, path::PathBuf, str::FromStr};
+use zksync_basic_types::{Log, U256};
 use zksync_utils::parse_env;
 use zksync_utils::UnsignedRatioSerializeAsDecimal;
 
@@ -80,6 +82,29 @@ pub struct Token {
     pub decimals: u8,
 }
 
+/// `NewToken` event emitted by the Governance contract when the token is listed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewTokenEvent {
+    /// Contract address of the listed ERC20 token.
+    pub address: Address,
+    /// Identifier assigned to the token by the Governance contract.
+    pub id: TokenId,
+}
+
+impl TryFrom<Log> for NewTokenEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(event: Log) -> Result<NewTokenEvent, anyhow::Error> {
+        if event.topics.len() != 3 {
+            return Err(format_err!("Failed to parse NewTokenEvent: {:#?}", event));
+        }
+        Ok(NewTokenEvent {
+            address: Address::from_slice(&event.topics[1].as_fixed_bytes()[12..]),
+            id: U256::from_big_endian(&event.topics[2].as_fixed_bytes()[..]).as_u32() as u16,
+        })
+    }
+}
+
 /// Tokens that added when deploying contract
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenGenesisListItem {
@@ -102,6 +127,17 @@ impl Token {
     }
 }
 
+/// Administrative status of the token, set by the server operator.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenStatus {
+    /// Token is not accepted to pay fees in.
+    pub deprecated_for_fees: bool,
+    /// New transfers in the token are not accepted by the API.
+    /// Withdrawals are still allowed, so users can always move their funds to L1.
+    pub paused: bool,
+}
+
 // Hidden as it relies on the filesystem structure, which can be different for reverse dependencies.
 #[doc(hidden)]
 pub fn get_genesis_token_list(network: &str) -> Result<Vec<TokenGenesisListItem>, anyhow::Error> {
@@ -4,7 +4,6 @@ use crate::{
 };
 
 use crate::account::PubKeyHash;
-use anyhow::ensure;
 use num::BigUint;
 use parity_crypto::Keccak256;
 use serde::{Deserialize, Serialize};
@@ -236,7 +235,21 @@ impl ChangePubKey {
     }
 
     /// Provides a message to be signed with the Ethereum private key.
+    ///
+    /// The message includes the batch hash of the ECDSA auth data, so the message is only
+    /// defined for the transaction with ECDSA auth data.
     pub fn get_eth_signed_data(&self) -> Result<Vec<u8>, anyhow::Error> {
+        match &self.eth_auth_data {
+            ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData { batch_hash, .. }) => {
+                Ok(self.get_eth_signed_data_with_batch_hash(*batch_hash))
+            }
+            _ => anyhow::bail!("Change pubkey signed message requires the ECDSA auth data"),
+        }
+    }
+
+    /// Provides a message to be signed with the Ethereum private key for the given batch hash.
+    /// Allows obtaining the message before the ECDSA auth data is set.
+    pub fn get_eth_signed_data_with_batch_hash(&self, batch_hash: H256) -> Vec<u8> {
         // Fee data is not included into ETH signature input, since it would require
         // to either have more chunks in pubdata (if fee amount is unpacked), unpack
         // fee on contract (if fee amount is packed), or display non human-readable
@@ -250,19 +263,9 @@ impl ChangePubKey {
         eth_signed_msg.extend_from_slice(&self.new_pk_hash.data);
         eth_signed_msg.extend_from_slice(&self.nonce.to_be_bytes());
         eth_signed_msg.extend_from_slice(&self.account_id.to_be_bytes());
-        // In case this transaction is not part of a batch, we simply append zeros.
-        if let ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData { batch_hash, .. }) =
-            self.eth_auth_data
-        {
-            eth_signed_msg.extend_from_slice(batch_hash.as_bytes());
-        }
-        ensure!(
-            eth_signed_msg.len() == CHANGE_PUBKEY_SIGNATURE_LEN,
-            "Change pubkey signed message len is too big: {}, expected: {}",
-            eth_signed_msg.len(),
-            CHANGE_PUBKEY_SIGNATURE_LEN
-        );
-        Ok(eth_signed_msg)
+        // In case this transaction is not part of a batch, the batch hash is zero.
+        eth_signed_msg.extend_from_slice(batch_hash.as_bytes());
+        eth_signed_msg
     }
 
     pub fn is_eth_auth_data_valid(&self) -> bool {
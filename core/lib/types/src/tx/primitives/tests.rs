@@ -104,6 +104,12 @@ fn test_batch_message() -> Result<()> {
     });
     let change_pub_key_message = change_pub_key.as_ref().get_eth_signed_data()?;
     assert!(change_pub_key_message.ends_with(batch_hash.as_slice()));
+    assert_eq!(
+        change_pub_key
+            .as_ref()
+            .get_eth_signed_data_with_batch_hash(H256::from_slice(batch_hash.as_slice())),
+        change_pub_key_message
+    );
     // Shouldn't fail.
     let batch_sign_data = BatchSignData::new(&txs, signatures.clone())?;
 
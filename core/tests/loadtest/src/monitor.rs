@@ -18,7 +18,7 @@ use zksync::{
 };
 use zksync_eth_signer::EthereumSigner;
 use zksync_types::{
-    tx::{PackedEthSignature, TxHash},
+    tx::{TxEthSignature, TxHash},
     BlockNumber, PriorityOp, ZkSyncTx, H256,
 };
 // Local uses
@@ -197,7 +197,7 @@ impl Monitor {
     pub async fn send_tx(
         &self,
         tx: ZkSyncTx,
-        eth_signature: Option<PackedEthSignature>,
+        eth_signature: Option<TxEthSignature>,
     ) -> anyhow::Result<TxHash> {
         let created_at = Instant::now();
         let address = tx.account();
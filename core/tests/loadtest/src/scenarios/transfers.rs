@@ -6,7 +6,7 @@ use num::BigUint;
 use serde::{Deserialize, Serialize};
 // Workspace uses
 use zksync::utils::closest_packable_token_amount;
-use zksync_types::{tx::PackedEthSignature, ZkSyncTx};
+use zksync_types::{tx::TxEthSignature, ZkSyncTx};
 // Local uses
 use super::{Fees, Scenario, ScenarioResources};
 use crate::{
@@ -62,7 +62,7 @@ pub struct TransferScenario {
     transfer_size: BigUint,
     transfer_rounds: u64,
     wallets: u64,
-    txs: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
+    txs: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
 }
 
 impl TransferScenario {
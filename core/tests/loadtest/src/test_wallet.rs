@@ -14,7 +14,8 @@ use zksync::{
 use zksync_config::ConfigurationOptions;
 use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::{
-    tx::PackedEthSignature, AccountId, Address, PriorityOp, TokenLike, TxFeeTypes, ZkSyncTx,
+    tx::{PackedEthSignature, TxEthSignature},
+    AccountId, Address, PriorityOp, TokenLike, TxFeeTypes, ZkSyncTx,
 };
 // Local uses
 use crate::{config::AccountInfo, monitor::Monitor, session::save_wallet};
@@ -175,7 +176,7 @@ impl TestWallet {
     pub async fn sign_change_pubkey(
         &self,
         fee: impl Into<BigUint>,
-    ) -> Result<(ZkSyncTx, Option<PackedEthSignature>), ClientError> {
+    ) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError> {
         let tx = self
             .inner
             .start_change_pubkey()
@@ -193,7 +194,7 @@ impl TestWallet {
         &self,
         amount: impl Into<BigUint>,
         fee: impl Into<BigUint>,
-    ) -> Result<(ZkSyncTx, Option<PackedEthSignature>), ClientError> {
+    ) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError> {
         self.inner
             .start_withdraw()
             .nonce(self.pending_nonce())
@@ -211,7 +212,7 @@ impl TestWallet {
         to: impl Into<Address>,
         amount: impl Into<BigUint>,
         fee: BigUint,
-    ) -> Result<(ZkSyncTx, Option<PackedEthSignature>), ClientError> {
+    ) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError> {
         self.inner
             .start_transfer()
             .nonce(self.pending_nonce())
@@ -262,9 +262,7 @@ impl ZkSyncAccount {
         change_pubkey.eth_auth_data = if auth_onchain {
             ChangePubKeyEthAuthData::Onchain
         } else {
-            let sign_bytes = change_pubkey
-                .get_eth_signed_data()
-                .expect("Failed to construct change pubkey signed message.");
+            let sign_bytes = change_pubkey.get_eth_signed_data_with_batch_hash(H256::zero());
             let eth_signature = PackedEthSignature::sign(&self.eth_private_key, &sign_bytes)
                 .expect("Signature should succeed");
             ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData {
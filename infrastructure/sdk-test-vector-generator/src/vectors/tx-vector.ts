@@ -25,9 +25,12 @@ export interface TxTestEntry extends TestVectorEntry {
         // Transaction zkSync signature.
         signature: zksync.types.Signature;
         // Message to be used to provie Ethereum signature. `null` if `inputs.ethSignData` is `null`.
+        // Binary messages (e.g. for `ChangePubKey`) are represented in a hexadecimal form.
         ethSignMessage: string | null;
         // Ethereum signature for a transaction. `null` if `inputs.ethSignData` is `null`.
         ethSignature: string | null;
+        // Hash of the transaction, i.e. SHA-256 hash of the encoded transaction bytes.
+        txHash: string;
     };
 }
 
@@ -99,7 +102,8 @@ async function getTransferSignVector(
             signBytes: utils.hexlify(transferSignBytes),
             signature: transferSignature,
             ethSignMessage: transferEthSignMessage,
-            ethSignature: transferEthSignature.signature
+            ethSignature: transferEthSignature.signature,
+            txHash: getTxHash(transferSignBytes)
         }
     };
 
@@ -142,8 +146,9 @@ async function getChangePubKeySignVector(
         outputs: {
             signBytes: utils.hexlify(changePubKeySignBytes),
             signature: changePubKeySignature,
-            ethSignMessage: changePubKeyEthSignMessage,
-            ethSignature: changePubKeyEthSignature.signature
+            ethSignMessage: utils.hexlify(changePubKeyEthSignMessage),
+            ethSignature: changePubKeyEthSignature.signature,
+            txHash: getTxHash(changePubKeySignBytes)
         }
     };
 
@@ -191,7 +196,8 @@ async function getWithdrawSignVector(
             signBytes: utils.hexlify(withdrawSignBytes),
             signature: withdrawSignature,
             ethSignMessage: withdrawEthSignMessage,
-            ethSignature: withdrawEthSignature.signature
+            ethSignature: withdrawEthSignature.signature,
+            txHash: getTxHash(withdrawSignBytes)
         }
     };
 
@@ -224,9 +230,14 @@ async function getForcedExitSignVector(ethPrivateKey: Uint8Array, signer: zksync
             signBytes: utils.hexlify(forcedExitSignBytes),
             signature: forcedExitSignature,
             ethSignMessage: null,
-            ethSignature: null
+            ethSignature: null,
+            txHash: getTxHash(forcedExitSignBytes)
         }
     };
 
     return forcedExitItem;
 }
+
+function getTxHash(signBytes: Uint8Array): string {
+    return 'sync-tx:' + utils.sha256(signBytes).slice(2);
+}
@@ -44,6 +44,20 @@ export async function checkExisting() {
     await check(expectedHash);
 }
 
+export async function exportSnapshot(path: string) {
+    await utils.spawn(`cargo run --bin zksync_data_restore --release -- --export_snapshot ${path}`);
+}
+
+export async function fromSnapshot(path: string) {
+    await db.reset();
+    await utils.spawn(`cargo run --bin zksync_data_restore --release -- --snapshot ${path} --finite`);
+}
+
+export async function verify(report?: string) {
+    const reportOption = report ? `--report ${report}` : '';
+    await utils.spawn(`cargo run --bin zksync_data_restore --release -- --verify ${reportOption}`);
+}
+
 export const command = new Command('data-restore');
 
 command.command('restart').description('wipe the database and run data restore in finite mode').action(restart);
@@ -60,6 +74,21 @@ command
     .description(`like "check", but instead hash is loaded from the database before wiping it`)
     .action(checkExisting);
 
+command
+    .command('export-snapshot <path>')
+    .description('save the state snapshot of the latest executed block from the database to the file')
+    .action(exportSnapshot);
+
+command
+    .command('from-snapshot <path>')
+    .description('wipe the database and run data restore in finite mode starting from the state snapshot')
+    .action(fromSnapshot);
+
+command
+    .command('verify [report]')
+    .description('restore the state in memory and compare it with the database, optionally saving the report')
+    .action(verify);
+
 command
     .command('root-hash')
     .description('find the hash of the latest verified block and print it')
@@ -58,10 +58,20 @@ export async function prover() {
 export async function js() {
     await utils.spawn('yarn zksync tests');
     await utils.spawn('yarn fee-seller tests');
+    await vectors();
+}
+
+// The stored SDK test vectors must be exactly the output of the `zksync.js` based generator.
+export async function vectors() {
+    process.chdir('infrastructure/sdk-test-vector-generator');
+    await utils.spawn('yarn generate');
+    await utils.spawn('cmp test-vectors.json $ZKSYNC_HOME/etc/test_config/sdk/test-vectors.json');
+    process.chdir(process.env.ZKSYNC_HOME as string);
 }
 
 export async function rust() {
     await utils.spawn('cargo test --release');
+    await utils.spawn('cargo test --release -p zksync --features cli vectors_generator');
     await db(true);
     await rustApi(true);
     await prover();
@@ -72,6 +82,10 @@ export async function rust() {
 export const command = new Command('test').description('run test suites').addCommand(integration.command);
 
 command.command('js').description('run unit-tests for javascript packages').action(js);
+command
+    .command('vectors')
+    .description('check that the stored SDK test vectors match the zksync.js generator output')
+    .action(vectors);
 command.command('prover').description('run unit-tests for the prover').action(prover);
 command.command('contracts').description('run unit-tests for the contracts').action(contracts);
 command.command('rust').description('run unit-tests for all rust binaries and libraries').action(rust);
@@ -0,0 +1,288 @@
+//! Tool to sign zkSync transfers and withdrawals on an offline (air-gapped) machine.
+//!
+//! The transaction template is prepared on an online machine with the `prepare` command,
+//! signed on an offline machine with the `sign` command and submitted later from
+//! an online machine with the `submit` command. Templates and signed transactions
+//! are stored as JSON files and can be inspected with the `review` command.
+//!
+//! Keys are never passed as the command line arguments:
+//! - `ETH_PRIVATE_KEY` environment variable or the `--keystore` file is used for the Ethereum key,
+//!   `KEYSTORE_PASSWORD` environment variable is used as the keystore password if set;
+//! - `ZKSYNC_PRIVATE_KEY` environment variable is used for the zkSync key if set,
+//!   otherwise the key is derived from the Ethereum signature as done by the wallets.
+
+use std::{
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::format_err;
+use num::BigUint;
+use structopt::StructOpt;
+use zksync::{
+    offline::{OfflineTxType, SignedTx, UnsignedTx},
+    provider::Provider,
+    utils::private_key_from_bytes,
+    Network, RpcProvider, WalletCredentials,
+};
+use zksync_eth_signer::{EthereumSigner, KeystoreSigner, PrivateKeySigner};
+use zksync_types::{tx::TxEthSignature, Address, TokenLike, ZkSyncTx, H256};
+use zksync_utils::format_units;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "zkSync offline signer",
+    author = "Matter Labs",
+    rename_all = "snake_case"
+)]
+enum Opt {
+    /// Prepares the unsigned transaction template using the zkSync API
+    Prepare {
+        /// Network to send the transaction to
+        #[structopt(long, default_value = "mainnet")]
+        network: Network,
+        /// zkSync JSON RPC API url, the default one for the network is used if not set
+        #[structopt(long)]
+        rpc: Option<String>,
+        /// Type of the transaction: `transfer` or `withdraw`
+        #[structopt(long = "type", parse(try_from_str = parse_tx_type))]
+        tx_type: OfflineTxType,
+        /// Address of the account sending the transaction
+        #[structopt(long)]
+        from: Address,
+        /// Recipient address for transfers, or Ethereum address for withdrawals
+        #[structopt(long)]
+        to: Address,
+        /// Token symbol, address or ID
+        #[structopt(long)]
+        token: String,
+        /// Amount in the minimal token units, e.g. wei for ETH
+        #[structopt(long)]
+        amount: BigUint,
+        /// Fee in the minimal token units, the fee requested from zkSync is used if not set
+        #[structopt(long)]
+        fee: Option<BigUint>,
+        /// File to write the transaction template to
+        #[structopt(long, short)]
+        output: PathBuf,
+    },
+    /// Reviews and signs the transaction template, should be run on an offline machine
+    Sign {
+        /// File with the transaction template
+        input: PathBuf,
+        /// Encrypted JSON keystore with the Ethereum key, `ETH_PRIVATE_KEY` is used if not set
+        #[structopt(long)]
+        keystore: Option<PathBuf>,
+        /// Sign the transaction without the confirmation
+        #[structopt(long)]
+        yes: bool,
+        /// File to write the signed transaction to
+        #[structopt(long, short)]
+        output: PathBuf,
+    },
+    /// Prints the transaction template or the signed transaction in the human-readable form
+    Review {
+        /// File with the transaction template or the signed transaction
+        input: PathBuf,
+    },
+    /// Submits the signed transaction to zkSync
+    Submit {
+        /// File with the signed transaction
+        input: PathBuf,
+        /// zkSync JSON RPC API url, the default one for the transaction network is used if not set
+        #[structopt(long)]
+        rpc: Option<String>,
+    },
+}
+
+fn parse_tx_type(s: &str) -> anyhow::Result<OfflineTxType> {
+    match s {
+        "transfer" => Ok(OfflineTxType::Transfer),
+        "withdraw" => Ok(OfflineTxType::Withdraw),
+        _ => Err(format_err!("Unknown transaction type: {}", s)),
+    }
+}
+
+fn decode_hex(data: &str) -> anyhow::Result<Vec<u8>> {
+    let data = data.trim();
+    Ok(hex::decode(data.strip_prefix("0x").unwrap_or(data))?)
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+fn write_json(path: &Path, value: &impl serde::Serialize) -> anyhow::Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(value)?)?;
+    println!("Saved to {}", path.display());
+    Ok(())
+}
+
+fn read_line(prompt: &str) -> anyhow::Result<String> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_owned())
+}
+
+fn provider(network: Network, rpc: Option<String>) -> RpcProvider {
+    match rpc {
+        Some(rpc) => RpcProvider::from_addr(rpc),
+        None => RpcProvider::new(network),
+    }
+}
+
+fn print_unsigned_tx(tx: &UnsignedTx) {
+    println!("Network: {}", tx.network);
+    println!("Account: {:?} (ID {})", tx.from, tx.account_id);
+    println!("Nonce: {}", tx.nonce);
+    println!(
+        "Token: {} ({:?}, ID {})",
+        tx.token.symbol, tx.token.address, tx.token.id
+    );
+    println!(
+        "Amount: {} {}",
+        format_units(&tx.amount, tx.token.decimals),
+        tx.token.symbol
+    );
+    println!(
+        "Fee: {} {}",
+        format_units(&tx.fee, tx.token.decimals),
+        tx.token.symbol
+    );
+    println!();
+    println!("Message to be signed by the Ethereum key:");
+    println!("{}", tx.ethereum_sign_message());
+}
+
+fn print_signed_tx(tx: &SignedTx) {
+    let message = tx.ethereum_sign_message();
+    println!("Network: {}", tx.network);
+    match &tx.tx {
+        ZkSyncTx::Transfer(transfer) => {
+            println!("Account: {:?} (ID {})", transfer.from, transfer.account_id);
+            println!("Nonce: {}", transfer.nonce);
+        }
+        ZkSyncTx::Withdraw(withdraw) => {
+            println!("Account: {:?} (ID {})", withdraw.from, withdraw.account_id);
+            println!("Nonce: {}", withdraw.nonce);
+        }
+        _ => {}
+    }
+    println!("Transaction hash: {}", tx.tx.hash().to_string());
+    if let TxEthSignature::EthereumSignature(signature) = &tx.eth_signature {
+        match signature.signature_recover_signer(message.as_bytes()) {
+            Ok(address) => println!("Signed by: {:?}", address),
+            Err(err) => println!("Invalid Ethereum signature: {}", err),
+        }
+    }
+    println!();
+    println!("Message signed by the Ethereum key:");
+    println!("{}", message);
+}
+
+async fn sign_tx<S: EthereumSigner>(tx: &UnsignedTx, eth_signer: S) -> anyhow::Result<SignedTx> {
+    let address = eth_signer.get_address().await?;
+    let credentials = match std::env::var("ZKSYNC_PRIVATE_KEY") {
+        Ok(private_key) => {
+            let private_key = private_key_from_bytes(&decode_hex(&private_key)?)?;
+            WalletCredentials::from_eth_signer_and_pk(address, eth_signer, private_key)
+        }
+        Err(_) => WalletCredentials::from_eth_signer(address, eth_signer, tx.network).await?,
+    };
+
+    Ok(tx.sign(credentials).await?)
+}
+
+#[tokio::main(basic_scheduler)]
+async fn main() -> anyhow::Result<()> {
+    match Opt::from_args() {
+        Opt::Prepare {
+            network,
+            rpc,
+            tx_type,
+            from,
+            to,
+            token,
+            amount,
+            fee,
+            output,
+        } => {
+            let provider = provider(network, rpc);
+            let mut tx = UnsignedTx::prepare(
+                &provider,
+                tx_type,
+                from,
+                to,
+                TokenLike::parse(&token),
+                amount,
+                fee,
+            )
+            .await?;
+            tx.network = network;
+
+            print_unsigned_tx(&tx);
+            write_json(&output, &tx)?;
+        }
+        Opt::Sign {
+            input,
+            keystore,
+            yes,
+            output,
+        } => {
+            let tx: UnsignedTx = read_json(&input)?;
+            print_unsigned_tx(&tx);
+            println!();
+            if !yes && read_line("Sign the transaction? [y/N] ")? != "y" {
+                return Err(format_err!("Signing is cancelled"));
+            }
+
+            let signed_tx = if let Some(keystore) = keystore {
+                let password = match std::env::var("KEYSTORE_PASSWORD") {
+                    Ok(password) => password,
+                    Err(_) => read_line("Keystore password: ")?,
+                };
+                sign_tx(&tx, KeystoreSigner::from_file(keystore, &password)?).await?
+            } else {
+                let private_key = std::env::var("ETH_PRIVATE_KEY").map_err(|_| {
+                    format_err!("Neither `--keystore` nor `ETH_PRIVATE_KEY` is set")
+                })?;
+                let private_key = decode_hex(&private_key)?;
+                if private_key.len() != 32 {
+                    return Err(format_err!("`ETH_PRIVATE_KEY` should be 32 bytes long"));
+                }
+                sign_tx(&tx, PrivateKeySigner::new(H256::from_slice(&private_key))).await?
+            };
+
+            write_json(&output, &signed_tx)?;
+        }
+        Opt::Review { input } => {
+            let contents = std::fs::read_to_string(&input)?;
+            if let Ok(tx) = serde_json::from_str::<SignedTx>(&contents) {
+                print_signed_tx(&tx);
+            } else {
+                print_unsigned_tx(&serde_json::from_str(&contents)?);
+            }
+        }
+        Opt::Submit { input, rpc } => {
+            let tx: SignedTx = read_json(&input)?;
+            print_signed_tx(&tx);
+            println!();
+
+            let provider = provider(tx.network, rpc);
+            let tx_hash = tx.submit(&provider).await?;
+            println!("Transaction sent: {}", tx_hash.to_string());
+            let tx_info = provider.tx_info(tx_hash).await?;
+            if let Some(false) = tx_info.success {
+                return Err(format_err!(
+                    "Transaction failed: {}",
+                    tx_info.fail_reason.unwrap_or_default()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,408 @@
+//! Generator of the deterministic test vectors shared by the zkSync SDKs.
+//!
+//! Produces the vectors in the same JSON format as `infrastructure/sdk-test-vector-generator`,
+//! but using the transactions encoding and signing implemented in `zksync_types`.
+//! Comparing its output with `etc/test_config/sdk/test-vectors.json` (generated by `zksync.js`)
+//! detects the encoding drift between the Rust and TypeScript SDKs.
+
+use std::path::PathBuf;
+
+use num::BigUint;
+use structopt::StructOpt;
+use zksync::utils::{
+    closest_packable_fee_amount, closest_packable_token_amount, is_fee_amount_packable,
+    is_token_amount_packable, pack_fee_amount, pack_token_amount, private_key_from_seed,
+};
+use zksync_config::test_config::unit_vectors::{self as vectors, TestEntry, TestSet, TxData};
+use zksync_crypto::{
+    bellman::{pairing::ff::PrimeField, PrimeFieldRepr},
+    PrivateKey,
+};
+use zksync_types::{
+    tx::{ChangePubKey, PackedEthSignature, TxSignature},
+    Address, ForcedExit, PubKeyHash, Transfer, Withdraw, ZkSyncTx, H256,
+};
+use zksync_utils::format_units;
+
+#[derive(StructOpt)]
+#[structopt(
+    name = "SDK test vector generator",
+    author = "Matter Labs",
+    rename_all = "snake_case"
+)]
+struct Opt {
+    /// File to write the test vectors to, vectors are printed to stdout if not set
+    #[structopt(long, short)]
+    output: Option<PathBuf>,
+}
+
+/// Values used in the packing test vectors.
+const PACKING_VALUES: &[&str] = &[
+    "0",
+    "1000",
+    "1111",
+    "474732833474",
+    "474732833400",
+    "10000000000000",
+];
+
+/// Recipient of the transactions in the transaction test vectors.
+const RECIPIENT: &str = "19aa2ed8712072e918632259780e587698ef58df";
+
+/// Generates the data array filled with `i % 255` values.
+fn generate_array(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 255) as u8).collect()
+}
+
+/// Derives the zkSync private key from the Ethereum one,
+/// the same way the wallets on the mainnet do.
+fn zksync_private_key(eth_private_key: &H256) -> PrivateKey {
+    const MESSAGE: &str = "Access zkSync account.\n\nOnly sign this message for a trusted client!";
+
+    let signature = PackedEthSignature::sign(eth_private_key, MESSAGE.as_bytes())
+        .expect("Failed to sign the message");
+    private_key_from_seed(&signature.serialize_packed()).expect("Failed to derive the key")
+}
+
+fn tx_signature(signature: &TxSignature) -> vectors::Signature {
+    vectors::Signature {
+        pub_key: hex::encode(
+            signature
+                .pub_key
+                .serialize_packed()
+                .expect("Failed to serialize public key"),
+        ),
+        signature: hex::encode(
+            signature
+                .signature
+                .serialize_packed()
+                .expect("Failed to serialize signature"),
+        ),
+    }
+}
+
+fn eth_signature(eth_private_key: &H256, message: &[u8]) -> Option<Vec<u8>> {
+    let signature = PackedEthSignature::sign(eth_private_key, message)
+        .expect("Failed to sign the message with Ethereum key");
+    Some(signature.serialize_packed().to_vec())
+}
+
+fn crypto_primitives_vectors(
+) -> TestSet<vectors::CryptoPrimitiveInput, vectors::CryptoPrimitiveOutput> {
+    let seed = generate_array(32);
+    let message = generate_array(64);
+
+    let private_key = private_key_from_seed(&seed).expect("Failed to derive the key");
+    let mut private_key_bytes = Vec::new();
+    private_key
+        .0
+        .into_repr()
+        .write_be(&mut private_key_bytes)
+        .expect("Failed to serialize the key");
+    let vectors::Signature { pub_key, signature } =
+        tx_signature(&TxSignature::sign_musig(&private_key, &message));
+
+    TestSet {
+        description: "Contains the seed for private key and the message for signing".to_string(),
+        items: vec![TestEntry {
+            inputs: vectors::CryptoPrimitiveInput { seed, message },
+            outputs: vectors::CryptoPrimitiveOutput {
+                private_key: private_key_bytes,
+                pub_key,
+                signature,
+            },
+        }],
+    }
+}
+
+fn transfer_vector(
+    eth_private_key: &H256,
+    private_key: &PrivateKey,
+) -> TestEntry<vectors::TxInput, vectors::TxOutput> {
+    let from = PackedEthSignature::address_from_private_key(eth_private_key).unwrap();
+    let to: Address = RECIPIENT.parse().unwrap();
+    let transfer = Transfer::new_signed(
+        44,
+        from,
+        to,
+        0,
+        1_000_000_000_000u64.into(),
+        1_000_000u64.into(),
+        12,
+        private_key,
+    )
+    .expect("Failed to sign transfer");
+    let eth_sign_message = transfer.get_ethereum_sign_message("ETH", 0);
+
+    TestEntry {
+        inputs: vectors::TxInput {
+            eth_private_key: eth_private_key.as_bytes().to_vec(),
+            data: TxData::Transfer {
+                data: Box::new(vectors::Transfer {
+                    account_id: transfer.account_id,
+                    from,
+                    to,
+                    token_id: transfer.token,
+                    amount: transfer.amount.clone(),
+                    fee: transfer.fee.clone(),
+                    nonce: transfer.nonce,
+                }),
+                eth_sign_data: vectors::TransferSignatureInputs {
+                    string_amount: format_units(&transfer.amount, 0),
+                    string_token: "ETH".to_string(),
+                    string_fee: format_units(&transfer.fee, 0),
+                    to,
+                    account_id: transfer.account_id,
+                    nonce: transfer.nonce,
+                },
+            },
+        },
+        outputs: vectors::TxOutput {
+            sign_bytes: transfer.get_bytes(),
+            signature: tx_signature(&transfer.signature),
+            eth_signature: eth_signature(eth_private_key, eth_sign_message.as_bytes()),
+            eth_sign_message: Some(eth_sign_message),
+            tx_hash: ZkSyncTx::from(transfer).hash(),
+        },
+    }
+}
+
+fn change_pubkey_vector(
+    eth_private_key: &H256,
+    private_key: &PrivateKey,
+) -> TestEntry<vectors::TxInput, vectors::TxOutput> {
+    let account = PackedEthSignature::address_from_private_key(eth_private_key).unwrap();
+    let mut change_pubkey = ChangePubKey::new_signed(
+        55,
+        account,
+        PubKeyHash::from_privkey(private_key),
+        0,
+        1_000_000_000u64.into(),
+        13,
+        None,
+        private_key,
+    )
+    .expect("Failed to sign change pubkey");
+    // Message is signed for the transaction that is not a part of a batch.
+    let eth_sign_message = change_pubkey.get_eth_signed_data_with_batch_hash(H256::zero());
+
+    TestEntry {
+        inputs: vectors::TxInput {
+            eth_private_key: eth_private_key.as_bytes().to_vec(),
+            data: TxData::ChangePubKey {
+                data: Box::new(vectors::ChangePubKey {
+                    account_id: change_pubkey.account_id,
+                    account,
+                    new_pk_hash: change_pubkey.new_pk_hash,
+                    fee_token_id: change_pubkey.fee_token,
+                    fee: change_pubkey.fee.clone(),
+                    nonce: change_pubkey.nonce,
+                }),
+                eth_sign_data: vectors::ChangePubKeySignatureInputs {
+                    pub_key_hash: change_pubkey.new_pk_hash,
+                    account_id: change_pubkey.account_id,
+                    nonce: change_pubkey.nonce,
+                },
+            },
+        },
+        outputs: vectors::TxOutput {
+            sign_bytes: change_pubkey.get_bytes(),
+            signature: tx_signature(&change_pubkey.signature),
+            eth_signature: eth_signature(eth_private_key, &eth_sign_message),
+            eth_sign_message: Some(format!("0x{}", hex::encode(eth_sign_message))),
+            tx_hash: ZkSyncTx::from(change_pubkey).hash(),
+        },
+    }
+}
+
+fn withdraw_vector(
+    eth_private_key: &H256,
+    private_key: &PrivateKey,
+) -> TestEntry<vectors::TxInput, vectors::TxOutput> {
+    let from = PackedEthSignature::address_from_private_key(eth_private_key).unwrap();
+    let eth_address: Address = RECIPIENT.parse().unwrap();
+    let withdraw = Withdraw::new_signed(
+        44,
+        from,
+        eth_address,
+        0,
+        1_000_000_000_000u64.into(),
+        1_000_000u64.into(),
+        12,
+        private_key,
+    )
+    .expect("Failed to sign withdraw");
+    let eth_sign_message = withdraw.get_ethereum_sign_message("ETH", 0);
+
+    TestEntry {
+        inputs: vectors::TxInput {
+            eth_private_key: eth_private_key.as_bytes().to_vec(),
+            data: TxData::Withdraw {
+                data: Box::new(vectors::Withdraw {
+                    account_id: withdraw.account_id,
+                    from,
+                    eth_address,
+                    token_id: withdraw.token,
+                    amount: withdraw.amount.clone(),
+                    fee: withdraw.fee.clone(),
+                    nonce: withdraw.nonce,
+                }),
+                eth_sign_data: vectors::WithdrawSignatureInputs {
+                    string_amount: format_units(&withdraw.amount, 0),
+                    string_token: "ETH".to_string(),
+                    string_fee: format_units(&withdraw.fee, 0),
+                    eth_address,
+                    account_id: withdraw.account_id,
+                    nonce: withdraw.nonce,
+                },
+            },
+        },
+        outputs: vectors::TxOutput {
+            sign_bytes: withdraw.get_bytes(),
+            signature: tx_signature(&withdraw.signature),
+            eth_signature: eth_signature(eth_private_key, eth_sign_message.as_bytes()),
+            eth_sign_message: Some(eth_sign_message),
+            tx_hash: ZkSyncTx::from(withdraw).hash(),
+        },
+    }
+}
+
+fn forced_exit_vector(
+    eth_private_key: &H256,
+    private_key: &PrivateKey,
+) -> TestEntry<vectors::TxInput, vectors::TxOutput> {
+    let from = PackedEthSignature::address_from_private_key(eth_private_key).unwrap();
+    let target: Address = RECIPIENT.parse().unwrap();
+    let forced_exit = ForcedExit::new_signed(44, target, 0, 1_000_000u64.into(), 12, private_key)
+        .expect("Failed to sign forced exit");
+
+    TestEntry {
+        inputs: vectors::TxInput {
+            eth_private_key: eth_private_key.as_bytes().to_vec(),
+            data: TxData::ForcedExit {
+                data: Box::new(vectors::ForcedExit {
+                    initiator_account_id: forced_exit.initiator_account_id,
+                    from,
+                    target,
+                    token_id: forced_exit.token,
+                    fee: forced_exit.fee.clone(),
+                    nonce: forced_exit.nonce,
+                }),
+            },
+        },
+        outputs: vectors::TxOutput {
+            sign_bytes: forced_exit.get_bytes(),
+            signature: tx_signature(&forced_exit.signature),
+            eth_sign_message: None,
+            eth_signature: None,
+            tx_hash: ZkSyncTx::from(forced_exit).hash(),
+        },
+    }
+}
+
+fn tx_vectors() -> TestSet<vectors::TxInput, vectors::TxOutput> {
+    let eth_private_key = H256::from_slice(&generate_array(32));
+    let private_key = zksync_private_key(&eth_private_key);
+
+    TestSet {
+        description: "Contains various zkSync transactions as inputs and zkSync and Ethereum signature data as outputs".to_string(),
+        items: vec![
+            transfer_vector(&eth_private_key, &private_key),
+            change_pubkey_vector(&eth_private_key, &private_key),
+            withdraw_vector(&eth_private_key, &private_key),
+            forced_exit_vector(&eth_private_key, &private_key),
+        ],
+    }
+}
+
+fn packing_vectors(
+    description: &str,
+    is_packable: fn(&BigUint) -> bool,
+    closest_packable: fn(&BigUint) -> BigUint,
+    pack: fn(&BigUint) -> Vec<u8>,
+) -> TestSet<vectors::PackingInput, vectors::PackingOutput> {
+    let items = PACKING_VALUES
+        .iter()
+        .map(|value| {
+            let value: BigUint = value.parse().unwrap();
+            let closest_packable = closest_packable(&value);
+            TestEntry {
+                outputs: vectors::PackingOutput {
+                    packable: is_packable(&value),
+                    packed_value: pack(&closest_packable),
+                    closest_packable,
+                },
+                inputs: vectors::PackingInput { value },
+            }
+        })
+        .collect();
+
+    TestSet {
+        description: description.to_string(),
+        items,
+    }
+}
+
+fn token_formatting_vectors(
+) -> TestSet<vectors::TokenFormattingInput, vectors::TokenFormattingOutput> {
+    let items = [
+        ("NNM", 0, "1000000000000000100000"),
+        ("DAI", 6, "1000000"),
+        ("ZRO", 11, "0"),
+        ("ETH", 18, "1000000000000000100000"),
+    ]
+    .iter()
+    .map(|&(token, decimals, amount)| {
+        let amount: BigUint = amount.parse().unwrap();
+        TestEntry {
+            outputs: vectors::TokenFormattingOutput {
+                formatted: format!("{} {}", format_units(&amount, decimals), token),
+            },
+            inputs: vectors::TokenFormattingInput {
+                token: token.to_string(),
+                decimals,
+                amount,
+            },
+        }
+    })
+    .collect();
+
+    TestSet {
+        description: "Checks for token amount formatting".to_string(),
+        items,
+    }
+}
+
+fn generate_vectors() -> vectors::Config {
+    vectors::Config {
+        crypto_primitives: crypto_primitives_vectors(),
+        transactions: tx_vectors(),
+        utils: vectors::UtilsTests {
+            amount_packing: packing_vectors(
+                "Checks for amount packing",
+                is_token_amount_packable,
+                closest_packable_token_amount,
+                pack_token_amount,
+            ),
+            fee_packing: packing_vectors(
+                "Checks for fee packing",
+                is_fee_amount_packable,
+                closest_packable_fee_amount,
+                pack_fee_amount,
+            ),
+            token_formatting: token_formatting_vectors(),
+        },
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let opt = Opt::from_args();
+    let vectors = serde_json::to_string_pretty(&generate_vectors())?;
+
+    match opt.output {
+        Some(path) => std::fs::write(path, vectors)?,
+        None => println!("{}", vectors),
+    }
+
+    Ok(())
+}
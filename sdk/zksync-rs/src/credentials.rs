@@ -1,9 +1,16 @@
-use crate::{error::ClientError, utils::private_key_from_seed};
+use crate::{
+    error::ClientError,
+    utils::{private_key_from_seed, private_key_from_seed_and_index},
+};
 
 use web3::types::{Address, H256};
 use zksync_crypto::PrivateKey;
 use zksync_eth_signer::{EthereumSigner, PrivateKeySigner};
-use zksync_types::{network::Network, tx::TxEthSignature};
+use zksync_types::{
+    network::Network,
+    tx::{ChangePubKeyCREATE2Data, TxEthSignature},
+    PubKeyHash,
+};
 
 pub struct WalletCredentials<S: EthereumSigner> {
     pub(crate) eth_signer: Option<S>,
@@ -20,7 +27,9 @@ impl<S: EthereumSigner> std::fmt::Debug for WalletCredentials<S> {
 }
 
 impl<S: EthereumSigner> WalletCredentials<S> {
-    /// Creates wallet credentials from the provided Ethereum wallet private key.
+    /// Creates wallet credentials from the provided Ethereum wallet signer.
+    /// Any `EthereumSigner` can be used, e.g. `PrivateKeySigner`, `KeystoreSigner` for the
+    /// encrypted JSON keystores, or `RemoteSigner` for the keys stored by a remote signing service.
     ///
     /// ## Arguments
     ///
@@ -31,6 +40,26 @@ impl<S: EthereumSigner> WalletCredentials<S> {
         eth_address: Address,
         eth_signer: S,
         network: Network,
+    ) -> Result<Self, ClientError> {
+        Self::from_eth_signer_with_key_index(eth_address, eth_signer, network, 0).await
+    }
+
+    /// Creates wallet credentials from the provided Ethereum wallet signer, deriving
+    /// the zkSync private key with the given index from the same signature as `from_eth_signer`.
+    /// Key with index `0` is the one used by `from_eth_signer`, so the signing key
+    /// of the account can be rotated by moving to the next index.
+    ///
+    /// ## Arguments
+    ///
+    /// - `eth_address`: Address of the corresponding Ethereum wallet.
+    /// - `eth_signer`: Abstract signer that signs messages and transactions.
+    /// - `network`: Network this wallet is used on.
+    /// - `key_index`: Index of the zkSync private key.
+    pub async fn from_eth_signer_with_key_index(
+        eth_address: Address,
+        eth_signer: S,
+        network: Network,
+        key_index: u32,
     ) -> Result<Self, ClientError> {
         // Pre-defined message to generate seed from.
         const MESSAGE: &str =
@@ -68,7 +97,7 @@ impl<S: EthereumSigner> WalletCredentials<S> {
 
         // Generate seed, and then zkSync private key.
         let signature_bytes = packed_signature.serialize_packed();
-        let zksync_pk = private_key_from_seed(&signature_bytes)?;
+        let zksync_pk = private_key_from_seed_and_index(&signature_bytes, key_index)?;
 
         Ok(Self {
             eth_signer: Some(eth_signer),
@@ -77,6 +106,48 @@ impl<S: EthereumSigner> WalletCredentials<S> {
         })
     }
 
+    /// Creates wallet credentials from the provided Ethereum signer and zkSync private key.
+    /// Used for the smart contract wallets signing messages with EIP1271 signatures,
+    /// since zkSync private key can't be derived from such signatures.
+    ///
+    /// ## Arguments
+    ///
+    /// - `eth_address`: Address of the wallet contract.
+    /// - `eth_signer`: Signer of the wallet contract messages, e.g. `EIP1271Signer`.
+    /// - `private_key`: Private key of a zkSync account.
+    pub fn from_eth_signer_and_pk(
+        eth_address: Address,
+        eth_signer: S,
+        private_key: PrivateKey,
+    ) -> Self {
+        Self {
+            eth_signer: Some(eth_signer),
+            eth_address,
+            zksync_private_key: private_key,
+        }
+    }
+
+    /// Creates wallet credentials for the account deployed via CREATE2.
+    /// Account address is derived from the CREATE2 data and the public key hash of
+    /// the provided zkSync private key, so the key can't be changed afterwards.
+    ///
+    /// ## Arguments
+    ///
+    /// - `create2_data`: Creator address, salt argument and code hash of the account contract.
+    /// - `private_key`: Private key of a zkSync account.
+    pub fn from_create2_data(
+        create2_data: &ChangePubKeyCREATE2Data,
+        private_key: PrivateKey,
+    ) -> Self {
+        let pubkey_hash = PubKeyHash::from_privkey(&private_key);
+
+        Self {
+            eth_signer: None,
+            eth_address: create2_data.get_address(&pubkey_hash),
+            zksync_private_key: private_key,
+        }
+    }
+
     /// Creates wallet credentials from the provided seed.
     /// zkSync private key will be randomly generated and Ethereum private key will be not set.
     /// Wallet created with such credentials won't be capable of performing on-chain operations,
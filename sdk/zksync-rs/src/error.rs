@@ -1,7 +1,39 @@
 pub use jsonrpc_core::types::response::Failure as RpcFailure;
+use serde::Deserialize;
+use std::fmt;
 use thiserror::Error;
 use zksync_eth_signer::error::SignerError;
 
+/// Error response of the zkSync REST API.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct RestError {
+    /// HTTP status code of the response.
+    #[serde(skip)]
+    pub status: u16,
+    /// A short, human-readable summary of the problem.
+    #[serde(default)]
+    pub title: String,
+    /// A human-readable explanation specific to this occurrence of the problem.
+    #[serde(default)]
+    pub detail: String,
+    /// Internal error code, e.g. the reason of the transaction rejection.
+    #[serde(default)]
+    pub code: Option<u64>,
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.status, self.title)?;
+        if !self.detail.is_empty() {
+            write!(f, ": {}", self.detail)?;
+        }
+        if let Some(code) = self.code {
+            write!(f, " (code {})", code)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Error, PartialEq)]
 pub enum ClientError {
     #[error("Network '{0}' is not supported")]
@@ -10,6 +42,8 @@ pub enum ClientError {
     MalformedResponse(String),
     #[error("RPC error: {0:?}")]
     RpcError(RpcFailure),
+    #[error("REST API error: {0}")]
+    RestError(RestError),
     #[error("Network error: {0}")]
     NetworkError(String),
 
@@ -26,6 +60,10 @@ pub enum ClientError {
     OperationTimeout,
     #[error("Polling interval is too small")]
     PollingIntervalIsTooSmall,
+    #[error("Ethereum transaction has failed or didn't create a priority operation")]
+    NoPriorityOperation,
+    #[error("Transaction failed: {0}")]
+    TransactionFailed(String),
 
     #[error("Signing error: {0}")]
     SigningError(SignerError),
@@ -37,4 +75,6 @@ pub enum ClientError {
 
     #[error("Provided value is not packable")]
     NotPackableValue,
+    #[error("Fee is paid in another token, transaction must be sent in a batch")]
+    FeeInAnotherToken,
 }
@@ -6,14 +6,15 @@ use std::{str::FromStr, time::Instant};
 use web3::contract::tokens::Tokenize;
 use web3::contract::{Contract, Options};
 use web3::transports::Http;
-use web3::types::{TransactionReceipt, H160, H256, U256};
+use web3::types::{Bytes, CallRequest, TransactionReceipt, H160, H256, U256, U64};
 use web3::Web3;
 use zksync_eth_client::ETHClient;
 use zksync_eth_signer::EthereumSigner;
-use zksync_types::{AccountId, PriorityOp, TokenLike};
+use zksync_types::{AccountId, AccountProof, BlockNumber, PriorityOp, TokenLike};
 
 use crate::{
-    error::ClientError, provider::Provider, tokens_cache::TokensCache, utils::u256_to_biguint,
+    error::ClientError, provider::Provider, tokens_cache::TokensCache, types::EthOpInfo,
+    utils::u256_to_biguint,
 };
 
 const IERC20_INTERFACE: &str = include_str!("abi/IERC20.json");
@@ -41,7 +42,8 @@ pub fn ierc20_contract() -> ethabi::Contract {
 /// `EthereumProvider` gains access to on-chain operations, such as deposits and full exits.
 /// Methods to interact with Ethereum return corresponding Ethereum transaction hash.
 /// In order to monitor transaction execution, an Ethereum node `web3` API is exposed
-/// via `EthereumProvider::web3` method.
+/// via `EthereumProvider::web3` method. Execution of the priority operations created by
+/// deposits and full exits can be awaited via `EthereumProvider::priority_op_handle` method.
 #[derive(Debug)]
 pub struct EthereumProvider<S: EthereumSigner> {
     tokens_cache: TokensCache,
@@ -103,6 +105,46 @@ impl<S: EthereumSigner> EthereumProvider<S> {
         self.eth_client.contract_addr
     }
 
+    /// Returns the hash of the block data stored by the zkSync contract in the
+    /// `storedBlockHashes` mapping. Zero hash is returned for the blocks not committed yet.
+    pub async fn stored_block_hash(&self, block_number: BlockNumber) -> Result<H256, ClientError> {
+        let mut data =
+            ethabi::short_signature("storedBlockHashes", &[ethabi::ParamType::Uint(32)]).to_vec();
+        data.extend(ethabi::encode(&[ethabi::Token::Uint(block_number.into())]));
+
+        let request = CallRequest {
+            from: None,
+            to: Some(self.contract_address()),
+            gas: None,
+            gas_price: None,
+            value: None,
+            data: Some(Bytes(data)),
+        };
+        let output = self
+            .web3()
+            .eth()
+            .call(request, None)
+            .await
+            .map_err(|err| ClientError::NetworkError(err.to_string()))?;
+
+        if output.0.len() != 32 {
+            return Err(ClientError::MalformedResponse(format!(
+                "Unexpected `storedBlockHashes` output length: {}",
+                output.0.len()
+            )));
+        }
+        Ok(H256::from_slice(&output.0))
+    }
+
+    /// Checks the account proof obtained from the zkSync API against the block data stored by
+    /// the zkSync contract, so the proved balance doesn't have to be trusted to the server.
+    pub async fn verify_account_proof(&self, proof: &AccountProof) -> Result<bool, ClientError> {
+        let stored_block_hash = self
+            .stored_block_hash(proof.stored_block.block_number)
+            .await?;
+        Ok(proof.verify_stored_block(stored_block_hash))
+    }
+
     /// Returns the Ethereum account balance.
     pub async fn balance(&self) -> Result<BigUint, ClientError> {
         self.eth_client
@@ -351,6 +393,45 @@ impl<S: EthereumSigner> EthereumProvider<S> {
             poller.tick().await;
         }
     }
+
+    /// Waits until the Ethereum transaction creating a priority operation (e.g. deposit or
+    /// full exit) is confirmed, and returns the handle to await the operation execution in zkSync.
+    ///
+    /// Returns an error if the transaction has failed or didn't create a priority operation.
+    pub async fn priority_op_handle<P: Provider>(
+        &self,
+        eth_tx_hash: H256,
+        provider: P,
+    ) -> Result<PriorityOpHandle<P>, ClientError> {
+        let receipt = self.wait_for_tx(eth_tx_hash).await?;
+        let priority_op = self
+            .new_priority_request(&receipt)
+            .ok_or(ClientError::NoPriorityOperation)?;
+
+        Ok(PriorityOpHandle::new(eth_tx_hash, priority_op, provider))
+    }
+
+    /// Returns the priority operation from the `NewPriorityRequest` event emitted
+    /// by the zkSync contract in the transaction.
+    fn new_priority_request(&self, receipt: &TransactionReceipt) -> Option<PriorityOp> {
+        if receipt.status == Some(U64::zero()) {
+            return None;
+        }
+
+        let topic = self
+            .eth_client
+            .contract
+            .event("NewPriorityRequest")
+            .expect("zkSync contract ABI doesn't contain `NewPriorityRequest` event")
+            .signature();
+        receipt
+            .logs
+            .iter()
+            .filter(|log| {
+                log.address == self.contract_address() && log.topics.first() == Some(&topic)
+            })
+            .find_map(|log| PriorityOp::try_from(log.clone()).ok())
+    }
 }
 
 /// Trait describes the ability to receive the priority operation from this holder.
@@ -366,3 +447,119 @@ impl PriorityOpHolder for TransactionReceipt {
             .find_map(|op| PriorityOp::try_from(op.clone()).ok())
     }
 }
+
+/// Handle for the priority operation created by the Ethereum transaction, providing an
+/// interface to await its execution in zkSync. For obtained handle it's possible to set
+/// the polling interval, commit timeout and verify timeout values.
+///
+/// By default, awaiting for operation may run up to forever, and the polling is
+/// performed once a second.
+#[derive(Debug)]
+pub struct PriorityOpHandle<P: Provider> {
+    eth_tx_hash: H256,
+    priority_op: PriorityOp,
+    provider: P,
+    polling_interval: Duration,
+    commit_timeout: Option<Duration>,
+    verify_timeout: Option<Duration>,
+}
+
+impl<P: Provider> PriorityOpHandle<P> {
+    pub fn new(eth_tx_hash: H256, priority_op: PriorityOp, provider: P) -> Self {
+        Self {
+            eth_tx_hash,
+            priority_op,
+            provider,
+            polling_interval: Duration::from_secs(1), // 1 second.
+            commit_timeout: None,                     // Wait until forever
+            verify_timeout: None,                     // Wait until forever
+        }
+    }
+
+    /// Sets the polling interval. Must be at least 200 milliseconds.
+    pub fn polling_interval(&mut self, polling_interval: Duration) -> Result<(), ClientError> {
+        if polling_interval >= Duration::from_millis(200) {
+            self.polling_interval = polling_interval;
+            Ok(())
+        } else {
+            Err(ClientError::PollingIntervalIsTooSmall)
+        }
+    }
+
+    /// Returns the hash of the Ethereum transaction that created the operation.
+    pub fn eth_tx_hash(&self) -> H256 {
+        self.eth_tx_hash
+    }
+
+    /// Returns the priority operation.
+    pub fn priority_op(&self) -> &PriorityOp {
+        &self.priority_op
+    }
+
+    /// Returns the serial ID of the priority operation.
+    pub fn serial_id(&self) -> u64 {
+        self.priority_op.serial_id
+    }
+
+    /// Sets the timeout for commit operation.
+    /// With this value set, `PriorityOpHandle::wait_for_commit` will return a `ClientError::OperationTimeout`
+    /// error if block will not be committed within provided time range.
+    pub fn commit_timeout(mut self, commit_timeout: Duration) -> Self {
+        self.commit_timeout = Some(commit_timeout);
+        self
+    }
+
+    /// Sets the timeout for verify operation.
+    /// With this value set, `PriorityOpHandle::wait_for_verify` will return a `ClientError::OperationTimeout`
+    /// error if block will not be verified within provided time range.
+    pub fn verify_timeout(mut self, verify_timeout: Duration) -> Self {
+        self.verify_timeout = Some(verify_timeout);
+        self
+    }
+
+    /// Awaits for the operation commit and returns the information about execution.
+    pub async fn wait_for_commit(&self) -> Result<EthOpInfo, ClientError> {
+        let mut timer = tokio::time::interval(self.polling_interval);
+        let start = Instant::now();
+
+        loop {
+            timer.tick().await;
+
+            if let Some(commit_timeout) = self.commit_timeout {
+                if start.elapsed() >= commit_timeout {
+                    return Err(ClientError::OperationTimeout);
+                }
+            }
+
+            let response = self.provider.ethop_info(self.serial_id() as u32).await?;
+            if let Some(block) = &response.block {
+                if block.committed {
+                    return Ok(response);
+                }
+            }
+        }
+    }
+
+    /// Awaits for the operation verification and returns the information about execution.
+    pub async fn wait_for_verify(&self) -> Result<EthOpInfo, ClientError> {
+        let mut timer = tokio::time::interval(self.polling_interval);
+        let start = Instant::now();
+
+        loop {
+            timer.tick().await;
+
+            if let Some(verify_timeout) = self.verify_timeout {
+                if start.elapsed() >= verify_timeout {
+                    return Err(ClientError::OperationTimeout);
+                }
+            }
+
+            let response = self.provider.ethop_info(self.serial_id() as u32).await?;
+            if let Some(block) = &response.block {
+                if block.verified {
+                    return Ok(response);
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod credentials;
 pub mod error;
 pub mod ethereum;
+pub mod offline;
 pub mod operations;
 pub mod provider;
 pub mod signer;
@@ -8,12 +9,19 @@ pub mod tokens_cache;
 pub mod types;
 pub mod utils;
 pub mod wallet;
+pub mod wallet_pool;
 
 pub use crate::{
-    credentials::WalletCredentials, ethereum::EthereumProvider, provider::RpcProvider,
+    credentials::WalletCredentials,
+    ethereum::EthereumProvider,
+    provider::{RestProvider, RpcProvider},
     wallet::Wallet,
+    wallet_pool::WalletPool,
 };
 pub use zksync_types::network::Network;
 
+#[cfg(feature = "mock")]
+pub use crate::provider::MockProvider;
+
 pub use web3;
 pub use zksync_types;
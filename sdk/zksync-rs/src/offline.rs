@@ -0,0 +1,234 @@
+//! Signing the transactions on an offline (air-gapped) machine.
+//!
+//! `Signer` requires the account ID, nonce, token and fee, which are only available from
+//! the zkSync server. So the offline signing is split into three steps:
+//!
+//! 1. `UnsignedTx` is prepared on an online machine via `UnsignedTx::prepare` and exported as JSON.
+//! 2. The template is reviewed and signed on an offline machine via `UnsignedTx::sign`,
+//!    producing the `SignedTx`.
+//! 3. The signed transaction is submitted from an online machine via `SignedTx::submit`.
+
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use zksync_eth_signer::{error::SignerError, EthereumSigner};
+use zksync_types::{
+    helpers::{closest_packable_fee_amount, is_token_amount_packable},
+    network::Network,
+    tx::{TxEthSignature, TxHash},
+    AccountId, Address, Nonce, PubKeyHash, Token, TokenLike, Transfer, TxFeeTypes, Withdraw,
+    ZkSyncTx,
+};
+use zksync_utils::BigUintSerdeAsRadix10Str;
+
+use crate::{
+    credentials::WalletCredentials, error::ClientError, provider::Provider, signer::Signer,
+    tokens_cache::TokensCache,
+};
+
+/// Type of the transaction which can be signed offline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OfflineTxType {
+    Transfer,
+    Withdraw,
+}
+
+impl OfflineTxType {
+    fn fee_type(self) -> TxFeeTypes {
+        match self {
+            Self::Transfer => TxFeeTypes::Transfer,
+            Self::Withdraw => TxFeeTypes::Withdraw,
+        }
+    }
+}
+
+/// Template of the transaction, containing everything required to sign it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsignedTx {
+    pub network: Network,
+    pub tx_type: OfflineTxType,
+    pub account_id: AccountId,
+    pub from: Address,
+    /// Current signing key of the account, used to check that the transaction
+    /// is signed with the correct zkSync key.
+    pub pub_key_hash: PubKeyHash,
+    /// Recipient address for transfers, or Ethereum address for withdrawals.
+    pub to: Address,
+    pub token: Token,
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    pub amount: BigUint,
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    pub fee: BigUint,
+    pub nonce: Nonce,
+}
+
+impl UnsignedTx {
+    /// Prepares the transaction template, fetching the account state and token from the provider.
+    /// If the fee is not set, the fee requested from the provider is used.
+    ///
+    /// Returns an error if the amount is not packable, since it can't be changed after the review.
+    pub async fn prepare<P: Provider>(
+        provider: &P,
+        tx_type: OfflineTxType,
+        from: Address,
+        to: Address,
+        token: impl Into<TokenLike>,
+        amount: BigUint,
+        fee: Option<BigUint>,
+    ) -> Result<Self, ClientError> {
+        if !is_token_amount_packable(&amount) {
+            return Err(ClientError::NotPackableValue);
+        }
+
+        let account_info = provider.account_info(from).await?;
+        let account_id = account_info
+            .id
+            .ok_or_else(|| ClientError::MissingRequiredField("account_id".into()))?;
+        let token = TokensCache::new(provider.tokens().await?)
+            .resolve(token.into())
+            .ok_or(ClientError::UnknownToken)?;
+
+        let fee = match fee {
+            Some(fee) => fee,
+            None => {
+                provider
+                    .get_tx_fee(tx_type.fee_type(), to, token.id)
+                    .await?
+                    .total_fee
+            }
+        };
+
+        Ok(Self {
+            network: provider.network(),
+            tx_type,
+            account_id,
+            from,
+            pub_key_hash: account_info.committed.pub_key_hash,
+            to,
+            token,
+            amount,
+            fee: closest_packable_fee_amount(&fee),
+            nonce: account_info.committed.nonce,
+        })
+    }
+
+    /// Returns the message to be signed by the Ethereum key, describing the transaction
+    /// in the human-readable form. Should be reviewed before signing the transaction.
+    pub fn ethereum_sign_message(&self) -> String {
+        ethereum_sign_message(&self.unsigned_tx(), &self.token)
+    }
+
+    /// Signs the transaction with the zkSync and Ethereum keys of the account.
+    ///
+    /// Returns an error if the credentials don't belong to the account or the zkSync key
+    /// is not the current signing key of the account.
+    pub async fn sign<S: EthereumSigner>(
+        &self,
+        credentials: WalletCredentials<S>,
+    ) -> Result<SignedTx, ClientError> {
+        let mut signer = Signer::with_credentials(credentials);
+        if signer.address != self.from || signer.pubkey_hash != self.pub_key_hash {
+            return Err(ClientError::IncorrectCredentials);
+        }
+        if signer.eth_signer.is_none() {
+            return Err(ClientError::NoEthereumPrivateKey);
+        }
+        signer.set_account_id(Some(self.account_id));
+
+        let (tx, eth_signature) = match self.tx_type {
+            OfflineTxType::Transfer => signer
+                .sign_transfer(
+                    self.token.clone(),
+                    self.amount.clone(),
+                    self.fee.clone(),
+                    self.to,
+                    self.nonce,
+                )
+                .await
+                .map(|(tx, signature)| (ZkSyncTx::Transfer(Box::new(tx)), signature)),
+            OfflineTxType::Withdraw => signer
+                .sign_withdraw(
+                    self.token.clone(),
+                    self.amount.clone(),
+                    self.fee.clone(),
+                    self.to,
+                    self.nonce,
+                )
+                .await
+                .map(|(tx, signature)| (ZkSyncTx::Withdraw(Box::new(tx)), signature)),
+        }
+        .map_err(ClientError::SigningError)?;
+
+        Ok(SignedTx {
+            network: self.network,
+            token: self.token.clone(),
+            tx,
+            eth_signature: eth_signature
+                .ok_or(ClientError::SigningError(SignerError::MissingEthSigner))?,
+        })
+    }
+
+    fn unsigned_tx(&self) -> ZkSyncTx {
+        match self.tx_type {
+            OfflineTxType::Transfer => ZkSyncTx::Transfer(Box::new(Transfer::new(
+                self.account_id,
+                self.from,
+                self.to,
+                self.token.id,
+                self.amount.clone(),
+                self.fee.clone(),
+                self.nonce,
+                None,
+            ))),
+            OfflineTxType::Withdraw => ZkSyncTx::Withdraw(Box::new(Withdraw::new(
+                self.account_id,
+                self.from,
+                self.to,
+                self.token.id,
+                self.amount.clone(),
+                self.fee.clone(),
+                self.nonce,
+                None,
+            ))),
+        }
+    }
+}
+
+/// Transaction signed offline, ready to be submitted to the zkSync network.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedTx {
+    pub network: Network,
+    /// Token of the transaction, used to restore the message signed by the Ethereum key.
+    pub token: Token,
+    pub tx: ZkSyncTx,
+    pub eth_signature: TxEthSignature,
+}
+
+impl SignedTx {
+    /// Returns the message signed by the Ethereum key.
+    pub fn ethereum_sign_message(&self) -> String {
+        ethereum_sign_message(&self.tx, &self.token)
+    }
+
+    /// Submits the transaction to the zkSync network, returning its hash.
+    ///
+    /// Returns an error if the transaction was prepared for another network.
+    pub async fn submit<P: Provider>(self, provider: &P) -> Result<TxHash, ClientError> {
+        let network = provider.network();
+        if network != Network::Unknown && network != self.network {
+            return Err(ClientError::NetworkNotSupported(network.to_string()));
+        }
+
+        provider.send_tx(self.tx, Some(self.eth_signature)).await
+    }
+}
+
+fn ethereum_sign_message(tx: &ZkSyncTx, token: &Token) -> String {
+    match tx {
+        ZkSyncTx::Transfer(tx) => tx.get_ethereum_sign_message(&token.symbol, token.decimals),
+        ZkSyncTx::Withdraw(tx) => tx.get_ethereum_sign_message(&token.symbol, token.decimals),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,320 @@
+use num::BigUint;
+use zksync_eth_signer::EthereumSigner;
+use zksync_types::{
+    helpers::{closest_packable_fee_amount, closest_packable_token_amount},
+    tx::TxEthSignature,
+    Address, Nonce, Token, TokenLike, TxFeeTypes, ZkSyncTx,
+};
+
+use crate::{
+    error::ClientError,
+    operations::{sign_fee_transfer, SyncTransactionHandle},
+    provider::Provider,
+    wallet::Wallet,
+};
+
+/// Operation added to the batch.
+#[derive(Debug, Clone)]
+enum BatchOperation {
+    Transfer {
+        token: Token,
+        amount: BigUint,
+        to: Address,
+    },
+    Withdraw {
+        token: Token,
+        amount: BigUint,
+        to: Address,
+    },
+}
+
+impl BatchOperation {
+    fn token(&self) -> &Token {
+        match self {
+            Self::Transfer { token, .. } | Self::Withdraw { token, .. } => token,
+        }
+    }
+
+    fn fee_type(&self) -> (TxFeeTypes, Address) {
+        match self {
+            Self::Transfer { to, .. } => (TxFeeTypes::Transfer, *to),
+            Self::Withdraw { to, .. } => (TxFeeTypes::Withdraw, *to),
+        }
+    }
+}
+
+/// Transaction of the batch paying the fee for the whole batch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FeePayer {
+    ChangePubKey,
+    Operation(usize),
+    /// None of the operations is in the fee token, so the fee is paid
+    /// by an additional transfer at the end of the batch.
+    FeeTransfer,
+}
+
+/// Builder of the transactions batch, which is executed atomically.
+///
+/// Transactions of the batch get sequential nonces and the fee for the whole batch
+/// is paid by a single transaction in the fee token, so the rest of transactions
+/// don't pay fee. Batch is authorized by a single Ethereum signature.
+#[derive(Debug)]
+pub struct BatchBuilder<'a, S: EthereumSigner, P: Provider> {
+    wallet: &'a Wallet<S, P>,
+    /// If set, the batch starts with the `ChangePubKey` transaction, authorized
+    /// either onchain (`true`) or by the Ethereum signature (`false`).
+    change_pubkey: Option<bool>,
+    operations: Vec<BatchOperation>,
+    fee_token: Option<Token>,
+    fee: Option<BigUint>,
+    nonce: Option<Nonce>,
+}
+
+impl<'a, S, P> BatchBuilder<'a, S, P>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    /// Initializes a transactions batch building process.
+    pub fn new(wallet: &'a Wallet<S, P>) -> Self {
+        Self {
+            wallet,
+            change_pubkey: None,
+            operations: Vec::new(),
+            fee_token: None,
+            fee: None,
+            nonce: None,
+        }
+    }
+
+    /// Returns the signed transactions of the batch and the Ethereum signature of the batch.
+    /// Batch signature is only provided if the wallet has an Ethereum signer.
+    ///
+    /// Fee for the whole batch is paid by the `ChangePubKey` if it's included into the batch,
+    /// otherwise by the first operation in the fee token. If there is no such operation,
+    /// the fee is paid by the transfer of zero amount in the fee token to the wallet itself.
+    #[allow(clippy::type_complexity)]
+    pub async fn txs(
+        self,
+    ) -> Result<(Vec<(ZkSyncTx, Option<TxEthSignature>)>, Vec<TxEthSignature>), ClientError> {
+        let fee_token = self
+            .fee_token
+            .clone()
+            .ok_or_else(|| ClientError::MissingRequiredField("fee_token".into()))?;
+        if self.change_pubkey.is_none() && self.operations.is_empty() {
+            return Err(ClientError::MissingRequiredField("operations".into()));
+        }
+
+        let fee_payer = if self.change_pubkey.is_some() {
+            FeePayer::ChangePubKey
+        } else {
+            self.operations
+                .iter()
+                .position(|operation| operation.token().id == fee_token.id)
+                .map(FeePayer::Operation)
+                .unwrap_or(FeePayer::FeeTransfer)
+        };
+
+        let mut nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => {
+                let account_info = self
+                    .wallet
+                    .provider
+                    .account_info(self.wallet.address())
+                    .await?;
+                account_info.committed.nonce
+            }
+        };
+
+        let fee = match &self.fee {
+            Some(fee) => fee.clone(),
+            None => self.get_batch_fee(&fee_token, fee_payer).await?,
+        };
+        let fee_for = |payer| {
+            if payer == fee_payer {
+                fee.clone()
+            } else {
+                BigUint::from(0u32)
+            }
+        };
+
+        let signer = &self.wallet.signer;
+        let mut txs = Vec::with_capacity(self.operations.len() + 2);
+        if self.change_pubkey.is_some() {
+            // Ethereum authorization depends on the batch hash, so it's set once all
+            // the transactions are signed.
+            let change_pubkey = signer
+                .sign_change_pubkey_tx(
+                    nonce,
+                    true,
+                    fee_token.clone(),
+                    fee_for(FeePayer::ChangePubKey),
+                )
+                .await
+                .map_err(ClientError::SigningError)?;
+            txs.push((ZkSyncTx::from(change_pubkey), None));
+            nonce += 1;
+        }
+
+        for (idx, operation) in self.operations.into_iter().enumerate() {
+            let fee = fee_for(FeePayer::Operation(idx));
+            let tx = match operation {
+                BatchOperation::Transfer { token, amount, to } => signer
+                    .sign_transfer(token, amount, fee, to, nonce)
+                    .await
+                    .map(|(tx, signature)| (ZkSyncTx::Transfer(Box::new(tx)), signature)),
+                BatchOperation::Withdraw { token, amount, to } => signer
+                    .sign_withdraw(token, amount, fee, to, nonce)
+                    .await
+                    .map(|(tx, signature)| (ZkSyncTx::Withdraw(Box::new(tx)), signature)),
+            }
+            .map_err(ClientError::SigningError)?;
+            txs.push(tx);
+            nonce += 1;
+        }
+
+        if fee_payer == FeePayer::FeeTransfer {
+            txs.push(sign_fee_transfer(self.wallet, fee_token, fee.clone(), nonce).await?);
+        }
+
+        if self.change_pubkey == Some(false) {
+            let mut batch: Vec<_> = txs.iter().map(|(tx, _)| tx.clone()).collect();
+            signer
+                .sign_change_pubkey_batch_auth(&mut batch)
+                .await
+                .map_err(ClientError::SigningError)?;
+            for ((tx, _), authorized_tx) in txs.iter_mut().zip(batch) {
+                *tx = authorized_tx;
+            }
+        }
+
+        let eth_signatures = if signer.eth_signer.is_some() {
+            let batch: Vec<_> = txs.iter().map(|(tx, _)| tx.clone()).collect();
+            let signature = signer
+                .sign_batch(&batch)
+                .await
+                .map_err(ClientError::SigningError)?;
+            vec![signature]
+        } else {
+            Vec::new()
+        };
+
+        Ok((txs, eth_signatures))
+    }
+
+    /// Sends the batch, returning the handles for awaiting every transaction of the batch.
+    pub async fn send(self) -> Result<Vec<SyncTransactionHandle<P>>, ClientError> {
+        let provider = self.wallet.provider.clone();
+
+        let (txs, eth_signatures) = self.txs().await?;
+        let tx_hashes = provider.send_txs_batch(txs, eth_signatures).await?;
+
+        Ok(tx_hashes
+            .into_iter()
+            .map(|tx_hash| SyncTransactionHandle::new(tx_hash, provider.clone()))
+            .collect())
+    }
+
+    /// Adds the `ChangePubKey` transaction setting the wallet signing key.
+    /// It is the first transaction of the batch, so the rest of transactions are signed
+    /// with the new key.
+    ///
+    /// If `onchain_auth` is not set, the key change is authorized by the batch Ethereum signature.
+    pub fn change_pubkey(mut self, onchain_auth: bool) -> Self {
+        self.change_pubkey = Some(onchain_auth);
+        self
+    }
+
+    /// Adds the transfer to the batch. If the provided amount is not packable,
+    /// rounds it to the closest packable amount.
+    /// Returns an error if token is not supported by zkSync.
+    pub fn add_transfer(
+        mut self,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+        to: Address,
+    ) -> Result<Self, ClientError> {
+        let token = self.resolve_token(token)?;
+        let amount = closest_packable_token_amount(&amount.into());
+
+        self.operations
+            .push(BatchOperation::Transfer { token, amount, to });
+        Ok(self)
+    }
+
+    /// Adds the withdrawal to the batch. If the provided amount is not packable,
+    /// rounds it to the closest packable amount.
+    /// Returns an error if token is not supported by zkSync.
+    pub fn add_withdraw(
+        mut self,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+        to: Address,
+    ) -> Result<Self, ClientError> {
+        let token = self.resolve_token(token)?;
+        let amount = closest_packable_token_amount(&amount.into());
+
+        self.operations
+            .push(BatchOperation::Withdraw { token, amount, to });
+        Ok(self)
+    }
+
+    /// Sets the token to pay the batch fee in. Returns an error if token is not supported by zkSync.
+    pub fn fee_token(mut self, token: impl Into<TokenLike>) -> Result<Self, ClientError> {
+        self.fee_token = Some(self.resolve_token(token)?);
+        Ok(self)
+    }
+
+    /// Set the fee amount for the whole batch. If the provided fee is not packable,
+    /// rounds it to the closest packable fee amount.
+    ///
+    /// For more details, see [utils](../utils/index.html) functions.
+    pub fn fee(mut self, fee: impl Into<BigUint>) -> Self {
+        let fee = closest_packable_fee_amount(&fee.into());
+        self.fee = Some(fee);
+
+        self
+    }
+
+    /// Sets the nonce of the first transaction in the batch.
+    pub fn nonce(mut self, nonce: Nonce) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    fn resolve_token(&self, token: impl Into<TokenLike>) -> Result<Token, ClientError> {
+        self.wallet
+            .tokens
+            .resolve(token.into())
+            .ok_or(ClientError::UnknownToken)
+    }
+
+    async fn get_batch_fee(
+        &self,
+        fee_token: &Token,
+        fee_payer: FeePayer,
+    ) -> Result<BigUint, ClientError> {
+        let mut fee_types = Vec::with_capacity(self.operations.len() + 1);
+        if let Some(onchain_auth) = self.change_pubkey {
+            fee_types.push((
+                TxFeeTypes::ChangePubKey {
+                    onchain_pubkey_auth: onchain_auth,
+                },
+                self.wallet.address(),
+            ));
+        }
+        fee_types.extend(self.operations.iter().map(BatchOperation::fee_type));
+        if fee_payer == FeePayer::FeeTransfer {
+            fee_types.push((TxFeeTypes::Transfer, self.wallet.address()));
+        }
+        let (tx_types, addresses) = fee_types.into_iter().unzip();
+
+        let batch_fee = self
+            .wallet
+            .provider
+            .get_txs_batch_fee_in_wei(tx_types, addresses, fee_token.id)
+            .await?;
+        Ok(batch_fee.total_fee)
+    }
+}
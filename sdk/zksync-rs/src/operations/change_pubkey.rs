@@ -3,6 +3,7 @@ use zksync_eth_signer::EthereumSigner;
 use zksync_types::{
     helpers::{closest_packable_fee_amount, is_fee_amount_packable},
     tokens::TxFeeTypes,
+    tx::ChangePubKeyCREATE2Data,
     Nonce, Token, TokenLike, ZkSyncTx,
 };
 
@@ -14,6 +15,7 @@ use crate::{
 pub struct ChangePubKeyBuilder<'a, S: EthereumSigner, P: Provider> {
     wallet: &'a Wallet<S, P>,
     onchain_auth: bool,
+    create2_data: Option<ChangePubKeyCREATE2Data>,
     fee_token: Option<Token>,
     fee: Option<BigUint>,
     nonce: Option<Nonce>,
@@ -29,6 +31,7 @@ where
         Self {
             wallet,
             onchain_auth: false,
+            create2_data: None,
             fee_token: None,
             fee: None,
             nonce: None,
@@ -49,7 +52,7 @@ where
                     .provider
                     .get_tx_fee(
                         TxFeeTypes::ChangePubKey {
-                            onchain_pubkey_auth: self.onchain_auth,
+                            onchain_pubkey_auth: self.onchain_auth || self.create2_data.is_some(),
                         },
                         self.wallet.address(),
                         fee_token.id,
@@ -71,13 +74,22 @@ where
             }
         };
 
-        Ok(ZkSyncTx::from(
-            self.wallet
-                .signer
-                .sign_change_pubkey_tx(nonce, self.onchain_auth, fee_token, fee)
-                .await
-                .map_err(ClientError::SigningError)?,
-        ))
+        let signer = &self.wallet.signer;
+        let change_pubkey = match self.create2_data {
+            Some(create2_data) => {
+                signer
+                    .sign_change_pubkey_create2_tx(nonce, create2_data, fee_token, fee)
+                    .await
+            }
+            None => {
+                signer
+                    .sign_change_pubkey_tx(nonce, self.onchain_auth, fee_token, fee)
+                    .await
+            }
+        }
+        .map_err(ClientError::SigningError)?;
+
+        Ok(ZkSyncTx::from(change_pubkey))
     }
 
     /// Sends the transaction, returning the handle for its awaiting.
@@ -90,6 +102,14 @@ where
         Ok(SyncTransactionHandle::new(tx_hash, provider))
     }
 
+    /// Authorizes the key change with the CREATE2 data of the account instead of
+    /// the Ethereum signature. Only applicable to the accounts deployed via CREATE2,
+    /// which address is derived from the CREATE2 data and the new public key hash.
+    pub fn create2_data(mut self, create2_data: ChangePubKeyCREATE2Data) -> Self {
+        self.create2_data = Some(create2_data);
+        self
+    }
+
     /// Sets the transaction fee token. Returns an error if token is not supported by zkSync.
     pub fn fee_token(mut self, token: impl Into<TokenLike>) -> Result<Self, ClientError> {
         let token_like = token.into();
@@ -0,0 +1,160 @@
+use num::BigUint;
+use zksync_eth_signer::EthereumSigner;
+use zksync_types::{
+    helpers::{closest_packable_fee_amount, is_fee_amount_packable},
+    tx::TxEthSignature,
+    Address, Nonce, Token, TokenLike, TxFeeTypes, ZkSyncTx,
+};
+
+use crate::{
+    error::ClientError, operations::SyncTransactionHandle, provider::Provider, wallet::Wallet,
+};
+
+/// Builder of the `ForcedExit` transaction, which withdraws all the funds of the target
+/// account in the certain token to the target address in L1.
+///
+/// The fee is paid by the wallet initiating the transaction in the withdrawn token.
+#[derive(Debug)]
+pub struct ForcedExitBuilder<'a, S: EthereumSigner, P: Provider> {
+    wallet: &'a Wallet<S, P>,
+    target: Option<Address>,
+    token: Option<Token>,
+    fee: Option<BigUint>,
+    nonce: Option<Nonce>,
+}
+
+impl<'a, S, P> ForcedExitBuilder<'a, S, P>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    /// Initializes a forced exit transaction building process.
+    pub fn new(wallet: &'a Wallet<S, P>) -> Self {
+        Self {
+            wallet,
+            target: None,
+            token: None,
+            fee: None,
+            nonce: None,
+        }
+    }
+
+    /// Directly returns the signed forced exit transaction for the subsequent usage.
+    /// Forced exit doesn't require an Ethereum signature, so it's always `None`.
+    pub async fn tx(self) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError> {
+        let target = self
+            .target
+            .ok_or_else(|| ClientError::MissingRequiredField("target".into()))?;
+        let token = self
+            .token
+            .ok_or_else(|| ClientError::MissingRequiredField("token".into()))?;
+
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => {
+                let account_info = self
+                    .wallet
+                    .provider
+                    .account_info(self.wallet.address())
+                    .await?;
+                account_info.committed.nonce
+            }
+        };
+
+        let fee = match self.fee {
+            Some(fee) => fee,
+            None => {
+                let fee = self
+                    .wallet
+                    .provider
+                    .get_tx_fee(TxFeeTypes::Withdraw, target, token.id)
+                    .await?;
+                fee.total_fee
+            }
+        };
+
+        self.wallet
+            .signer
+            .sign_forced_exit(target, token, fee, nonce)
+            .await
+            .map(|tx| (ZkSyncTx::ForcedExit(Box::new(tx)), None))
+            .map_err(ClientError::SigningError)
+    }
+
+    /// Sends the transaction, returning the handle for its awaiting.
+    pub async fn send(self) -> Result<SyncTransactionHandle<P>, ClientError> {
+        let provider = self.wallet.provider.clone();
+
+        let (tx, eth_signature) = self.tx().await?;
+        let tx_hash = provider.send_tx(tx, eth_signature).await?;
+
+        Ok(SyncTransactionHandle::new(tx_hash, provider))
+    }
+
+    /// Sets the address of the account to withdraw funds from.
+    /// Funds are withdrawn to the same address in L1.
+    pub fn target(mut self, target: Address) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Same as `ForcedExitBuilder::target`, but accepts a string address value.
+    ///
+    /// Provided string value must be a correct address in a hexadecimal form,
+    /// otherwise an error will be returned.
+    pub fn str_target(mut self, target: impl AsRef<str>) -> Result<Self, ClientError> {
+        let target: Address = target
+            .as_ref()
+            .parse()
+            .map_err(|_| ClientError::IncorrectAddress)?;
+
+        self.target = Some(target);
+        Ok(self)
+    }
+
+    /// Sets the token to withdraw, which is also the token to pay the fee in.
+    /// Returns an error if token is not supported by zkSync.
+    pub fn token(mut self, token: impl Into<TokenLike>) -> Result<Self, ClientError> {
+        let token_like = token.into();
+        let token = self
+            .wallet
+            .tokens
+            .resolve(token_like)
+            .ok_or(ClientError::UnknownToken)?;
+
+        self.token = Some(token);
+
+        Ok(self)
+    }
+
+    /// Set the fee amount. If the amount provided is not packable,
+    /// rounds it to the closest packable fee amount.
+    ///
+    /// For more details, see [utils](../utils/index.html) functions.
+    pub fn fee(mut self, fee: impl Into<BigUint>) -> Self {
+        let fee = closest_packable_fee_amount(&fee.into());
+        self.fee = Some(fee);
+
+        self
+    }
+
+    /// Set the fee amount. If the provided fee is not packable,
+    /// returns an error.
+    ///
+    /// For more details, see [utils](../utils/index.html) functions.
+    pub fn fee_exact(mut self, fee: impl Into<BigUint>) -> Result<Self, ClientError> {
+        let fee = fee.into();
+        if !is_fee_amount_packable(&fee) {
+            return Err(ClientError::NotPackableValue);
+        }
+        self.fee = Some(fee);
+
+        Ok(self)
+    }
+
+    /// Sets the transaction nonce.
+    pub fn nonce(mut self, nonce: Nonce) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+}
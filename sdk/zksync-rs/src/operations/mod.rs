@@ -2,18 +2,72 @@
 
 use std::time::{Duration, Instant};
 
-use zksync_types::tx::TxHash;
+use num::BigUint;
+use zksync_eth_signer::EthereumSigner;
+use zksync_types::{
+    tx::{TxEthSignature, TxHash},
+    Address, Nonce, Token, TxFeeTypes, ZkSyncTx,
+};
 
-use crate::{error::ClientError, provider::Provider, types::TransactionInfo};
+use crate::{error::ClientError, provider::Provider, types::TransactionInfo, wallet::Wallet};
 
 pub use self::{
-    change_pubkey::ChangePubKeyBuilder, transfer::TransferBuilder, withdraw::WithdrawBuilder,
+    batch::BatchBuilder, change_pubkey::ChangePubKeyBuilder, forced_exit::ForcedExitBuilder,
+    transfer::TransferBuilder, withdraw::WithdrawBuilder,
 };
 
+mod batch;
 mod change_pubkey;
+mod forced_exit;
 mod transfer;
 mod withdraw;
 
+/// Obtains the fee for the batch of the transaction with the given fee parameters
+/// and the transfer paying the fee in `fee_token`.
+async fn get_fee_in_another_token<S, P>(
+    wallet: &Wallet<S, P>,
+    tx_type: TxFeeTypes,
+    address: Address,
+    fee_token: &Token,
+) -> Result<BigUint, ClientError>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    let batch_fee = wallet
+        .provider
+        .get_txs_batch_fee_in_wei(
+            vec![tx_type, TxFeeTypes::Transfer],
+            vec![address, wallet.address()],
+            fee_token.id,
+        )
+        .await?;
+    Ok(batch_fee.total_fee)
+}
+
+/// Signs the transfer paying the fee for the batch in `fee_token`.
+///
+/// Transfer moves zero amount of the fee token from the wallet to itself, so only the fee
+/// is charged, and it goes to the fee account as for any other transaction. Since the batch
+/// is executed atomically, the fee of this transfer covers all the transactions in the batch.
+async fn sign_fee_transfer<S, P>(
+    wallet: &Wallet<S, P>,
+    fee_token: Token,
+    fee: BigUint,
+    nonce: Nonce,
+) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    wallet
+        .signer
+        .sign_transfer(fee_token, BigUint::from(0u32), fee, wallet.address(), nonce)
+        .await
+        .map(|(tx, signature)| (ZkSyncTx::Transfer(Box::new(tx)), signature))
+        .map_err(ClientError::SigningError)
+}
+
 /// Handle for transaction, providing an interface to control its execution.
 /// For obtained handle it's possible to set the polling interval, commit timeout
 /// and verify timeout values.
@@ -71,6 +125,28 @@ impl<P: Provider> SyncTransactionHandle<P> {
         self
     }
 
+    /// Awaits for the transaction execution and returns the information about execution.
+    /// The transaction is executed before its block is committed, so the commit timeout is used.
+    pub async fn wait_for_execution(&self) -> Result<TransactionInfo, ClientError> {
+        let mut timer = tokio::time::interval(self.polling_interval);
+        let start = Instant::now();
+
+        loop {
+            timer.tick().await;
+
+            if let Some(commit_timeout) = self.commit_timeout {
+                if start.elapsed() >= commit_timeout {
+                    return Err(ClientError::OperationTimeout);
+                }
+            }
+
+            let response = self.provider.tx_info(self.hash).await?;
+            if response.executed {
+                return Ok(response);
+            }
+        }
+    }
+
     /// Awaits for the transaction commit and returns the information about execution.
     pub async fn wait_for_commit(&self) -> Result<TransactionInfo, ClientError> {
         let mut timer = tokio::time::interval(self.polling_interval);
@@ -5,12 +5,15 @@ use zksync_types::{
         closest_packable_fee_amount, closest_packable_token_amount, is_fee_amount_packable,
         is_token_amount_packable,
     },
-    tx::PackedEthSignature,
+    tx::TxEthSignature,
     Address, Nonce, Token, TokenLike, TxFeeTypes, ZkSyncTx,
 };
 
 use crate::{
-    error::ClientError, operations::SyncTransactionHandle, provider::Provider, wallet::Wallet,
+    error::ClientError,
+    operations::{get_fee_in_another_token, sign_fee_transfer, SyncTransactionHandle},
+    provider::Provider,
+    wallet::Wallet,
 };
 
 #[derive(Debug)]
@@ -19,6 +22,7 @@ pub struct WithdrawBuilder<'a, S: EthereumSigner, P: Provider> {
     token: Option<Token>,
     amount: Option<BigUint>,
     fee: Option<BigUint>,
+    fee_token: Option<Token>,
     to: Option<Address>,
     nonce: Option<Nonce>,
 }
@@ -35,13 +39,20 @@ where
             token: None,
             amount: None,
             fee: None,
+            fee_token: None,
             to: None,
             nonce: None,
         }
     }
 
     /// Directly returns the signed withdraw transaction for the subsequent usage.
-    pub async fn tx(self) -> Result<(ZkSyncTx, Option<PackedEthSignature>), ClientError> {
+    ///
+    /// Returns an error if the fee is paid in another token, use `txs` in this case.
+    pub async fn tx(self) -> Result<(ZkSyncTx, Option<TxEthSignature>), ClientError> {
+        if self.is_fee_in_another_token() {
+            return Err(ClientError::FeeInAnotherToken);
+        }
+
         let token = self
             .token
             .ok_or_else(|| ClientError::MissingRequiredField("token".into()))?;
@@ -84,12 +95,74 @@ where
             .map_err(ClientError::SigningError)
     }
 
+    /// Returns the signed transactions to be sent in a batch.
+    ///
+    /// If the fee is paid in the withdraw token, the withdraw is the only transaction.
+    /// Otherwise, the withdraw doesn't pay fee and is followed by the transfer paying
+    /// the fee for both transactions in the fee token.
+    pub async fn txs(self) -> Result<Vec<(ZkSyncTx, Option<TxEthSignature>)>, ClientError> {
+        if !self.is_fee_in_another_token() {
+            return Ok(vec![self.tx().await?]);
+        }
+
+        let token = self
+            .token
+            .ok_or_else(|| ClientError::MissingRequiredField("token".into()))?;
+        let fee_token = self
+            .fee_token
+            .ok_or_else(|| ClientError::MissingRequiredField("fee_token".into()))?;
+        let amount = self
+            .amount
+            .ok_or_else(|| ClientError::MissingRequiredField("amount".into()))?;
+        let to = self
+            .to
+            .ok_or_else(|| ClientError::MissingRequiredField("to".into()))?;
+
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => {
+                let account_info = self
+                    .wallet
+                    .provider
+                    .account_info(self.wallet.address())
+                    .await?;
+                account_info.committed.nonce
+            }
+        };
+
+        let fee = match self.fee {
+            Some(fee) => fee,
+            None => {
+                get_fee_in_another_token(self.wallet, TxFeeTypes::Withdraw, to, &fee_token).await?
+            }
+        };
+
+        let withdraw = self
+            .wallet
+            .signer
+            .sign_withdraw(token, amount, BigUint::from(0u32), to, nonce)
+            .await
+            .map(|(tx, signature)| (ZkSyncTx::Withdraw(Box::new(tx)), signature))
+            .map_err(ClientError::SigningError)?;
+        let fee_transfer = sign_fee_transfer(self.wallet, fee_token, fee, nonce + 1).await?;
+
+        Ok(vec![withdraw, fee_transfer])
+    }
+
     /// Sends the transaction, returning the handle for its awaiting.
+    ///
+    /// If the fee is paid in another token, the transactions are sent in a batch,
+    /// and the handle for the withdraw is returned.
     pub async fn send(self) -> Result<SyncTransactionHandle<P>, ClientError> {
         let provider = self.wallet.provider.clone();
 
-        let (tx, eth_signature) = self.tx().await?;
-        let tx_hash = provider.send_tx(tx, eth_signature).await?;
+        let tx_hash = if self.is_fee_in_another_token() {
+            let tx_hashes = provider.send_txs_batch(self.txs().await?, vec![]).await?;
+            tx_hashes[0]
+        } else {
+            let (tx, eth_signature) = self.tx().await?;
+            provider.send_tx(tx, eth_signature).await?
+        };
 
         Ok(SyncTransactionHandle::new(tx_hash, provider))
     }
@@ -108,6 +181,23 @@ where
         Ok(self)
     }
 
+    /// Sets the token to pay the fee in. If it's not the withdraw token, the fee is paid
+    /// by an additional transfer sent in a batch with the withdraw.
+    /// In this case, the fee set via `fee` is the fee for the whole batch in the fee token.
+    /// Returns an error if token is not supported by zkSync.
+    pub fn fee_token(mut self, token: impl Into<TokenLike>) -> Result<Self, ClientError> {
+        let token_like = token.into();
+        let token = self
+            .wallet
+            .tokens
+            .resolve(token_like)
+            .ok_or(ClientError::UnknownToken)?;
+
+        self.fee_token = Some(token);
+
+        Ok(self)
+    }
+
     /// Set the transfer amount. If the amount provided is not packable,
     /// rounds it to the closest packable amount.
     ///
@@ -183,4 +273,11 @@ where
         self.nonce = Some(nonce);
         self
     }
+
+    fn is_fee_in_another_token(&self) -> bool {
+        match (&self.token, &self.fee_token) {
+            (Some(token), Some(fee_token)) => token.id != fee_token.id,
+            _ => false,
+        }
+    }
 }
@@ -11,14 +11,22 @@ use jsonrpc_core::{types::response::Output, ErrorCode};
 // Workspace uses
 use zksync_types::{
     network::Network,
-    tx::{PackedEthSignature, TxHash, ZkSyncTx},
+    tx::{TxEthSignature, TxHash, ZkSyncTx},
     Address, TokenLike, TxFeeTypes,
 };
 
 // Local uses
+#[cfg(feature = "mock")]
+pub use self::mock::MockProvider;
+pub use self::rest::{get_rest_addr, RestProvider};
+
 use self::messages::JsonRpcRequest;
 use crate::{error::ClientError, types::*};
 
+#[cfg(feature = "mock")]
+mod mock;
+mod rest;
+
 /// Returns a corresponding address for a provided network name.
 pub fn get_rpc_addr(network: Network) -> &'static str {
     match network {
@@ -44,6 +52,9 @@ pub trait Provider {
     /// Requests and returns information about transaction execution status.
     async fn tx_info(&self, tx_hash: TxHash) -> Result<TransactionInfo, ClientError>;
 
+    /// Requests and returns information about an Ethereum operation given its `serial_id`.
+    async fn ethop_info(&self, serial_id: u32) -> Result<EthOpInfo, ClientError>;
+
     /// Obtains minimum fee required to process transaction in zkSync network.
     async fn get_tx_fee(
         &self,
@@ -52,14 +63,31 @@ pub trait Provider {
         token: impl Into<TokenLike> + Send + 'async_trait,
     ) -> Result<Fee, ClientError>;
 
+    /// Obtains minimum fee required to process all the transactions of the batch in zkSync
+    /// network, if the fee is paid in the single token.
+    async fn get_txs_batch_fee_in_wei(
+        &self,
+        tx_types: Vec<TxFeeTypes>,
+        addresses: Vec<Address>,
+        token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<BatchFee, ClientError>;
+
     /// Submits a transaction to the zkSync network.
     /// Returns the hash of the created transaction.
     async fn send_tx(
         &self,
         tx: ZkSyncTx,
-        eth_signature: Option<PackedEthSignature>,
+        eth_signature: Option<TxEthSignature>,
     ) -> Result<TxHash, ClientError>;
 
+    /// Submits a batch transaction to the zkSync network.
+    /// Returns the hashes of the created transactions.
+    async fn send_txs_batch(
+        &self,
+        txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+        eth_signatures: Vec<TxEthSignature>,
+    ) -> Result<Vec<TxHash>, ClientError>;
+
     /// Type of network this provider is allowing access to.
     fn network(&self) -> Network;
 
@@ -93,6 +121,11 @@ impl Provider for RpcProvider {
         self.send_and_deserialize(&msg).await
     }
 
+    async fn ethop_info(&self, serial_id: u32) -> Result<EthOpInfo, ClientError> {
+        let msg = JsonRpcRequest::ethop_info(serial_id);
+        self.send_and_deserialize(&msg).await
+    }
+
     async fn get_tx_fee(
         &self,
         tx_type: TxFeeTypes,
@@ -104,15 +137,35 @@ impl Provider for RpcProvider {
         self.send_and_deserialize(&msg).await
     }
 
+    async fn get_txs_batch_fee_in_wei(
+        &self,
+        tx_types: Vec<TxFeeTypes>,
+        addresses: Vec<Address>,
+        token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<BatchFee, ClientError> {
+        let token = token.into();
+        let msg = JsonRpcRequest::get_txs_batch_fee_in_wei(tx_types, addresses, token);
+        self.send_and_deserialize(&msg).await
+    }
+
     async fn send_tx(
         &self,
         tx: ZkSyncTx,
-        eth_signature: Option<PackedEthSignature>,
+        eth_signature: Option<TxEthSignature>,
     ) -> Result<TxHash, ClientError> {
         let msg = JsonRpcRequest::submit_tx(tx, eth_signature);
         self.send_and_deserialize(&msg).await
     }
 
+    async fn send_txs_batch(
+        &self,
+        txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+        eth_signatures: Vec<TxEthSignature>,
+    ) -> Result<Vec<TxHash>, ClientError> {
+        let msg = JsonRpcRequest::submit_tx_batch(txs_signed, eth_signatures);
+        self.send_and_deserialize(&msg).await
+    }
+
     fn network(&self) -> Network {
         self.network
     }
@@ -142,23 +195,6 @@ impl RpcProvider {
         }
     }
 
-    /// Submits a batch transaction to the zkSync network.
-    /// Returns the hashes of the created transactions.
-    pub async fn send_txs_batch(
-        &self,
-        txs_signed: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
-        eth_signatures: Vec<PackedEthSignature>,
-    ) -> Result<Vec<TxHash>, ClientError> {
-        let msg = JsonRpcRequest::submit_tx_batch(txs_signed, eth_signatures);
-        self.send_and_deserialize(&msg).await
-    }
-
-    /// Requests and returns information about an Ethereum operation given its `serial_id`.
-    pub async fn ethop_info(&self, serial_id: u32) -> Result<EthOpInfo, ClientError> {
-        let msg = JsonRpcRequest::ethop_info(serial_id);
-        self.send_and_deserialize(&msg).await
-    }
-
     /// Requests and returns eth withdrawal transaction hash for some offchain withdrawal.
     pub async fn get_eth_tx_for_withdrawal(
         &self,
@@ -250,7 +286,7 @@ impl RpcProvider {
 mod messages {
     use serde::Serialize;
     use zksync_types::{
-        tx::{PackedEthSignature, TxEthSignature, TxHash, ZkSyncTx},
+        tx::{TxEthSignature, TxHash, ZkSyncTx},
         Address, TokenLike, TxFeeTypes,
     };
 
@@ -278,35 +314,28 @@ mod messages {
             Self::create("account_info", params)
         }
 
-        pub fn submit_tx(tx: ZkSyncTx, eth_signature: Option<PackedEthSignature>) -> Self {
+        pub fn submit_tx(tx: ZkSyncTx, eth_signature: Option<TxEthSignature>) -> Self {
             let mut params = Vec::with_capacity(2);
             params.push(serde_json::to_value(tx).expect("serialization fail"));
-            params.push(
-                serde_json::to_value(eth_signature.map(TxEthSignature::EthereumSignature))
-                    .expect("serialization fail"),
-            );
+            params.push(serde_json::to_value(eth_signature).expect("serialization fail"));
             Self::create("tx_submit", params)
         }
 
         pub fn submit_tx_batch(
-            txs_signed: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
-            eth_signatures: Vec<PackedEthSignature>,
+            txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+            eth_signatures: Vec<TxEthSignature>,
         ) -> Self {
             let mut params = Vec::with_capacity(2);
 
             let txs_signed = txs_signed.into_iter().map(|(tx, eth_signature)| {
                 serde_json::json!({
                     "tx": serde_json::to_value(tx).expect("serialization fail"),
-                    "signature": serde_json::to_value(eth_signature.map(TxEthSignature::EthereumSignature))
-                        .expect("serialization fail"),
+                    "signature": serde_json::to_value(eth_signature).expect("serialization fail"),
                 })
             }).collect();
             let eth_signatures = eth_signatures
                 .into_iter()
-                .map(|signature| {
-                    serde_json::to_value(TxEthSignature::EthereumSignature(signature))
-                        .expect("serialization fail")
-                })
+                .map(|signature| serde_json::to_value(signature).expect("serialization fail"))
                 .collect();
             params.push(serde_json::Value::Array(txs_signed));
             params.push(serde_json::Value::Array(eth_signatures));
@@ -349,5 +378,17 @@ mod messages {
             params.push(serde_json::to_value(token_symbol).expect("serialization fail"));
             Self::create("get_tx_fee", params)
         }
+
+        pub fn get_txs_batch_fee_in_wei(
+            tx_types: Vec<TxFeeTypes>,
+            addresses: Vec<Address>,
+            token_symbol: TokenLike,
+        ) -> Self {
+            let mut params = Vec::with_capacity(3);
+            params.push(serde_json::to_value(tx_types).expect("serialization fail"));
+            params.push(serde_json::to_value(addresses).expect("serialization fail"));
+            params.push(serde_json::to_value(token_symbol).expect("serialization fail"));
+            Self::create("get_txs_batch_fee_in_wei", params)
+        }
     }
 }
@@ -0,0 +1,677 @@
+//! In-memory `Provider` implementation for testing the code built on top of the SDK
+//! without a running zkSync server. Available with the `mock` feature.
+
+// Built-in imports
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+// External uses
+use async_trait::async_trait;
+use jsonrpc_core::{types::response::Failure, ErrorCode, Id, Version};
+use num::BigUint;
+
+// Workspace uses
+use zksync_state::state::ZkSyncState;
+use zksync_types::{
+    network::Network,
+    tx::{
+        BatchSignData, ChangePubKeyECDSAData, ChangePubKeyEthAuthData, PackedEthSignature,
+        TxEthSignature, TxHash, ZkSyncTx,
+    },
+    Account, AccountId, Address, BlockNumber, Deposit, Nonce, PubKeyHash, Token, TokenId,
+    TokenLike, TxFeeTypes, ZkSyncPriorityOp,
+};
+
+// Local uses
+use super::Provider;
+use crate::{error::ClientError, tokens_cache::TokensCache, types::*};
+
+/// Reasons to reject the submitted transaction.
+/// Error codes and messages are the same as the ones returned by the JSON RPC API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RejectReason {
+    NonceMismatch,
+    IncorrectTx,
+    TxFeeTooLow,
+    TxBatchFeeTooLow,
+    MissingEthSignature,
+    IncorrectEthSignature,
+    ChangePkNotAuthorized,
+    EmptyBatch,
+}
+
+impl RejectReason {
+    fn code(self) -> i64 {
+        match self {
+            Self::NonceMismatch => 101,
+            Self::IncorrectTx => 103,
+            Self::TxFeeTooLow | Self::TxBatchFeeTooLow => 104,
+            Self::MissingEthSignature => 200,
+            Self::IncorrectEthSignature => 202,
+            Self::ChangePkNotAuthorized => 203,
+            Self::EmptyBatch => 300,
+        }
+    }
+
+    fn message(self) -> &'static str {
+        match self {
+            Self::NonceMismatch => "Tx nonce is too low.",
+            Self::IncorrectTx => "Tx is incorrect",
+            Self::TxFeeTooLow => "Transaction fee is too low",
+            Self::TxBatchFeeTooLow => "Transactions batch summary fee is too low",
+            Self::MissingEthSignature => "MissingEthSignature",
+            Self::IncorrectEthSignature => "Eth signature is incorrect",
+            Self::ChangePkNotAuthorized => "Change pubkey tx is not authorized onchain",
+            Self::EmptyBatch => "Transaction batch is empty",
+        }
+    }
+}
+
+impl From<RejectReason> for ClientError {
+    fn from(reason: RejectReason) -> Self {
+        ClientError::RpcError(Failure {
+            jsonrpc: Some(Version::V2),
+            error: jsonrpc_core::Error {
+                code: ErrorCode::ServerError(reason.code()),
+                message: reason.message().to_owned(),
+                data: None,
+            },
+            id: Id::Num(1),
+        })
+    }
+}
+
+/// Result of the operation execution.
+#[derive(Debug, Clone)]
+struct ExecutedOp {
+    block: BlockNumber,
+    success: bool,
+    fail_reason: Option<String>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    /// State including all the executed operations.
+    state: ZkSyncState,
+    /// States at the moment of the committed, but not verified yet blocks.
+    committed_states: BTreeMap<BlockNumber, ZkSyncState>,
+    /// State at the moment of the last verified block.
+    verified_state: ZkSyncState,
+    last_committed_block: BlockNumber,
+    last_verified_block: BlockNumber,
+
+    tokens: Tokens,
+    fees: HashMap<TxFeeTypes, BigUint>,
+    /// Public key hashes authorized onchain, with the account nonce they are authorized for.
+    authorized_pubkeys: HashSet<(Address, Nonce, PubKeyHash)>,
+    /// Owners of the smart contract wallets, which sign the EIP1271 signatures.
+    eip1271_owners: HashMap<Address, Address>,
+
+    txs: HashMap<TxHash, ExecutedOp>,
+    priority_ops: HashMap<u64, ExecutedOp>,
+    next_serial_id: u64,
+}
+
+impl MockState {
+    fn new() -> Self {
+        let eth = Token::new(0, Address::zero(), "ETH", 18);
+        let mut tokens = Tokens::new();
+        tokens.insert(eth.symbol.clone(), eth);
+
+        Self {
+            state: ZkSyncState::empty(),
+            committed_states: BTreeMap::new(),
+            verified_state: ZkSyncState::empty(),
+            last_committed_block: 0,
+            last_verified_block: 0,
+            tokens,
+            fees: HashMap::new(),
+            authorized_pubkeys: HashSet::new(),
+            eip1271_owners: HashMap::new(),
+            txs: HashMap::new(),
+            priority_ops: HashMap::new(),
+            next_serial_id: 0,
+        }
+    }
+
+    fn resolve_token(&self, token: impl Into<TokenLike>) -> Option<Token> {
+        TokensCache::new(self.tokens.clone()).resolve(token.into())
+    }
+
+    fn required_fee(&self, tx_type: TxFeeTypes) -> BigUint {
+        self.fees
+            .get(&tx_type)
+            .cloned()
+            .unwrap_or_else(|| BigUint::from(MockProvider::DEFAULT_TX_FEE))
+    }
+
+    fn account_state(state: &ZkSyncState, tokens: &Tokens, address: Address) -> AccountState {
+        let account = match state.get_account_by_address(&address) {
+            Some((_, account)) => account,
+            None => return AccountState::default(),
+        };
+
+        let balances = tokens
+            .values()
+            .map(|token| (token.symbol.clone(), account.get_balance(token.id)))
+            .filter(|(_, balance)| *balance != BigUint::from(0u32))
+            .map(|(symbol, balance)| (symbol, balance.into()))
+            .collect();
+        AccountState {
+            balances,
+            nonce: account.nonce,
+            pub_key_hash: account.pub_key_hash,
+        }
+    }
+
+    fn block_info(&self, block: BlockNumber) -> BlockInfo {
+        BlockInfo {
+            block_number: i64::from(block),
+            committed: block <= self.last_committed_block,
+            verified: block <= self.last_verified_block,
+        }
+    }
+
+    /// Returns the address of the account initiating the transaction.
+    fn tx_sender(&self, tx: &ZkSyncTx) -> Option<Address> {
+        match tx {
+            ZkSyncTx::ForcedExit(tx) => self
+                .state
+                .get_account(tx.initiator_account_id)
+                .map(|account| account.address),
+            _ => Some(tx.account()),
+        }
+    }
+
+    /// Performs the same checks as the server does before accepting the transaction,
+    /// except for the Ethereum signature and fee checks. Returns the transaction sender.
+    ///
+    /// Since there is no mempool, the transaction nonce must be the next nonce of the sender.
+    /// `pending_nonces` holds the next nonces of the senders of the previous transactions
+    /// in the batch.
+    fn check_tx(
+        &self,
+        tx: &ZkSyncTx,
+        pending_nonces: &mut HashMap<Address, Nonce>,
+    ) -> Result<Address, RejectReason> {
+        // The correctness check caches the verified signature, so it's performed on a copy.
+        if tx.is_close() || !tx.clone().check_correctness() {
+            return Err(RejectReason::IncorrectTx);
+        }
+        let sender = self.tx_sender(tx).ok_or(RejectReason::IncorrectTx)?;
+
+        let expected_nonce = pending_nonces.get(&sender).copied().or_else(|| {
+            self.state
+                .get_account_by_address(&sender)
+                .map(|(_, account)| account.nonce)
+        });
+        if expected_nonce.map_or(false, |nonce| tx.nonce() != nonce) {
+            return Err(RejectReason::NonceMismatch);
+        }
+        if let ZkSyncTx::ChangePubKey(tx) = tx {
+            let authorization = (tx.account, tx.nonce, tx.new_pk_hash);
+            if tx.eth_auth_data.is_onchain() && !self.authorized_pubkeys.contains(&authorization) {
+                return Err(RejectReason::ChangePkNotAuthorized);
+            }
+        }
+
+        pending_nonces.insert(sender, tx.nonce() + 1);
+        Ok(sender)
+    }
+
+    /// Checks the Ethereum signature of the transaction, if it requires one.
+    /// Missing signature is only allowed if the transaction is authorized by the batch signature.
+    fn check_tx_eth_signature(
+        &self,
+        tx: &ZkSyncTx,
+        sender: Address,
+        eth_signature: Option<&TxEthSignature>,
+        signed_by_batch: bool,
+    ) -> Result<(), RejectReason> {
+        let message = match tx {
+            ZkSyncTx::Transfer(tx) => {
+                let token = self
+                    .resolve_token(tx.token)
+                    .ok_or(RejectReason::IncorrectTx)?;
+                tx.get_ethereum_sign_message(&token.symbol, token.decimals)
+            }
+            ZkSyncTx::Withdraw(tx) => {
+                let token = self
+                    .resolve_token(tx.token)
+                    .ok_or(RejectReason::IncorrectTx)?;
+                tx.get_ethereum_sign_message(&token.symbol, token.decimals)
+            }
+            // `ChangePubKey` carries the Ethereum signature in its auth data.
+            ZkSyncTx::ChangePubKey(tx) => {
+                return match &tx.eth_auth_data {
+                    ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData {
+                        eth_signature, ..
+                    }) => {
+                        let message = tx
+                            .get_eth_signed_data()
+                            .map_err(|_| RejectReason::IncorrectTx)?;
+                        let signer = eth_signature
+                            .signature_recover_signer(&message)
+                            .map_err(|_| RejectReason::IncorrectEthSignature)?;
+                        if tx.is_eth_auth_data_valid() && signer == sender {
+                            Ok(())
+                        } else {
+                            Err(RejectReason::IncorrectEthSignature)
+                        }
+                    }
+                    // Onchain authorization is checked by `check_tx`, and CREATE2 data
+                    // is checked by the correctness check.
+                    _ => Ok(()),
+                };
+            }
+            // `ForcedExit` doesn't require the Ethereum signature, same as on the server.
+            ZkSyncTx::ForcedExit(_) | ZkSyncTx::Close(_) => return Ok(()),
+        };
+
+        match eth_signature {
+            Some(eth_signature) => {
+                self.check_eth_signature(eth_signature, message.as_bytes(), sender)
+            }
+            None if signed_by_batch => Ok(()),
+            None => Err(RejectReason::MissingEthSignature),
+        }
+    }
+
+    /// Checks that the fee provided by the transactions covers the required one.
+    /// Since the mock has no token prices, fees in all tokens are considered to be equal.
+    fn is_fee_enough<'a>(&self, txs: impl Iterator<Item = &'a ZkSyncTx>) -> bool {
+        let (required_fee, provided_fee) = txs.filter_map(ZkSyncTx::get_fee_info).fold(
+            (BigUint::from(0u32), BigUint::from(0u32)),
+            |(required, provided), (tx_type, _, _, fee)| {
+                (required + self.required_fee(tx_type), provided + fee)
+            },
+        );
+        provided_fee >= required_fee
+    }
+
+    fn submit_tx(
+        &mut self,
+        tx: ZkSyncTx,
+        eth_signature: Option<TxEthSignature>,
+    ) -> Result<TxHash, RejectReason> {
+        let sender = self.check_tx(&tx, &mut HashMap::new())?;
+        self.check_tx_eth_signature(&tx, sender, eth_signature.as_ref(), false)?;
+        if !self.is_fee_enough(std::iter::once(&tx)) {
+            return Err(RejectReason::TxFeeTooLow);
+        }
+
+        let tx_hash = tx.hash();
+        let result = self.state.execute_tx(tx).map(drop);
+        self.store_tx_result(tx_hash, result.map_err(|err| err.to_string()));
+        Ok(tx_hash)
+    }
+
+    fn submit_txs_batch(
+        &mut self,
+        txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+        eth_signatures: Vec<TxEthSignature>,
+    ) -> Result<Vec<TxHash>, RejectReason> {
+        if txs_signed.is_empty() {
+            return Err(RejectReason::EmptyBatch);
+        }
+
+        let mut txs = Vec::with_capacity(txs_signed.len());
+        let mut senders = Vec::with_capacity(txs_signed.len());
+        let mut pending_nonces = HashMap::new();
+        for (tx, eth_signature) in txs_signed {
+            let sender = self.check_tx(&tx, &mut pending_nonces)?;
+            self.check_tx_eth_signature(
+                &tx,
+                sender,
+                eth_signature.as_ref(),
+                !eth_signatures.is_empty(),
+            )?;
+            txs.push(tx);
+            senders.push(sender);
+        }
+
+        if !eth_signatures.is_empty() {
+            let batch_sign_data =
+                BatchSignData::new(&txs, eth_signatures).map_err(|_| RejectReason::IncorrectTx)?;
+            // Every sender must have signed the batch.
+            for sender in &senders {
+                let is_signed = batch_sign_data.signatures.iter().any(|signature| {
+                    self.check_eth_signature(signature, &batch_sign_data.message, *sender)
+                        .is_ok()
+                });
+                if !is_signed {
+                    return Err(RejectReason::IncorrectEthSignature);
+                }
+            }
+        }
+        if !self.is_fee_enough(txs.iter()) {
+            return Err(RejectReason::TxBatchFeeTooLow);
+        }
+
+        let tx_hashes: Vec<_> = txs.iter().map(ZkSyncTx::hash).collect();
+        let signed_txs: Vec<_> = txs.into_iter().map(Into::into).collect();
+        let results = self.state.execute_txs_batch(&signed_txs);
+        for (tx_hash, result) in tx_hashes.iter().zip(results) {
+            self.store_tx_result(*tx_hash, result.map(drop).map_err(|err| err.to_string()));
+        }
+        Ok(tx_hashes)
+    }
+
+    /// Stores the result of the transaction execution in the pending block.
+    fn store_tx_result(&mut self, tx_hash: TxHash, result: Result<(), String>) {
+        let executed_op = ExecutedOp {
+            block: self.last_committed_block + 1,
+            success: result.is_ok(),
+            fail_reason: result.err(),
+        };
+        self.txs.insert(tx_hash, executed_op);
+    }
+
+    /// Checks that the message is signed by the expected Ethereum account.
+    ///
+    /// EIP1271 signatures are only accepted for the registered smart contract wallets,
+    /// and are checked as if the wallet contract verifies the signature of its owner.
+    fn check_eth_signature(
+        &self,
+        eth_signature: &TxEthSignature,
+        message: &[u8],
+        expected_signer: Address,
+    ) -> Result<(), RejectReason> {
+        let (eth_signature, expected_signer) = match eth_signature {
+            TxEthSignature::EthereumSignature(signature) => (signature.clone(), expected_signer),
+            TxEthSignature::EIP1271Signature(signature) => {
+                let owner = self
+                    .eip1271_owners
+                    .get(&expected_signer)
+                    .ok_or(RejectReason::IncorrectEthSignature)?;
+                let signature = PackedEthSignature::deserialize_packed(&signature.0)
+                    .map_err(|_| RejectReason::IncorrectEthSignature)?;
+                (signature, *owner)
+            }
+        };
+
+        let signer = eth_signature
+            .signature_recover_signer(message)
+            .map_err(|_| RejectReason::IncorrectEthSignature)?;
+        if signer == expected_signer {
+            Ok(())
+        } else {
+            Err(RejectReason::IncorrectEthSignature)
+        }
+    }
+}
+
+/// `MockProvider` executes the submitted transactions against the in-memory zkSync state,
+/// so the code built on top of the SDK can be tested without a running server.
+///
+/// Transactions are checked the same way the server does: zkSync and Ethereum signatures
+/// must be correct, and the fee must cover the one returned by `Provider::get_tx_fee`.
+/// Since there are no token prices, fees in all tokens are considered to be equal.
+/// There is no mempool either, so the transactions must have the next nonce of the account.
+///
+/// Accepted transactions are executed immediately and included into the pending block.
+/// Blocks are committed and verified only on the explicit `MockProvider::commit_block`
+/// and `MockProvider::verify_block` calls. Clones of the provider share the same state.
+#[derive(Debug, Clone)]
+pub struct MockProvider {
+    inner: Arc<Mutex<MockState>>,
+    network: Network,
+}
+
+impl MockProvider {
+    /// Fee required by default for every transaction, in units of the fee token.
+    pub const DEFAULT_TX_FEE: u64 = 1000;
+
+    /// Creates a new `MockProvider` with the empty state. Only the "ETH" token is supported
+    /// by default, other tokens can be added via `MockProvider::add_token`.
+    pub fn new(network: Network) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MockState::new())),
+            network,
+        }
+    }
+
+    /// Adds a new token with the given symbol, returning the added token.
+    pub fn add_token(&self, symbol: &str, decimals: u8) -> Token {
+        let mut inner = self.inner();
+        let id = inner.tokens.len() as TokenId;
+        let token = Token::new(id, Address::from_low_u64_be(id.into()), symbol, decimals);
+
+        inner.tokens.insert(token.symbol.clone(), token.clone());
+        token
+    }
+
+    /// Sets the fee required for the transactions of the given type.
+    /// By default, `MockProvider::DEFAULT_TX_FEE` is required for every transaction.
+    pub fn set_tx_fee(&self, tx_type: TxFeeTypes, fee: impl Into<BigUint>) {
+        self.inner().fees.insert(tx_type, fee.into());
+    }
+
+    /// Creates an empty account with the given address, returning its ID.
+    /// If the account already exists, just returns its ID.
+    pub fn create_account(&self, address: Address) -> AccountId {
+        let mut inner = self.inner();
+        if let Some((account_id, _)) = inner.state.get_account_by_address(&address) {
+            return account_id;
+        }
+
+        let account_id = inner.state.get_accounts().len() as AccountId;
+        inner
+            .state
+            .insert_account(account_id, Account::default_with_address(&address));
+        account_id
+    }
+
+    /// Executes a deposit to the given address in the pending block, creating the account
+    /// if it doesn't exist yet. Returns the serial ID of the priority operation.
+    pub fn deposit(
+        &self,
+        to: Address,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+    ) -> Result<u64, ClientError> {
+        let mut inner = self.inner();
+        let token = inner
+            .resolve_token(token)
+            .ok_or(ClientError::UnknownToken)?;
+
+        let deposit = Deposit {
+            from: to,
+            token: token.id,
+            amount: amount.into(),
+            to,
+        };
+        inner
+            .state
+            .execute_priority_op(ZkSyncPriorityOp::Deposit(deposit));
+
+        let serial_id = inner.next_serial_id;
+        inner.next_serial_id += 1;
+        let executed_op = ExecutedOp {
+            block: inner.last_committed_block + 1,
+            success: true,
+            fail_reason: None,
+        };
+        inner.priority_ops.insert(serial_id, executed_op);
+        Ok(serial_id)
+    }
+
+    /// Authorizes the `ChangePubKey` transaction with the given account nonce and
+    /// public key hash, as it is done by the `setAuthPubkeyHash` call of the zkSync contract.
+    pub fn authorize_pubkey_hash(&self, address: Address, nonce: Nonce, pub_key_hash: PubKeyHash) {
+        self.inner()
+            .authorized_pubkeys
+            .insert((address, nonce, pub_key_hash));
+    }
+
+    /// Registers the smart contract wallet, which accepts the EIP1271 signatures
+    /// made by the owner account.
+    pub fn add_eip1271_wallet(&self, wallet: Address, owner: Address) {
+        self.inner().eip1271_owners.insert(wallet, owner);
+    }
+
+    /// Commits the pending block with all the operations executed since the previous commit.
+    /// Returns the number of the committed block.
+    pub fn commit_block(&self) -> BlockNumber {
+        let mut inner = self.inner();
+        inner.last_committed_block += 1;
+
+        let block = inner.last_committed_block;
+        let state = inner.state.clone();
+        inner.committed_states.insert(block, state);
+        block
+    }
+
+    /// Verifies the oldest committed block that is not verified yet.
+    /// Returns the number of the verified block, or `None` if there are no such blocks.
+    pub fn verify_block(&self) -> Option<BlockNumber> {
+        let mut inner = self.inner();
+        if inner.last_verified_block == inner.last_committed_block {
+            return None;
+        }
+        inner.last_verified_block += 1;
+
+        let block = inner.last_verified_block;
+        inner.verified_state = inner
+            .committed_states
+            .remove(&block)
+            .expect("State of the committed block is missing");
+        Some(block)
+    }
+
+    fn inner(&self) -> MutexGuard<'_, MockState> {
+        self.inner.lock().expect("MockProvider state is poisoned")
+    }
+}
+
+#[async_trait]
+impl Provider for MockProvider {
+    /// Returns the account info. Unlike the server, committed state of the account
+    /// includes the operations of the pending block.
+    async fn account_info(&self, address: Address) -> Result<AccountInfo, ClientError> {
+        let inner = self.inner();
+        let id = inner
+            .state
+            .get_account_by_address(&address)
+            .map(|(id, _)| id);
+
+        Ok(AccountInfo {
+            address,
+            id,
+            depositing: Default::default(),
+            committed: MockState::account_state(&inner.state, &inner.tokens, address),
+            verified: MockState::account_state(&inner.verified_state, &inner.tokens, address),
+        })
+    }
+
+    async fn tokens(&self) -> Result<Tokens, ClientError> {
+        Ok(self.inner().tokens.clone())
+    }
+
+    async fn tx_info(&self, tx_hash: TxHash) -> Result<TransactionInfo, ClientError> {
+        let inner = self.inner();
+        let info = match inner.txs.get(&tx_hash) {
+            Some(executed_op) => TransactionInfo {
+                executed: true,
+                success: Some(executed_op.success),
+                fail_reason: executed_op.fail_reason.clone(),
+                block: Some(inner.block_info(executed_op.block)),
+            },
+            None => TransactionInfo {
+                executed: false,
+                success: None,
+                fail_reason: None,
+                block: None,
+            },
+        };
+        Ok(info)
+    }
+
+    async fn ethop_info(&self, serial_id: u32) -> Result<EthOpInfo, ClientError> {
+        let inner = self.inner();
+        let info = match inner.priority_ops.get(&u64::from(serial_id)) {
+            Some(executed_op) => EthOpInfo {
+                executed: true,
+                block: Some(inner.block_info(executed_op.block)),
+            },
+            None => EthOpInfo {
+                executed: false,
+                block: None,
+            },
+        };
+        Ok(info)
+    }
+
+    async fn get_tx_fee(
+        &self,
+        tx_type: TxFeeTypes,
+        _address: Address,
+        _token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<Fee, ClientError> {
+        let fee_type = match tx_type {
+            TxFeeTypes::Transfer => OutputFeeType::Transfer,
+            TxFeeTypes::Withdraw => OutputFeeType::Withdraw,
+            TxFeeTypes::FastWithdraw => OutputFeeType::FastWithdraw,
+            TxFeeTypes::ChangePubKey {
+                onchain_pubkey_auth,
+            } => OutputFeeType::ChangePubKey {
+                onchain_pubkey_auth,
+            },
+        };
+        let total_fee = self.inner().required_fee(tx_type);
+
+        Ok(Fee {
+            fee_type,
+            gas_tx_amount: BigUint::from(0u32),
+            gas_price_wei: BigUint::from(0u32),
+            gas_fee: total_fee.clone(),
+            zkp_fee: BigUint::from(0u32),
+            total_fee,
+        })
+    }
+
+    async fn get_txs_batch_fee_in_wei(
+        &self,
+        tx_types: Vec<TxFeeTypes>,
+        _addresses: Vec<Address>,
+        _token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<BatchFee, ClientError> {
+        let inner = self.inner();
+        let total_fee = tx_types
+            .into_iter()
+            .map(|tx_type| inner.required_fee(tx_type))
+            .sum();
+
+        Ok(BatchFee { total_fee })
+    }
+
+    async fn send_tx(
+        &self,
+        tx: ZkSyncTx,
+        eth_signature: Option<TxEthSignature>,
+    ) -> Result<TxHash, ClientError> {
+        Ok(self.inner().submit_tx(tx, eth_signature)?)
+    }
+
+    async fn send_txs_batch(
+        &self,
+        txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+        eth_signatures: Vec<TxEthSignature>,
+    ) -> Result<Vec<TxHash>, ClientError> {
+        Ok(self.inner().submit_txs_batch(txs_signed, eth_signatures)?)
+    }
+
+    fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Returns zero addresses, since there are no contracts deployed for the mock.
+    async fn contract_address(&self) -> Result<ContractAddress, ClientError> {
+        Ok(ContractAddress {
+            main_contract: format!("{:?}", Address::zero()),
+            gov_contract: format!("{:?}", Address::zero()),
+        })
+    }
+}
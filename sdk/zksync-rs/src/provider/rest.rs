@@ -0,0 +1,353 @@
+//! `Provider` implementation over the zkSync REST API v1.
+
+// External uses
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+// Workspace uses
+use zksync_types::{
+    network::Network,
+    tx::{TxEthSignature, TxHash, ZkSyncTx},
+    Address, BlockNumber, Token, TokenLike, TxFeeTypes,
+};
+
+// Local uses
+use super::{Provider, RpcProvider};
+use crate::{
+    error::{ClientError, RestError},
+    types::*,
+};
+
+/// Returns a corresponding REST API address for a provided network name.
+pub fn get_rest_addr(network: Network) -> &'static str {
+    match network {
+        Network::Mainnet => "https://api.zksync.io",
+        Network::Rinkeby => "https://rinkeby-api.zksync.io",
+        Network::Ropsten => "https://ropsten-api.zksync.io",
+        Network::Localhost => "http://127.0.0.1:3001",
+        Network::Unknown => panic!("Attempt to create a provider from an unknown network"),
+    }
+}
+
+/// Transaction status returned by the REST API.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "status", rename_all = "camelCase")]
+enum TxReceipt {
+    Pending,
+    Executed,
+    Committed { block: BlockNumber },
+    Verified { block: BlockNumber },
+    Rejected { reason: Option<String> },
+}
+
+impl TxReceipt {
+    fn block(&self) -> Option<BlockInfo> {
+        match self {
+            Self::Committed { block } => Some(BlockInfo {
+                block_number: i64::from(*block),
+                committed: true,
+                verified: false,
+            }),
+            Self::Verified { block } => Some(BlockInfo {
+                block_number: i64::from(*block),
+                committed: true,
+                verified: true,
+            }),
+            _ => None,
+        }
+    }
+
+    fn is_executed(&self) -> bool {
+        !matches!(self, Self::Pending)
+    }
+}
+
+impl From<Option<TxReceipt>> for TransactionInfo {
+    fn from(receipt: Option<TxReceipt>) -> Self {
+        let receipt = match receipt {
+            Some(receipt) => receipt,
+            None => {
+                return Self {
+                    executed: false,
+                    success: None,
+                    fail_reason: None,
+                    block: None,
+                }
+            }
+        };
+
+        let (success, fail_reason) = match &receipt {
+            TxReceipt::Pending => (None, None),
+            TxReceipt::Rejected { reason } => (Some(false), reason.clone()),
+            _ => (Some(true), None),
+        };
+        Self {
+            executed: receipt.is_executed(),
+            success,
+            fail_reason,
+            block: receipt.block(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+struct PriorityOpReceipt {
+    #[serde(flatten)]
+    status: TxReceipt,
+}
+
+impl From<Option<PriorityOpReceipt>> for EthOpInfo {
+    fn from(receipt: Option<PriorityOpReceipt>) -> Self {
+        match receipt {
+            Some(PriorityOpReceipt { status }) => Self {
+                executed: status.is_executed(),
+                block: status.block(),
+            },
+            None => Self {
+                executed: false,
+                block: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Contracts {
+    contract: Address,
+    gov_contract: Address,
+}
+
+#[derive(Debug, Serialize)]
+struct IncomingTx {
+    tx: ZkSyncTx,
+    signature: Option<TxEthSignature>,
+}
+
+#[derive(Debug, Serialize)]
+struct IncomingTxBatch {
+    txs: Vec<ZkSyncTx>,
+    signatures: Vec<TxEthSignature>,
+    tx_signatures: Vec<Option<TxEthSignature>>,
+}
+
+/// `RestProvider` is capable of interacting with the zkSync node via its REST API v1.
+///
+/// REST API doesn't provide the fee endpoints yet, so the fee requests are sent
+/// to the JSON RPC API of the same node.
+#[derive(Debug, Clone)]
+pub struct RestProvider {
+    rest_addr: String,
+    client: reqwest::Client,
+    rpc_provider: RpcProvider,
+    network: Network,
+}
+
+#[async_trait]
+impl Provider for RestProvider {
+    async fn account_info(&self, address: Address) -> Result<AccountInfo, ClientError> {
+        let account_info: Option<AccountInfo> =
+            self.get(&format!("accounts/{:x}", address)).await?;
+
+        // Unlike the JSON RPC API, REST API doesn't return any data for the accounts
+        // that don't exist in the zkSync network yet.
+        Ok(account_info.unwrap_or_else(|| AccountInfo {
+            address,
+            id: None,
+            depositing: Default::default(),
+            committed: Default::default(),
+            verified: Default::default(),
+        }))
+    }
+
+    async fn tokens(&self) -> Result<Tokens, ClientError> {
+        let tokens: Vec<Token> = self.get("tokens").await?;
+        Ok(tokens
+            .into_iter()
+            .map(|token| (token.symbol.clone(), token))
+            .collect())
+    }
+
+    async fn tx_info(&self, tx_hash: TxHash) -> Result<TransactionInfo, ClientError> {
+        let receipt: Option<TxReceipt> = self
+            .get(&format!("transactions/{}", tx_hash.to_string()))
+            .await?;
+        Ok(receipt.into())
+    }
+
+    async fn ethop_info(&self, serial_id: u32) -> Result<EthOpInfo, ClientError> {
+        let receipt: Option<PriorityOpReceipt> = self
+            .get(&format!("operations/priority_op/{}", serial_id))
+            .await?;
+        Ok(receipt.into())
+    }
+
+    async fn get_tx_fee(
+        &self,
+        tx_type: TxFeeTypes,
+        address: Address,
+        token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<Fee, ClientError> {
+        self.rpc_provider.get_tx_fee(tx_type, address, token).await
+    }
+
+    async fn get_txs_batch_fee_in_wei(
+        &self,
+        tx_types: Vec<TxFeeTypes>,
+        addresses: Vec<Address>,
+        token: impl Into<TokenLike> + Send + 'async_trait,
+    ) -> Result<BatchFee, ClientError> {
+        self.rpc_provider
+            .get_txs_batch_fee_in_wei(tx_types, addresses, token)
+            .await
+    }
+
+    async fn send_tx(
+        &self,
+        tx: ZkSyncTx,
+        eth_signature: Option<TxEthSignature>,
+    ) -> Result<TxHash, ClientError> {
+        let body = IncomingTx {
+            tx,
+            signature: eth_signature,
+        };
+        self.post("transactions/submit", &body).await
+    }
+
+    async fn send_txs_batch(
+        &self,
+        txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+        eth_signatures: Vec<TxEthSignature>,
+    ) -> Result<Vec<TxHash>, ClientError> {
+        let (txs, tx_signatures) = txs_signed.into_iter().unzip();
+        let body = IncomingTxBatch {
+            txs,
+            signatures: eth_signatures,
+            tx_signatures,
+        };
+        self.post("transactions/submit/batch", &body).await
+    }
+
+    fn network(&self) -> Network {
+        self.network
+    }
+
+    async fn contract_address(&self) -> Result<ContractAddress, ClientError> {
+        let contracts: Contracts = self.get("config/contracts").await?;
+        Ok(ContractAddress {
+            main_contract: format!("{:?}", contracts.contract),
+            gov_contract: format!("{:?}", contracts.gov_contract),
+        })
+    }
+}
+
+impl RestProvider {
+    /// Creates a new `RestProvider` connected to the desired zkSync network.
+    pub fn new(network: Network) -> Self {
+        Self {
+            rest_addr: get_rest_addr(network).into(),
+            client: reqwest::Client::new(),
+            rpc_provider: RpcProvider::new(network),
+            network,
+        }
+    }
+
+    /// Creates a new `RestProvider` object connected to a custom address.
+    /// JSON RPC API address is required to request the transaction fees.
+    pub fn from_addr(rest_addr: impl Into<String>, rpc_addr: impl Into<String>) -> Self {
+        Self {
+            rest_addr: rest_addr.into(),
+            client: reqwest::Client::new(),
+            rpc_provider: RpcProvider::from_addr(rpc_addr),
+            network: Network::Unknown,
+        }
+    }
+
+    fn endpoint(&self, method: &str) -> String {
+        [&self.rest_addr, "/api/v1/", method].concat()
+    }
+
+    async fn get<R: DeserializeOwned>(&self, method: &str) -> Result<R, ClientError> {
+        let request = self.client.get(&self.endpoint(method));
+        Self::send(request).await
+    }
+
+    async fn post<B, R>(&self, method: &str, body: &B) -> Result<R, ClientError>
+    where
+        B: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let request = self.client.post(&self.endpoint(method)).json(body);
+        Self::send(request).await
+    }
+
+    /// Sends the request and decodes the response.
+    /// For unsuccessful responses the `ClientError::RestError` is returned,
+    /// which contains the error description and code provided by the server.
+    async fn send<R: DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<R, ClientError> {
+        let response = request
+            .send()
+            .await
+            .map_err(|err| ClientError::NetworkError(err.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return response
+                .json()
+                .await
+                .map_err(|err| ClientError::MalformedResponse(err.to_string()));
+        }
+
+        // Not every error response has a body, e.g. if the endpoint is not found.
+        let mut error: RestError = response.json().await.unwrap_or_default();
+        error.status = status.as_u16();
+        if error.title.is_empty() {
+            error.title = status.canonical_reason().unwrap_or_default().to_owned();
+        }
+        Err(ClientError::RestError(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_receipt_into_tx_info() {
+        let receipt: Option<TxReceipt> =
+            serde_json::from_str(r#"{"status": "committed", "block": 5}"#).unwrap();
+        let info = TransactionInfo::from(receipt);
+        assert!(info.executed);
+        assert_eq!(info.success, Some(true));
+        let block = info.block.unwrap();
+        assert_eq!(block.block_number, 5);
+        assert!(block.committed && !block.verified);
+
+        let receipt: Option<TxReceipt> =
+            serde_json::from_str(r#"{"status": "rejected", "reason": "Nonce mismatch"}"#).unwrap();
+        let info = TransactionInfo::from(receipt);
+        assert!(info.executed);
+        assert_eq!(info.success, Some(false));
+        assert_eq!(info.fail_reason.as_deref(), Some("Nonce mismatch"));
+        assert!(info.block.is_none());
+
+        let receipt: Option<TxReceipt> = serde_json::from_str("null").unwrap();
+        let info = TransactionInfo::from(receipt);
+        assert!(!info.executed);
+        assert!(info.success.is_none());
+    }
+
+    #[test]
+    fn priority_op_receipt_into_ethop_info() {
+        let receipt: Option<PriorityOpReceipt> =
+            serde_json::from_str(r#"{"status": "verified", "block": 7, "index": 2}"#).unwrap();
+        let info = EthOpInfo::from(receipt);
+        assert!(info.is_verified());
+        assert_eq!(info.block.unwrap().block_number, 7);
+
+        let receipt: Option<PriorityOpReceipt> =
+            serde_json::from_str(r#"{"status": "pending", "index": 0}"#).unwrap();
+        let info = EthOpInfo::from(receipt);
+        assert!(!info.executed);
+        assert!(info.block.is_none());
+    }
+}
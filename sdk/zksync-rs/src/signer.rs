@@ -2,14 +2,17 @@
 use std::fmt;
 use zksync_eth_signer::error::SignerError;
 use zksync_eth_signer::EthereumSigner;
-use zksync_types::tx::{ChangePubKeyECDSAData, ChangePubKeyEthAuthData, TxEthSignature};
+use zksync_types::tx::{
+    BatchSignData, ChangePubKeyCREATE2Data, ChangePubKeyECDSAData, ChangePubKeyEthAuthData,
+    TxEthSignature,
+};
 // External uses
 use num::BigUint;
 // Workspace uses
 use zksync_crypto::PrivateKey;
-use zksync_types::tx::{ChangePubKey, PackedEthSignature};
+use zksync_types::tx::ChangePubKey;
 use zksync_types::{
-    AccountId, Address, ForcedExit, Nonce, PubKeyHash, Token, Transfer, Withdraw, H256,
+    AccountId, Address, ForcedExit, Nonce, PubKeyHash, Token, Transfer, Withdraw, ZkSyncTx, H256,
 };
 // Local imports
 use crate::WalletCredentials;
@@ -102,9 +105,7 @@ impl<S: EthereumSigner> Signer<S> {
                 .as_ref()
                 .ok_or(SignerError::MissingEthSigner)?;
 
-            let sign_bytes = change_pubkey
-                .get_eth_signed_data()
-                .map_err(signing_failed_error)?;
+            let sign_bytes = change_pubkey.get_eth_signed_data_with_batch_hash(H256::zero());
             let eth_signature = eth_signer
                 .sign_message(&sign_bytes)
                 .await
@@ -132,6 +133,113 @@ impl<S: EthereumSigner> Signer<S> {
         Ok(change_pubkey)
     }
 
+    /// Signs the `ChangePubKey` transaction of the account deployed via CREATE2.
+    /// Key change is authorized by the CREATE2 data the account address is derived from,
+    /// so the Ethereum signature is not required.
+    pub async fn sign_change_pubkey_create2_tx(
+        &self,
+        nonce: Nonce,
+        create2_data: ChangePubKeyCREATE2Data,
+        fee_token: Token,
+        fee: BigUint,
+    ) -> Result<ChangePubKey, SignerError> {
+        let account_id = self.account_id.ok_or(SignerError::NoSigningKey)?;
+
+        let mut change_pubkey = ChangePubKey::new_signed(
+            account_id,
+            self.address,
+            self.pubkey_hash,
+            fee_token.id,
+            fee,
+            nonce,
+            None,
+            &self.private_key,
+        )
+        .map_err(signing_failed_error)?;
+        change_pubkey.eth_auth_data = ChangePubKeyEthAuthData::CREATE2(create2_data);
+
+        if !change_pubkey.is_eth_auth_data_valid() {
+            return Err(SignerError::CustomError(
+                "CREATE2 data doesn't match the account address".to_string(),
+            ));
+        }
+
+        Ok(change_pubkey)
+    }
+
+    /// Authorizes the `ChangePubKey` included into the batch by the Ethereum signature
+    /// of the message containing the batch hash.
+    ///
+    /// The batch still has to be signed by `sign_batch`. Its message is the same `ChangePubKey`
+    /// message once the auth data is set, but the batch signature is requested separately.
+    pub async fn sign_change_pubkey_batch_auth(
+        &self,
+        txs: &mut [ZkSyncTx],
+    ) -> Result<(), SignerError> {
+        let eth_signer = self
+            .eth_signer
+            .as_ref()
+            .ok_or(SignerError::MissingEthSigner)?;
+
+        // Batch hash doesn't depend on the `ChangePubKey` authorization data, and for the batch
+        // without the `ChangePubKey` authorized by signature the batch message is the batch hash.
+        for tx in txs.iter_mut() {
+            if let ZkSyncTx::ChangePubKey(change_pubkey) = tx {
+                change_pubkey.eth_auth_data = ChangePubKeyEthAuthData::Onchain;
+            }
+        }
+        let batch_hash = BatchSignData::new(txs, Vec::new())
+            .map_err(signing_failed_error)?
+            .message;
+        let batch_hash = H256::from_slice(&batch_hash);
+
+        let change_pubkey = txs
+            .iter_mut()
+            .find_map(|tx| match tx {
+                ZkSyncTx::ChangePubKey(change_pubkey) => Some(change_pubkey),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                SignerError::CustomError("There is no ChangePubKey in the batch".to_string())
+            })?;
+        let sign_bytes = change_pubkey.get_eth_signed_data_with_batch_hash(batch_hash);
+        let eth_signature = match eth_signer
+            .sign_message(&sign_bytes)
+            .await
+            .map_err(signing_failed_error)?
+        {
+            TxEthSignature::EthereumSignature(packed_signature) => packed_signature,
+            TxEthSignature::EIP1271Signature(..) => {
+                return Err(SignerError::CustomError(
+                    "Can't sign ChangePubKey message with EIP1271 signer".to_string(),
+                ))
+            }
+        };
+        change_pubkey.eth_auth_data = ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData {
+            eth_signature,
+            batch_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Signs the batch of transactions with the Ethereum key.
+    /// Signature authorizes all the transactions in the batch, so they don't have to be
+    /// signed one by one.
+    ///
+    /// For the smart contract wallets the batch is signed with the EIP1271 signature.
+    pub async fn sign_batch(&self, txs: &[ZkSyncTx]) -> Result<TxEthSignature, SignerError> {
+        let eth_signer = self
+            .eth_signer
+            .as_ref()
+            .ok_or(SignerError::MissingEthSigner)?;
+
+        let message = BatchSignData::new(txs, Vec::new())
+            .map_err(signing_failed_error)?
+            .message;
+        eth_signer.sign_message(&message).await
+    }
+
     pub async fn sign_transfer(
         &self,
         token: Token,
@@ -139,7 +247,7 @@ impl<S: EthereumSigner> Signer<S> {
         fee: BigUint,
         to: Address,
         nonce: Nonce,
-    ) -> Result<(Transfer, Option<PackedEthSignature>), SignerError> {
+    ) -> Result<(Transfer, Option<TxEthSignature>), SignerError> {
         let account_id = self.account_id.ok_or(SignerError::NoSigningKey)?;
 
         let transfer = Transfer::new_signed(
@@ -157,13 +265,7 @@ impl<S: EthereumSigner> Signer<S> {
         let eth_signature = match &self.eth_signer {
             Some(signer) => {
                 let message = transfer.get_ethereum_sign_message(&token.symbol, token.decimals);
-                let signature = signer.sign_message(&message.as_bytes()).await?;
-
-                if let TxEthSignature::EthereumSignature(packed_signature) = signature {
-                    Some(packed_signature)
-                } else {
-                    return Err(SignerError::MissingEthSigner);
-                }
+                Some(signer.sign_message(&message.as_bytes()).await?)
             }
             _ => None,
         };
@@ -178,7 +280,7 @@ impl<S: EthereumSigner> Signer<S> {
         fee: BigUint,
         eth_address: Address,
         nonce: Nonce,
-    ) -> Result<(Withdraw, Option<PackedEthSignature>), SignerError> {
+    ) -> Result<(Withdraw, Option<TxEthSignature>), SignerError> {
         let account_id = self.account_id.ok_or(SignerError::NoSigningKey)?;
 
         let withdraw = Withdraw::new_signed(
@@ -196,13 +298,7 @@ impl<S: EthereumSigner> Signer<S> {
         let eth_signature = match &self.eth_signer {
             Some(signer) => {
                 let message = withdraw.get_ethereum_sign_message(&token.symbol, token.decimals);
-                let signature = signer.sign_message(&message.as_bytes()).await?;
-
-                if let TxEthSignature::EthereumSignature(packed_signature) = signature {
-                    Some(packed_signature)
-                } else {
-                    return Err(SignerError::MissingEthSigner);
-                }
+                Some(signer.sign_message(&message.as_bytes()).await?)
             }
             _ => None,
         };
@@ -13,10 +13,12 @@ use zksync_types::{AccountId, U256};
 use crate::{error::ClientError, provider::Provider, wallet::Wallet};
 
 // Public re-exports.
+pub use zksync_crypto::merkle_tree::verify_proof;
 pub use zksync_types::helpers::{
     closest_packable_fee_amount, closest_packable_token_amount, is_fee_amount_packable,
     is_token_amount_packable, pack_fee_amount, pack_token_amount,
 };
+pub use zksync_types::AccountProof;
 
 /// Generates a new `PrivateKey` from seed using a deterministic algorithm:
 /// seed is hashed via `sha256` hash (twice), and the output treated as a `PrivateKey`.
@@ -50,6 +52,41 @@ pub fn private_key_from_seed(seed: &[u8]) -> Result<PrivateKey, ClientError> {
     }
 }
 
+/// Derives the `PrivateKey` with the given index from seed, so multiple keys can be obtained
+/// from the same seed, e.g. to rotate the signing key of the account.
+/// Key with index `0` is the one generated via `private_key_from_seed`, keys with other indices
+/// are generated from the seed followed by the big-endian key index.
+pub fn private_key_from_seed_and_index(
+    seed: &[u8],
+    key_index: u32,
+) -> Result<PrivateKey, ClientError> {
+    if seed.len() < 32 {
+        return Err(ClientError::SeedTooShort);
+    }
+
+    if key_index == 0 {
+        private_key_from_seed(seed)
+    } else {
+        private_key_from_seed(&[seed, &key_index.to_be_bytes()].concat())
+    }
+}
+
+/// Reads the `PrivateKey` from its 32-byte big-endian representation,
+/// i.e. the form in which the private keys are exported by `zksync.js`.
+pub fn private_key_from_bytes(bytes: &[u8]) -> Result<PrivateKey, ClientError> {
+    if bytes.len() != 32 {
+        return Err(ClientError::IncorrectCredentials);
+    }
+
+    let mut fs_repr = FsRepr::default();
+    fs_repr
+        .read_be(bytes)
+        .map_err(|_| ClientError::IncorrectCredentials)?;
+    Fs::from_repr(fs_repr)
+        .map(priv_key_from_fs)
+        .map_err(|_| ClientError::IncorrectCredentials)
+}
+
 ///
 /// Waits until there is a zkSync account ID associated with the `wallet`.
 ///
@@ -103,6 +140,7 @@ pub fn biguint_to_u256(value: BigUint) -> U256 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use zksync_types::PubKeyHash;
 
     fn biguint_u256_conversion_roundrip(u256: U256) {
         let biguint = u256_to_biguint(u256);
@@ -138,5 +176,45 @@ mod tests {
 
         let pk_err = private_key_from_seed(short_seed).map(|_| ()).unwrap_err();
         assert_eq!(pk_err, ClientError::SeedTooShort);
+
+        let pk_err = private_key_from_seed_and_index(short_seed, 1)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(pk_err, ClientError::SeedTooShort);
+    }
+
+    #[test]
+    fn test_private_key_from_seed_and_index() {
+        let seed = &[42; 32];
+        let pubkey_hash = |key_index| {
+            PubKeyHash::from_privkey(&private_key_from_seed_and_index(seed, key_index).unwrap())
+        };
+
+        assert_eq!(
+            pubkey_hash(0),
+            PubKeyHash::from_privkey(&private_key_from_seed(seed).unwrap())
+        );
+        assert_ne!(pubkey_hash(0), pubkey_hash(1));
+        assert_ne!(pubkey_hash(1), pubkey_hash(2));
+        assert_eq!(pubkey_hash(1), pubkey_hash(1));
+    }
+
+    #[test]
+    fn test_private_key_from_bytes_byte_order() {
+        // Key is read as a big-endian number, so the last byte is the least significant one.
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        let private_key = private_key_from_bytes(&bytes).unwrap();
+        assert_eq!(private_key.0, Fs::from_str("1").unwrap());
+
+        // Big-endian representation of the key is restored to the same key.
+        let private_key = private_key_from_seed(&[42; 32]).unwrap();
+        let mut bytes = Vec::new();
+        private_key.0.into_repr().write_be(&mut bytes).unwrap();
+        let restored = private_key_from_bytes(&bytes).unwrap();
+        assert_eq!(restored.0, private_key.0);
+
+        let pk_err = private_key_from_bytes(&bytes[1..]).map(|_| ()).unwrap_err();
+        assert_eq!(pk_err, ClientError::IncorrectCredentials);
     }
 }
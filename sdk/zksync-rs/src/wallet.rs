@@ -10,7 +10,7 @@ use crate::{
     provider::Provider,
     signer::Signer,
     tokens_cache::TokensCache,
-    types::{AccountInfo, BlockStatus},
+    types::{AccountInfo, BlockStatus, TransactionInfo},
 };
 
 #[derive(Debug)]
@@ -114,6 +114,65 @@ where
         Ok(key_set)
     }
 
+    /// Changes the signing key of the account to the key of the provided credentials,
+    /// e.g. the next key obtained via `WalletCredentials::from_eth_signer_with_key_index`.
+    /// The key change is authorized by the Ethereum signature, and the fee is paid in `fee_token`.
+    ///
+    /// The wallet starts using the new key only after the `ChangePubKey` transaction is executed,
+    /// so the current key is kept if the transaction is rejected or fails.
+    /// Returns an error if the credentials belong to another account or have no Ethereum signer.
+    ///
+    /// If the transaction execution can't be awaited (e.g. on `ClientError::OperationTimeout`),
+    /// the account state is checked: the wallet switches to the new key if it's already set,
+    /// otherwise the error is returned and the current key is kept. In the latter case the
+    /// transaction may still be executed later, which can be checked via `is_signing_key_set`
+    /// of the wallet created with the new credentials.
+    pub async fn rotate_signing_key(
+        &mut self,
+        credentials: WalletCredentials<S>,
+        fee_token: impl Into<TokenLike>,
+    ) -> Result<TransactionInfo, ClientError> {
+        if credentials.eth_address != self.address() {
+            return Err(ClientError::IncorrectCredentials);
+        }
+        if credentials.eth_signer.is_none() {
+            return Err(ClientError::NoEthereumPrivateKey);
+        }
+
+        let mut signer = Signer::with_credentials(credentials);
+        signer.set_account_id(self.account_id());
+        let wallet = Wallet {
+            provider: self.provider.clone(),
+            signer,
+            tokens: self.tokens.clone(),
+        };
+
+        let handle = wallet
+            .start_change_pubkey()
+            .fee_token(fee_token)?
+            .send()
+            .await?;
+        let tx_info = match handle.wait_for_execution().await {
+            Ok(tx_info) => tx_info,
+            Err(err) => {
+                if !wallet.is_signing_key_set().await? {
+                    return Err(err);
+                }
+                // The new key is set, so the transaction was executed successfully.
+                self.signer = wallet.signer;
+                return self.provider.tx_info(handle.hash()).await;
+            }
+        };
+        if tx_info.success != Some(true) {
+            return Err(ClientError::TransactionFailed(
+                tx_info.fail_reason.unwrap_or_default(),
+            ));
+        }
+
+        self.signer = wallet.signer;
+        Ok(tx_info)
+    }
+
     /// Initializes `Transfer` transaction sending.
     pub fn start_transfer(&self) -> TransferBuilder<'_, S, P> {
         TransferBuilder::new(self)
@@ -129,6 +188,16 @@ where
         WithdrawBuilder::new(self)
     }
 
+    /// Initializes `ForcedExit` transaction sending.
+    pub fn start_forced_exit(&self) -> ForcedExitBuilder<'_, S, P> {
+        ForcedExitBuilder::new(self)
+    }
+
+    /// Initializes the transactions batch building.
+    pub fn start_batch(&self) -> BatchBuilder<'_, S, P> {
+        BatchBuilder::new(self)
+    }
+
     /// Creates an `EthereumProvider` to interact with the Ethereum network.
     ///
     /// Returns an error if wallet was created without providing an Ethereum private key.
@@ -0,0 +1,388 @@
+//! Pool of wallets for sending a large amount of transactions concurrently.
+//!
+//! Builders of `Wallet` request the nonce from the server for every transaction, so
+//! concurrent transactions of the same account get the same nonce and all but one
+//! of them are rejected. `WalletPool` keeps the nonce of the next transaction locally
+//! for every account instead, and sends the transactions of each account one by one,
+//! so they reach the server in the nonce order.
+//!
+//! The local nonce is obtained from the server on the first transaction of the account,
+//! and is resynced if the server rejects the transaction because of the nonce mismatch
+//! (e.g. if the account was used outside of the pool), or if it's unknown whether the
+//! transaction was accepted (e.g. in case of the network error). Other rejections
+//! (e.g. because of the too low fee) don't consume the nonce, so it is kept.
+//!
+//! Note that the nonce is resynced from the committed state of the account, which doesn't
+//! include the transactions still waiting in the mempool. If the transaction with the unknown
+//! outcome was actually accepted, the next transaction reuses its nonce and fails once
+//! executed, so the nonce is resynced again.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use futures::future::join_all;
+use num::BigUint;
+use tokio::sync::Mutex as AsyncMutex;
+use zksync_eth_signer::EthereumSigner;
+use zksync_types::{helpers::closest_packable_token_amount, Address, Nonce, TokenId, TokenLike};
+
+use crate::{
+    error::ClientError, operations::SyncTransactionHandle, provider::Provider, wallet::Wallet,
+};
+
+/// Code of the transaction rejection because of the nonce mismatch in the JSON RPC API.
+const RPC_NONCE_MISMATCH_CODE: i64 = 101;
+/// Code of the transaction rejection by the mempool in the REST API.
+/// The rejection reason is only provided by the error title.
+const REST_TX_ADD_CODE: u64 = 105;
+/// Part of the REST API error title for the transaction rejected because of the nonce mismatch.
+const REST_NONCE_MISMATCH_TITLE: &str = "nonce is too low";
+
+/// Transfer added to the queue via `WalletPool::queue_transfer`.
+#[derive(Debug, Clone)]
+struct PendingTransfer {
+    token: TokenId,
+    amount: BigUint,
+    to: Address,
+}
+
+#[derive(Debug)]
+struct PoolAccount<S: EthereumSigner, P: Provider> {
+    wallet: Wallet<S, P>,
+    /// Nonce of the next transaction, `None` if it has to be requested from the server.
+    /// Lock is held while the transaction is sent.
+    nonce: AsyncMutex<Option<Nonce>>,
+    /// Number of the transactions or batches being sent by the account,
+    /// including the ones waiting for the nonce lock.
+    in_flight: AtomicUsize,
+}
+
+/// Decrements the counter of the transactions in flight when dropped,
+/// so the counter stays correct even if the sending future is cancelled.
+struct InFlightGuard<'a>(&'a AtomicUsize);
+
+impl<'a> InFlightGuard<'a> {
+    fn new(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<S, P> PoolAccount<S, P>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    /// Sends the transfers with the local nonce, as a single transaction or a batch.
+    /// If the transfers are rejected because of the nonce mismatch, resyncs the nonce
+    /// with the server and retries once. Other errors are returned as is, resetting
+    /// the local nonce only if the outcome of the sending is unknown.
+    async fn send(
+        &self,
+        transfers: &[PendingTransfer],
+    ) -> Result<Vec<SyncTransactionHandle<P>>, ClientError> {
+        let _in_flight = InFlightGuard::new(&self.in_flight);
+        let mut local_nonce = self.nonce.lock().await;
+
+        let mut synced = false;
+        loop {
+            let nonce = match *local_nonce {
+                Some(nonce) => nonce,
+                None => {
+                    synced = true;
+                    self.wallet.account_info().await?.committed.nonce
+                }
+            };
+
+            match self.send_with_nonce(transfers, nonce).await {
+                Ok(handles) => {
+                    *local_nonce = Some(nonce + handles.len() as Nonce);
+                    return Ok(handles);
+                }
+                Err(err) if is_nonce_mismatch(&err) => {
+                    *local_nonce = None;
+                    if synced {
+                        return Err(err);
+                    }
+                }
+                Err(err) => {
+                    *local_nonce = if is_outcome_unknown(&err) {
+                        None
+                    } else {
+                        Some(nonce)
+                    };
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    async fn send_with_nonce(
+        &self,
+        transfers: &[PendingTransfer],
+        nonce: Nonce,
+    ) -> Result<Vec<SyncTransactionHandle<P>>, ClientError> {
+        if let [transfer] = transfers {
+            let handle = self
+                .wallet
+                .start_transfer()
+                .token(transfer.token)?
+                .amount(transfer.amount.clone())
+                .to(transfer.to)
+                .nonce(nonce)
+                .send()
+                .await?;
+            return Ok(vec![handle]);
+        }
+
+        // The batch fee is paid by the first transfer, so there is no additional
+        // fee transaction and handles correspond to the transfers.
+        let fee_token = transfers
+            .first()
+            .ok_or_else(|| ClientError::MissingRequiredField("transfers".into()))?
+            .token;
+        let mut batch = self.wallet.start_batch().fee_token(fee_token)?.nonce(nonce);
+        for transfer in transfers {
+            batch = batch.add_transfer(transfer.token, transfer.amount.clone(), transfer.to)?;
+        }
+        batch.send().await
+    }
+}
+
+fn is_nonce_mismatch(err: &ClientError) -> bool {
+    match err {
+        ClientError::RpcError(failure) => {
+            failure.error.code == jsonrpc_core::ErrorCode::ServerError(RPC_NONCE_MISMATCH_CODE)
+        }
+        ClientError::RestError(err) => {
+            err.code == Some(REST_TX_ADD_CODE)
+                && err.title.to_lowercase().contains(REST_NONCE_MISMATCH_TITLE)
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether it's unknown if the transactions were accepted by the server,
+/// e.g. if the request has failed because of the network error.
+fn is_outcome_unknown(err: &ClientError) -> bool {
+    match err {
+        ClientError::NetworkError(_) | ClientError::MalformedResponse(_) => true,
+        // Rejections of the transactions always have the error code.
+        ClientError::RestError(err) => err.code.is_none(),
+        _ => false,
+    }
+}
+
+/// Pool of wallets sending transfers concurrently.
+///
+/// Every transfer is sent by the account with the least number of transactions in flight,
+/// so the load is distributed between the accounts. Transfers can be sent right away
+/// via `transfer`, or queued via `queue_transfer` and sent in batches. The batch is sent
+/// as soon as the queue reaches `max_batch_size` transfers, and the rest of the queue
+/// is only sent by `flush`, so it has to be called once all the transfers are queued.
+///
+/// The pool assumes that transactions of its accounts are only sent by the pool.
+/// If a transaction fails after being accepted by the server, the local nonces
+/// become too high and have to be reset via `resync`.
+#[derive(Debug)]
+pub struct WalletPool<S: EthereumSigner, P: Provider> {
+    accounts: Vec<PoolAccount<S, P>>,
+    next_account: AtomicUsize,
+    queue: Mutex<Vec<PendingTransfer>>,
+    max_batch_size: usize,
+}
+
+impl<S, P> WalletPool<S, P>
+where
+    S: EthereumSigner + Clone,
+    P: Provider + Clone,
+{
+    /// Default maximum number of transfers in a batch, the default limit of the server.
+    pub const DEFAULT_MAX_BATCH_SIZE: usize = 10;
+
+    /// Creates the pool of the provided wallets.
+    ///
+    /// Panics if no wallets are provided.
+    pub fn new(wallets: Vec<Wallet<S, P>>) -> Self {
+        assert!(!wallets.is_empty(), "Wallet pool can't be empty");
+
+        let accounts = wallets
+            .into_iter()
+            .map(|wallet| PoolAccount {
+                wallet,
+                nonce: AsyncMutex::new(None),
+                in_flight: AtomicUsize::new(0),
+            })
+            .collect();
+
+        Self {
+            accounts,
+            next_account: AtomicUsize::new(0),
+            queue: Mutex::new(Vec::new()),
+            max_batch_size: Self::DEFAULT_MAX_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the maximum number of transfers in a batch sent by `queue_transfer` and `flush`.
+    ///
+    /// Panics if the size is zero.
+    pub fn max_batch_size(mut self, max_batch_size: usize) -> Self {
+        assert!(max_batch_size > 0, "Batch size can't be zero");
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Returns the wallets of the pool.
+    pub fn wallets(&self) -> impl Iterator<Item = &Wallet<S, P>> {
+        self.accounts.iter().map(|account| &account.wallet)
+    }
+
+    /// Sends the transfer from the least loaded account of the pool, returning the handle
+    /// for its awaiting. The fee is paid in the transfer token. If the provided amount
+    /// is not packable, rounds it to the closest packable amount.
+    pub async fn transfer(
+        &self,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+        to: Address,
+    ) -> Result<SyncTransactionHandle<P>, ClientError> {
+        let transfer = self.pending_transfer(token, amount, to)?;
+        let mut handles = self.pick_account().send(&[transfer]).await?;
+        Ok(handles.remove(0))
+    }
+
+    /// Adds the transfer to the queue. If the provided amount is not packable, rounds it
+    /// to the closest packable amount.
+    ///
+    /// Once the queue reaches `max_batch_size` transfers, they're sent in a batch and the handles
+    /// of the batch transfers are returned. Otherwise, the transfer will be sent by one of the
+    /// following `queue_transfer` calls or by `flush`, and `None` is returned.
+    ///
+    /// Returns an error if token is not supported by zkSync, or if the batch was not sent.
+    /// Transfers of the failed batch are not returned to the queue.
+    pub async fn queue_transfer(
+        &self,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+        to: Address,
+    ) -> Result<Option<Vec<SyncTransactionHandle<P>>>, ClientError> {
+        let transfer = self.pending_transfer(token, amount, to)?;
+        let batch = {
+            let mut queue = self.queue.lock().unwrap();
+            queue.push(transfer);
+            if queue.len() < self.max_batch_size {
+                return Ok(None);
+            }
+            std::mem::take(&mut *queue)
+        };
+
+        self.pick_account().send(&batch).await.map(Some)
+    }
+
+    /// Returns the number of transfers in the queue.
+    pub fn queued_transfers(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    /// Sends all the queued transfers, splitting them into batches of at most `max_batch_size`
+    /// transfers in the queue order. Batches are distributed between the accounts and
+    /// sent concurrently.
+    ///
+    /// Returns the result for every batch, with the handles corresponding to the batch transfers.
+    /// Transfers of the failed batches are not returned to the queue.
+    pub async fn flush(&self) -> Vec<Result<Vec<SyncTransactionHandle<P>>, ClientError>> {
+        let transfers = std::mem::take(&mut *self.queue.lock().unwrap());
+
+        let batches = transfers
+            .chunks(self.max_batch_size)
+            .map(|batch| self.pick_account().send(batch));
+        join_all(batches).await
+    }
+
+    /// Resets the local nonces, so they're requested from the server for the next transactions.
+    /// Waits for the transactions being sent, but not for the execution of the sent ones:
+    /// the nonce is requested from the committed state, so the transactions still waiting
+    /// in the mempool have to be executed before the resync.
+    pub async fn resync(&self) {
+        for account in &self.accounts {
+            *account.nonce.lock().await = None;
+        }
+    }
+
+    fn pending_transfer(
+        &self,
+        token: impl Into<TokenLike>,
+        amount: impl Into<BigUint>,
+        to: Address,
+    ) -> Result<PendingTransfer, ClientError> {
+        // All the wallets share the same list of tokens.
+        let token = self.accounts[0]
+            .wallet
+            .tokens
+            .resolve(token.into())
+            .ok_or(ClientError::UnknownToken)?;
+
+        Ok(PendingTransfer {
+            token: token.id,
+            amount: closest_packable_token_amount(&amount.into()),
+            to,
+        })
+    }
+
+    /// Picks the account with the least number of transactions in flight.
+    /// Ties are broken in the round-robin manner.
+    fn pick_account(&self) -> &PoolAccount<S, P> {
+        let start = self.next_account.fetch_add(1, Ordering::Relaxed);
+        let accounts_count = self.accounts.len();
+
+        (0..accounts_count)
+            .map(|offset| &self.accounts[(start + offset) % accounts_count])
+            .min_by_key(|account| account.in_flight.load(Ordering::SeqCst))
+            .expect("Wallet pool can't be empty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RestError;
+
+    /// Parses the REST API error the same way as `RestProvider` does.
+    fn rest_error(body: &str) -> ClientError {
+        let mut error: RestError = serde_json::from_str(body).unwrap();
+        error.status = 400;
+        ClientError::RestError(error)
+    }
+
+    #[test]
+    fn rest_nonce_mismatch() {
+        let err = rest_error(
+            r#"{"title": "Transaction adding error: Tx nonce is too low.", "code": 105}"#,
+        );
+        assert!(is_nonce_mismatch(&err));
+        assert!(!is_outcome_unknown(&err));
+
+        // Other rejections by the mempool have the same code.
+        let err = rest_error(
+            r#"{"title": "Transaction adding error: Transaction fee is too low", "code": 105}"#,
+        );
+        assert!(!is_nonce_mismatch(&err));
+        assert!(!is_outcome_unknown(&err));
+
+        // Code of the nonce mismatch in the JSON RPC API is used for another error in REST.
+        let err = rest_error(r#"{"title": "Account close tx is disabled.", "code": 101}"#);
+        assert!(!is_nonce_mismatch(&err));
+
+        let err = rest_error(r#"{"title": "Internal Server Error"}"#);
+        assert!(!is_nonce_mismatch(&err));
+        assert!(is_outcome_unknown(&err));
+    }
+}
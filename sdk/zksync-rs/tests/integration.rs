@@ -747,3 +747,101 @@ async fn batch_transfer() -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn transfer_with_fee_in_another_token() -> Result<(), anyhow::Error> {
+    let wallet = init_account_with_one_ether().await?;
+    let ethereum = wallet.ethereum(LOCALHOST_WEB3_ADDR).await?;
+    let token_dai = wallet
+        .tokens
+        .resolve("DAI".into())
+        .ok_or_else(|| anyhow::anyhow!("Error resolve token"))?;
+
+    // Deposit some DAI to pay the fee in ETH for its transfer.
+    let dai_deposit_amount = U256::from(10).pow(18.into()) * 100; // 100 DAI
+    transfer_to("DAI", dai_deposit_amount, wallet.address()).await?;
+    let tx_approve_deposits = ethereum
+        .approve_erc20_token_deposits(token_dai.address)
+        .await?;
+    ethereum.wait_for_tx(tx_approve_deposits).await?;
+    let deposit_tx_hash = ethereum
+        .deposit("DAI", dai_deposit_amount, wallet.address())
+        .await?;
+    ethereum.wait_for_tx(deposit_tx_hash).await?;
+
+    let timeout = Duration::from_secs(60);
+    let mut poller = tokio::time::interval(Duration::from_millis(100));
+    let start = Instant::now();
+    while wallet.get_balance(BlockStatus::Committed, "DAI").await? == 0u32.into() {
+        if start.elapsed() > timeout {
+            panic!("Timeout elapsed while waiting for DAI deposit");
+        }
+        poller.tick().await;
+    }
+
+    let eth_balance_before = wallet.get_balance(BlockStatus::Committed, "ETH").await?;
+    let dai_balance_before = wallet.get_balance(BlockStatus::Committed, "DAI").await?;
+    let recipient = eth_random_account_credentials().0;
+    let amount = 1_000_000u64;
+
+    // Transfer and its fee transfer are sent in a batch.
+    let txs = wallet
+        .start_transfer()
+        .token("DAI")?
+        .fee_token("ETH")?
+        .amount(amount)
+        .to(recipient)
+        .txs()
+        .await?;
+    assert_eq!(txs.len(), 2);
+
+    let handle = wallet
+        .start_transfer()
+        .token("DAI")?
+        .fee_token("ETH")?
+        .amount(amount)
+        .to(recipient)
+        .send()
+        .await?;
+    handle
+        .commit_timeout(Duration::from_secs(180))
+        .wait_for_commit()
+        .await?;
+
+    let eth_balance_after = wallet.get_balance(BlockStatus::Committed, "ETH").await?;
+    let dai_balance_after = wallet.get_balance(BlockStatus::Committed, "DAI").await?;
+    assert_eq!(dai_balance_before - dai_balance_after, amount.into());
+    assert!(eth_balance_after < eth_balance_before);
+
+    // Withdrawal pays the fee in another token as well.
+    let handle = wallet
+        .start_withdraw()
+        .token("DAI")?
+        .fee_token("ETH")?
+        .amount(amount)
+        .to(wallet.address())
+        .send()
+        .await?;
+    handle
+        .commit_timeout(Duration::from_secs(180))
+        .wait_for_commit()
+        .await?;
+    assert_eq!(
+        wallet.get_balance(BlockStatus::Committed, "DAI").await?,
+        dai_balance_after - amount
+    );
+
+    // Transaction paying the fee in another token can't be sent alone.
+    let result = wallet
+        .start_transfer()
+        .token("DAI")?
+        .fee_token("ETH")?
+        .amount(amount)
+        .to(recipient)
+        .tx()
+        .await;
+    assert_eq!(result.unwrap_err(), ClientError::FeeInAnotherToken);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use zksync::{tokens_cache::TokensCache, utils::*, web3::types::H160, zksync_types::Token};
 use zksync_config::test_config::unit_vectors::{Config as TestVectorsConfig, TestEntry};
 use zksync_crypto::PrivateKey;
-use zksync_types::tx::TxSignature;
+use zksync_types::tx::{PackedEthSignature, TxEthSignature, TxSignature};
 
 #[test]
 fn test_tokens_cache() {
@@ -81,6 +81,13 @@ fn assert_tx_signature(signature: &TxSignature, expected_pub: &str, expected_sig
     assert_eq!(hex::encode(packed_sig), expected_sig);
 }
 
+fn ecdsa_signature(signature: &TxEthSignature) -> &PackedEthSignature {
+    match signature {
+        TxEthSignature::EthereumSignature(signature) => signature,
+        _ => panic!("Expected ECDSA signature, got {:?}", signature),
+    }
+}
+
 #[cfg(test)]
 mod primitives_with_vectors {
     use super::*;
@@ -155,7 +162,7 @@ mod signatures_with_vectors {
     use zksync_config::test_config::unit_vectors::TxData;
     use zksync_eth_signer::PrivateKeySigner;
     use zksync_types::tx::{ChangePubKeyECDSAData, ChangePubKeyEthAuthData};
-    use zksync_types::{network::Network, AccountId, Address, H256};
+    use zksync_types::{network::Network, AccountId, Address, ZkSyncTx, H256};
 
     async fn get_signer(
         eth_private_key_raw: &[u8],
@@ -208,6 +215,7 @@ mod signatures_with_vectors {
                     .expect("Transfer signing error");
 
                 assert_eq!(transfer.get_bytes(), outputs.sign_bytes);
+                assert_eq!(ZkSyncTx::from(transfer.clone()).hash(), outputs.tx_hash);
                 assert_tx_signature(
                     &transfer.signature,
                     &outputs.signature.pub_key,
@@ -220,7 +228,8 @@ mod signatures_with_vectors {
                 );
 
                 if let Some(expected_eth_signature) = outputs.eth_signature {
-                    let eth_signature = eth_signature.unwrap().serialize_packed();
+                    let eth_signature =
+                        ecdsa_signature(eth_signature.as_ref().unwrap()).serialize_packed();
                     assert_eq!(&eth_signature[..], expected_eth_signature.as_slice());
                 }
             }
@@ -261,6 +270,7 @@ mod signatures_with_vectors {
                     .expect("Withdraw signing error");
 
                 assert_eq!(withdraw.get_bytes(), outputs.sign_bytes);
+                assert_eq!(ZkSyncTx::from(withdraw.clone()).hash(), outputs.tx_hash);
                 assert_tx_signature(
                     &withdraw.signature,
                     &outputs.signature.pub_key,
@@ -273,7 +283,8 @@ mod signatures_with_vectors {
                 );
 
                 if let Some(expected_eth_signature) = outputs.eth_signature {
-                    let eth_signature = eth_signature.unwrap().serialize_packed();
+                    let eth_signature =
+                        ecdsa_signature(eth_signature.as_ref().unwrap()).serialize_packed();
                     assert_eq!(&eth_signature[..], expected_eth_signature.as_slice());
                 }
             }
@@ -314,6 +325,10 @@ mod signatures_with_vectors {
                     .expect("Change pub key signing error");
 
                 assert_eq!(change_pub_key.get_bytes(), outputs.sign_bytes);
+                assert_eq!(
+                    ZkSyncTx::from(change_pub_key.clone()).hash(),
+                    outputs.tx_hash
+                );
                 assert_tx_signature(
                     &change_pub_key.signature,
                     &outputs.signature.pub_key,
@@ -321,8 +336,11 @@ mod signatures_with_vectors {
                 );
 
                 assert_eq!(
-                    change_pub_key.get_eth_signed_data().unwrap(),
-                    outputs.eth_sign_message.unwrap().into_bytes()
+                    format!(
+                        "0x{}",
+                        hex::encode(change_pub_key.get_eth_signed_data().unwrap())
+                    ),
+                    outputs.eth_sign_message.unwrap()
                 );
 
                 if let Some(expected_eth_signature) = outputs.eth_signature {
@@ -368,6 +386,7 @@ mod signatures_with_vectors {
                     .expect("Forced exit signing error");
 
                 assert_eq!(forced_exit.get_bytes(), outputs.sign_bytes);
+                assert_eq!(ZkSyncTx::from(forced_exit.clone()).hash(), outputs.tx_hash);
                 assert_tx_signature(
                     &forced_exit.signature,
                     &outputs.signature.pub_key,
@@ -378,6 +397,27 @@ mod signatures_with_vectors {
     }
 }
 
+#[cfg(feature = "cli")]
+mod vectors_generator {
+    use super::*;
+    use std::process::Command;
+
+    /// Vectors generated from the Rust implementation must match the ones generated by `zksync.js`.
+    #[test]
+    fn test_generated_vectors() {
+        let output = Command::new(env!("CARGO_BIN_EXE_test_vector_generator"))
+            .output()
+            .expect("Failed to run test vector generator");
+        assert!(output.status.success());
+
+        let generated: TestVectorsConfig = serde_json::from_slice(&output.stdout).unwrap();
+        let expected = TestVectorsConfig::load();
+        assert_eq!(generated.crypto_primitives, expected.crypto_primitives);
+        assert_eq!(generated.utils, expected.utils);
+        assert_eq!(generated.transactions, expected.transactions);
+    }
+}
+
 #[cfg(test)]
 mod wallet_tests {
     use super::*;
@@ -387,14 +427,18 @@ mod wallet_tests {
         provider::Provider,
         signer::Signer,
         types::{
-            AccountInfo, AccountState, BlockStatus, ContractAddress, Fee, Tokens, TransactionInfo,
+            AccountInfo, AccountState, BatchFee, BlockStatus, ContractAddress, EthOpInfo, Fee,
+            Tokens, TransactionInfo,
         },
         Network, Wallet, WalletCredentials,
     };
-    use zksync_eth_signer::PrivateKeySigner;
+    use zksync_eth_signer::{KeystoreSigner, PrivateKeySigner};
     use zksync_types::{
         tokens::get_genesis_token_list,
-        tx::{PackedEthSignature, TxHash},
+        tx::{
+            BatchSignData, ChangePubKeyCREATE2Data, ChangePubKeyEthAuthData, PackedEthSignature,
+            TxHash,
+        },
         Address, PubKeyHash, TokenLike, TxFeeTypes, ZkSyncTx, H256,
     };
 
@@ -474,6 +518,10 @@ mod wallet_tests {
             unreachable!()
         }
 
+        async fn ethop_info(&self, _serial_id: u32) -> Result<EthOpInfo, ClientError> {
+            unreachable!()
+        }
+
         async fn get_tx_fee(
             &self,
             _tx_type: TxFeeTypes,
@@ -483,14 +531,34 @@ mod wallet_tests {
             unreachable!()
         }
 
+        /// Returns the fee of 100 for every transaction in the batch.
+        async fn get_txs_batch_fee_in_wei(
+            &self,
+            tx_types: Vec<TxFeeTypes>,
+            _addresses: Vec<Address>,
+            _token: impl Into<TokenLike> + Send + 'async_trait,
+        ) -> Result<BatchFee, ClientError> {
+            Ok(BatchFee {
+                total_fee: BigUint::from(100u32 * tx_types.len() as u32),
+            })
+        }
+
         async fn send_tx(
             &self,
             _tx: ZkSyncTx,
-            _eth_signature: Option<PackedEthSignature>,
+            _eth_signature: Option<TxEthSignature>,
         ) -> Result<TxHash, ClientError> {
             unreachable!()
         }
 
+        async fn send_txs_batch(
+            &self,
+            _txs_signed: Vec<(ZkSyncTx, Option<TxEthSignature>)>,
+            _eth_signatures: Vec<TxEthSignature>,
+        ) -> Result<Vec<TxHash>, ClientError> {
+            unreachable!()
+        }
+
         fn network(&self) -> Network {
             self.network
         }
@@ -533,6 +601,50 @@ mod wallet_tests {
         assert_eq!(wallet.address(), expected_address);
     }
 
+    #[tokio::test]
+    async fn test_wallet_from_keystore() {
+        // Test vector from the Web3 Secret Storage Definition, password is "testpassword".
+        const KEYSTORE: &str = r#"{
+            "crypto": {
+                "cipher": "aes-128-ctr",
+                "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+                "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                "kdf": "pbkdf2",
+                "kdfparams": {
+                    "c": 262144,
+                    "dklen": 32,
+                    "prf": "hmac-sha256",
+                    "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                },
+                "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+            },
+            "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+            "version": 3
+        }"#;
+        let private_key: H256 = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d"
+            .parse()
+            .unwrap();
+
+        let eth_signer = KeystoreSigner::from_json(KEYSTORE, "testpassword").unwrap();
+        let creds =
+            WalletCredentials::from_eth_signer(eth_signer.address(), eth_signer, Network::Mainnet)
+                .await
+                .unwrap();
+        let provider = MockProvider {
+            network: Network::Mainnet,
+            eth_private_key: private_key,
+        };
+        let wallet = Wallet::new(provider, creds).await.unwrap();
+
+        // Keys derived via keystore must be the same as the ones derived from the private key.
+        let expected_wallet = get_test_wallet(private_key.as_bytes(), Network::Mainnet).await;
+        assert_eq!(wallet.address(), expected_wallet.address());
+        assert_eq!(
+            wallet.signer.pubkey_hash(),
+            expected_wallet.signer.pubkey_hash()
+        );
+    }
+
     #[tokio::test]
     async fn test_wallet_account_info() {
         let wallet = get_test_wallet(&[10; 32], Network::Mainnet).await;
@@ -613,4 +725,858 @@ mod wallet_tests {
         let expected_address: Vec<_> = (0..20).collect();
         assert_eq!(eth_provider.contract_address().as_bytes(), expected_address);
     }
+
+    #[tokio::test]
+    async fn test_wallet_fee_in_another_token() {
+        let wallet = get_test_wallet(&[60; 32], Network::Mainnet).await;
+        let recipient = Address::repeat_byte(0x11);
+        let dai = wallet.tokens.resolve("DAI".into()).unwrap();
+        let usdc = wallet.tokens.resolve("USDC".into()).unwrap();
+
+        let txs = wallet
+            .start_transfer()
+            .token("DAI")
+            .unwrap()
+            .fee_token("USDC")
+            .unwrap()
+            .amount(10u32)
+            .to(recipient)
+            .txs()
+            .await
+            .unwrap();
+        assert_eq!(txs.len(), 2);
+        match (&txs[0].0, &txs[1].0) {
+            (ZkSyncTx::Transfer(transfer), ZkSyncTx::Transfer(fee_transfer)) => {
+                assert_eq!(transfer.token, dai.id);
+                assert_eq!(transfer.to, recipient);
+                assert_eq!(transfer.fee, BigUint::from(0u32));
+                assert_eq!(transfer.nonce, 0);
+
+                assert_eq!(fee_transfer.token, usdc.id);
+                assert_eq!(fee_transfer.to, wallet.address());
+                assert_eq!(fee_transfer.amount, BigUint::from(0u32));
+                assert_eq!(fee_transfer.fee, BigUint::from(200u32));
+                assert_eq!(fee_transfer.nonce, 1);
+            }
+            _ => panic!("Unexpected transactions: {:?}", txs),
+        }
+
+        let txs = wallet
+            .start_withdraw()
+            .token("DAI")
+            .unwrap()
+            .fee_token("USDC")
+            .unwrap()
+            .amount(10u32)
+            .to(recipient)
+            .fee(500u32)
+            .txs()
+            .await
+            .unwrap();
+        match (&txs[0].0, &txs[1].0) {
+            (ZkSyncTx::Withdraw(withdraw), ZkSyncTx::Transfer(fee_transfer)) => {
+                assert_eq!(withdraw.fee, BigUint::from(0u32));
+                assert_eq!(fee_transfer.fee, BigUint::from(500u32));
+            }
+            _ => panic!("Unexpected transactions: {:?}", txs),
+        }
+
+        // Transaction paying the fee in another token can't be built alone.
+        let result = wallet
+            .start_transfer()
+            .token("DAI")
+            .unwrap()
+            .fee_token("USDC")
+            .unwrap()
+            .amount(10u32)
+            .to(recipient)
+            .tx()
+            .await;
+        assert_eq!(result.unwrap_err(), ClientError::FeeInAnotherToken);
+
+        // Fee token matching the transfer token doesn't require a batch.
+        let txs = wallet
+            .start_transfer()
+            .token("DAI")
+            .unwrap()
+            .fee_token("DAI")
+            .unwrap()
+            .amount(10u32)
+            .fee(100u32)
+            .to(recipient)
+            .txs()
+            .await
+            .unwrap();
+        assert_eq!(txs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_wallet_batch_builder() {
+        let wallet = get_test_wallet(&[70; 32], Network::Mainnet).await;
+        let recipient = Address::repeat_byte(0x11);
+        let usdc = wallet.tokens.resolve("USDC".into()).unwrap();
+        let batch_message = |txs: &[(ZkSyncTx, Option<TxEthSignature>)]| {
+            let txs: Vec<_> = txs.iter().map(|(tx, _)| tx.clone()).collect();
+            BatchSignData::new(&txs, Vec::new()).unwrap().message
+        };
+
+        // Fee is paid by the first transaction in the fee token.
+        let (txs, eth_signatures) = wallet
+            .start_batch()
+            .add_transfer("DAI", 10u32, recipient)
+            .unwrap()
+            .add_transfer("USDC", 20u32, recipient)
+            .unwrap()
+            .add_withdraw("USDC", 30u32, recipient)
+            .unwrap()
+            .fee_token("USDC")
+            .unwrap()
+            .txs()
+            .await
+            .unwrap();
+        assert_eq!(txs.len(), 3);
+        let fees: Vec<_> = txs
+            .iter()
+            .map(|(tx, _)| match tx {
+                ZkSyncTx::Transfer(tx) => (tx.nonce, tx.fee.clone()),
+                ZkSyncTx::Withdraw(tx) => (tx.nonce, tx.fee.clone()),
+                _ => panic!("Unexpected transaction: {:?}", tx),
+            })
+            .collect();
+        assert_eq!(
+            fees,
+            vec![
+                (0, BigUint::from(0u32)),
+                (1, BigUint::from(300u32)),
+                (2, BigUint::from(0u32))
+            ]
+        );
+        assert_eq!(eth_signatures.len(), 1);
+        let signer = ecdsa_signature(&eth_signatures[0])
+            .signature_recover_signer(&batch_message(&txs))
+            .unwrap();
+        assert_eq!(signer, wallet.address());
+
+        // Without the transaction in the fee token, the fee is paid by an additional transfer.
+        let (txs, _) = wallet
+            .start_batch()
+            .add_transfer("DAI", 10u32, recipient)
+            .unwrap()
+            .fee_token("USDC")
+            .unwrap()
+            .nonce(5)
+            .txs()
+            .await
+            .unwrap();
+        match &txs[..] {
+            [(ZkSyncTx::Transfer(transfer), _), (ZkSyncTx::Transfer(fee_transfer), _)] => {
+                assert_eq!(transfer.nonce, 5);
+                assert_eq!(transfer.fee, BigUint::from(0u32));
+                assert_eq!(fee_transfer.nonce, 6);
+                assert_eq!(fee_transfer.token, usdc.id);
+                assert_eq!(fee_transfer.to, wallet.address());
+                assert_eq!(fee_transfer.fee, BigUint::from(200u32));
+            }
+            _ => panic!("Unexpected transactions: {:?}", txs),
+        }
+
+        // `ChangePubKey` goes first, pays the fee and is authorized by the batch signature.
+        let (txs, eth_signatures) = wallet
+            .start_batch()
+            .add_withdraw("DAI", 10u32, recipient)
+            .unwrap()
+            .change_pubkey(false)
+            .fee_token("USDC")
+            .unwrap()
+            .fee(1000u32)
+            .txs()
+            .await
+            .unwrap();
+        match &txs[..] {
+            [(ZkSyncTx::ChangePubKey(change_pubkey), _), (ZkSyncTx::Withdraw(withdraw), _)] => {
+                assert_eq!(change_pubkey.nonce, 0);
+                assert_eq!(change_pubkey.fee_token, usdc.id);
+                assert_eq!(change_pubkey.fee, BigUint::from(1000u32));
+                assert!(change_pubkey.is_eth_auth_data_valid());
+                match &change_pubkey.eth_auth_data {
+                    ChangePubKeyEthAuthData::ECDSA(auth_data) => {
+                        assert_eq!(
+                            &auth_data.eth_signature,
+                            ecdsa_signature(&eth_signatures[0])
+                        );
+                    }
+                    _ => panic!("ChangePubKey is expected to be authorized by signature"),
+                }
+                assert_eq!(withdraw.nonce, 1);
+                assert_eq!(withdraw.fee, BigUint::from(0u32));
+            }
+            _ => panic!("Unexpected transactions: {:?}", txs),
+        }
+        let signer = ecdsa_signature(&eth_signatures[0])
+            .signature_recover_signer(&batch_message(&txs))
+            .unwrap();
+        assert_eq!(signer, wallet.address());
+
+        let result = wallet.start_batch().fee_token("USDC").unwrap().txs().await;
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::MissingRequiredField("operations".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wallet_create2_change_pubkey() {
+        let create2_data = ChangePubKeyCREATE2Data {
+            creator_address: Address::repeat_byte(0x12),
+            salt_arg: H256::repeat_byte(0x34),
+            code_hash: H256::repeat_byte(0x56),
+        };
+        let private_key = private_key_from_seed(&[90; 32]).unwrap();
+        let creds = WalletCredentials::<PrivateKeySigner>::from_create2_data(
+            &create2_data,
+            private_key_from_seed(&[90; 32]).unwrap(),
+        );
+        let expected_address = create2_data.get_address(&PubKeyHash::from_privkey(&private_key));
+        let provider = MockProvider {
+            network: Network::Mainnet,
+            eth_private_key: H256::from([90; 32]),
+        };
+        let wallet = Wallet::new(provider, creds).await.unwrap();
+        assert_eq!(wallet.address(), expected_address);
+
+        let tx = wallet
+            .start_change_pubkey()
+            .create2_data(create2_data.clone())
+            .fee_token("DAI")
+            .unwrap()
+            .fee(100u32)
+            .nonce(0)
+            .tx()
+            .await
+            .unwrap();
+        match tx {
+            ZkSyncTx::ChangePubKey(change_pubkey) => {
+                assert_eq!(change_pubkey.account, expected_address);
+                assert_eq!(change_pubkey.new_pk_hash, *wallet.signer.pubkey_hash());
+                assert!(matches!(
+                    change_pubkey.eth_auth_data,
+                    ChangePubKeyEthAuthData::CREATE2(_)
+                ));
+                assert!(change_pubkey.is_eth_auth_data_valid());
+            }
+            _ => panic!("Unexpected transaction: {:?}", tx),
+        }
+
+        // CREATE2 data must match the account address.
+        let other_create2_data = ChangePubKeyCREATE2Data {
+            salt_arg: H256::repeat_byte(0x78),
+            ..create2_data
+        };
+        let result = wallet
+            .start_change_pubkey()
+            .create2_data(other_create2_data)
+            .fee_token("DAI")
+            .unwrap()
+            .fee(100u32)
+            .nonce(0)
+            .tx()
+            .await;
+        assert!(matches!(result, Err(ClientError::SigningError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_forced_exit_builder() {
+        let wallet = get_test_wallet(&[80; 32], Network::Mainnet).await;
+        let target = Address::repeat_byte(0x22);
+        let dai = wallet.tokens.resolve("DAI".into()).unwrap();
+
+        let (tx, eth_signature) = wallet
+            .start_forced_exit()
+            .target(target)
+            .token("DAI")
+            .unwrap()
+            .fee(100u32)
+            .nonce(3)
+            .tx()
+            .await
+            .unwrap();
+        assert!(eth_signature.is_none());
+        match tx {
+            ZkSyncTx::ForcedExit(forced_exit) => {
+                assert_eq!(forced_exit.initiator_account_id, 42);
+                assert_eq!(forced_exit.target, target);
+                assert_eq!(forced_exit.token, dai.id);
+                assert_eq!(forced_exit.fee, BigUint::from(100u32));
+                assert_eq!(forced_exit.nonce, 3);
+                assert_eq!(
+                    forced_exit.verify_signature(),
+                    Some(*wallet.signer.pubkey_hash())
+                );
+            }
+            _ => panic!("Unexpected transaction: {:?}", tx),
+        }
+
+        let result = wallet.start_forced_exit().token("DAI").unwrap().tx().await;
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::MissingRequiredField("target".into())
+        );
+    }
+}
+
+mod mock_provider_tests {
+    use super::*;
+    use futures::future::join_all;
+    use jsonrpc_core::ErrorCode;
+    use num::BigUint;
+    use zksync::{
+        error::ClientError,
+        offline::{OfflineTxType, SignedTx, UnsignedTx},
+        provider::Provider,
+        types::BlockStatus,
+        MockProvider, Network, Wallet, WalletCredentials, WalletPool,
+    };
+    use zksync_eth_signer::{EIP1271Signer, EthereumSigner, PrivateKeySigner};
+    use zksync_types::{
+        tx::{ChangePubKeyECDSAData, ChangePubKeyEthAuthData, PackedEthSignature},
+        Address, ZkSyncTx, H256,
+    };
+
+    async fn get_test_wallet(
+        private_key_raw: &[u8],
+        provider: MockProvider,
+    ) -> Wallet<PrivateKeySigner, MockProvider> {
+        let private_key = H256::from_slice(private_key_raw);
+        let address = PackedEthSignature::address_from_private_key(&private_key).unwrap();
+
+        let eth_signer = PrivateKeySigner::new(private_key);
+        let creds = WalletCredentials::from_eth_signer(address, eth_signer, Network::Mainnet)
+            .await
+            .unwrap();
+        Wallet::new(provider, creds).await.unwrap()
+    }
+
+    /// Creates a wallet for the private key filled with `seed`, deposits ETH to it
+    /// and sets its signing key.
+    async fn activated_wallet(
+        provider: &MockProvider,
+        seed: u8,
+    ) -> Wallet<PrivateKeySigner, MockProvider> {
+        let private_key = H256::from([seed; 32]);
+        let address = PackedEthSignature::address_from_private_key(&private_key).unwrap();
+        provider.deposit(address, "ETH", 1_000_000u32).unwrap();
+        let wallet = get_test_wallet(private_key.as_bytes(), provider.clone()).await;
+        provider.authorize_pubkey_hash(address, 0, *wallet.signer.pubkey_hash());
+        wallet
+            .start_batch()
+            .change_pubkey(true)
+            .fee_token("ETH")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        wallet
+    }
+
+    fn assert_rpc_error(err: ClientError, expected_code: i64) {
+        match err {
+            ClientError::RpcError(failure) => {
+                assert_eq!(failure.error.code, ErrorCode::ServerError(expected_code))
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_provider_executes_txs() {
+        let provider = MockProvider::new(Network::Mainnet);
+        let fee = BigUint::from(MockProvider::DEFAULT_TX_FEE);
+        provider.add_token("DAI", 18);
+
+        let address = PackedEthSignature::address_from_private_key(&H256::from([7; 32])).unwrap();
+        let serial_id = provider.deposit(address, "DAI", 1_000_000u32).unwrap();
+        let wallet = get_test_wallet(&[7; 32], provider.clone()).await;
+        assert_eq!(wallet.account_id(), Some(0));
+
+        let recipient = Address::repeat_byte(0x33);
+        provider.authorize_pubkey_hash(address, 0, *wallet.signer.pubkey_hash());
+        let handles = wallet
+            .start_batch()
+            .change_pubkey(true)
+            .add_transfer("DAI", 1000u32, recipient)
+            .unwrap()
+            .fee_token("DAI")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(handles.len(), 2);
+
+        let expected_balance = BigUint::from(1_000_000u32 - 1000) - &fee * 2u32;
+        assert!(wallet.is_signing_key_set().await.unwrap());
+        assert_eq!(
+            wallet
+                .get_balance(BlockStatus::Committed, "DAI")
+                .await
+                .unwrap(),
+            expected_balance
+        );
+        assert_eq!(
+            wallet
+                .get_balance(BlockStatus::Verified, "DAI")
+                .await
+                .unwrap(),
+            BigUint::from(0u32)
+        );
+
+        let tx_info = provider.tx_info(handles[1].hash()).await.unwrap();
+        assert_eq!(tx_info.success, Some(true));
+        assert!(!tx_info.block.unwrap().committed);
+
+        assert_eq!(provider.commit_block(), 1);
+        assert_eq!(provider.verify_block(), Some(1));
+        assert_eq!(provider.verify_block(), None);
+        let tx_info = provider.tx_info(handles[1].hash()).await.unwrap();
+        assert!(tx_info.block.unwrap().verified);
+        assert!(provider
+            .ethop_info(serial_id as u32)
+            .await
+            .unwrap()
+            .is_verified());
+        assert_eq!(
+            wallet
+                .get_balance(BlockStatus::Verified, "DAI")
+                .await
+                .unwrap(),
+            expected_balance
+        );
+
+        // Fee is lower than the required one.
+        let err = wallet
+            .start_transfer()
+            .token("DAI")
+            .unwrap()
+            .amount(1000u32)
+            .fee(fee.clone() - 1u32)
+            .to(recipient)
+            .send()
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 104);
+
+        // Transfer is not signed by the Ethereum key.
+        let (tx, _) = wallet
+            .start_transfer()
+            .token("DAI")
+            .unwrap()
+            .amount(1000u32)
+            .to(recipient)
+            .tx()
+            .await
+            .unwrap();
+        let err = provider.send_tx(tx, None).await.unwrap_err();
+        assert_rpc_error(err, 200);
+    }
+
+    #[tokio::test]
+    async fn test_mock_provider_eip1271_wallet() {
+        let provider = MockProvider::new(Network::Mainnet);
+        let owner_private_key = H256::from([9; 32]);
+        let owner = PackedEthSignature::address_from_private_key(&owner_private_key).unwrap();
+        let wallet_address = Address::repeat_byte(0x44);
+        provider.add_eip1271_wallet(wallet_address, owner);
+        provider
+            .deposit(wallet_address, "ETH", 1_000_000u32)
+            .unwrap();
+
+        let eth_signer =
+            EIP1271Signer::new(wallet_address, PrivateKeySigner::new(owner_private_key));
+        let creds = WalletCredentials::from_eth_signer_and_pk(
+            wallet_address,
+            eth_signer,
+            private_key_from_seed(&[10; 32]).unwrap(),
+        );
+        let wallet = Wallet::new(provider.clone(), creds).await.unwrap();
+
+        // Batch and transactions are signed with EIP1271 signatures.
+        let recipient = Address::repeat_byte(0x55);
+        provider.authorize_pubkey_hash(wallet_address, 0, *wallet.signer.pubkey_hash());
+        wallet
+            .start_batch()
+            .change_pubkey(true)
+            .add_transfer("ETH", 1000u32, recipient)
+            .unwrap()
+            .fee_token("ETH")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        let handle = wallet
+            .start_transfer()
+            .token("ETH")
+            .unwrap()
+            .amount(1000u32)
+            .to(recipient)
+            .send()
+            .await
+            .unwrap();
+        let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+        assert_eq!(tx_info.success, Some(true));
+        assert_eq!(
+            wallet
+                .get_balance(BlockStatus::Committed, "ETH")
+                .await
+                .unwrap(),
+            BigUint::from(1_000_000u32 - 2000) - BigUint::from(MockProvider::DEFAULT_TX_FEE) * 3u32
+        );
+
+        // EIP1271 signatures of the unknown wallets are rejected.
+        let eth_signer = EIP1271Signer::new(recipient, PrivateKeySigner::new(owner_private_key));
+        let other_wallet = Wallet::new(
+            provider.clone(),
+            WalletCredentials::from_eth_signer_and_pk(
+                recipient,
+                eth_signer,
+                private_key_from_seed(&[11; 32]).unwrap(),
+            ),
+        )
+        .await
+        .unwrap();
+        provider.authorize_pubkey_hash(recipient, 0, *other_wallet.signer.pubkey_hash());
+        let err = other_wallet
+            .start_batch()
+            .change_pubkey(true)
+            .fee_token("ETH")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 202);
+    }
+
+    #[tokio::test]
+    async fn test_mock_provider_rejects_incorrect_txs() {
+        let provider = MockProvider::new(Network::Mainnet);
+        let address = PackedEthSignature::address_from_private_key(&H256::from([16; 32])).unwrap();
+        provider.deposit(address, "ETH", 1_000_000u32).unwrap();
+        let wallet = get_test_wallet(&[16; 32], provider.clone()).await;
+
+        // `ChangePubKey` message is signed by the key of another account.
+        let mut change_pubkey = match wallet
+            .start_change_pubkey()
+            .fee_token("ETH")
+            .unwrap()
+            .tx()
+            .await
+            .unwrap()
+        {
+            ZkSyncTx::ChangePubKey(tx) => tx,
+            tx => panic!("Unexpected transaction: {:?}", tx),
+        };
+        let message = change_pubkey.get_eth_signed_data_with_batch_hash(H256::zero());
+        let eth_signature = PackedEthSignature::sign(&H256::from([17; 32]), &message).unwrap();
+        change_pubkey.eth_auth_data = ChangePubKeyEthAuthData::ECDSA(ChangePubKeyECDSAData {
+            eth_signature,
+            batch_hash: H256::zero(),
+        });
+        let err = provider
+            .send_tx(ZkSyncTx::ChangePubKey(change_pubkey), None)
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 103);
+        assert!(!wallet.is_signing_key_set().await.unwrap());
+
+        let handle = wallet
+            .start_change_pubkey()
+            .fee_token("ETH")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+        assert_eq!(tx_info.success, Some(true));
+        assert!(wallet.is_signing_key_set().await.unwrap());
+
+        // Transactions with the future nonce are rejected rather than executed.
+        let recipient = Address::repeat_byte(0x88);
+        let err = wallet
+            .start_transfer()
+            .token("ETH")
+            .unwrap()
+            .amount(1000u32)
+            .to(recipient)
+            .nonce(2)
+            .send()
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 101);
+        let err = wallet
+            .start_batch()
+            .fee_token("ETH")
+            .unwrap()
+            .add_transfer("ETH", 1000u32, recipient)
+            .unwrap()
+            .nonce(2)
+            .send()
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 101);
+        let account_info = wallet.account_info().await.unwrap();
+        assert_eq!(account_info.committed.nonce, 1);
+    }
+
+    #[tokio::test]
+    async fn test_offline_signing() {
+        let provider = MockProvider::new(Network::Mainnet);
+        let private_key = H256::from([12; 32]);
+        let wallet = activated_wallet(&provider, 12).await;
+        let address = wallet.address();
+
+        // Template is prepared online and transferred to the offline machine as JSON.
+        let recipient = Address::repeat_byte(0x66);
+        let tx = UnsignedTx::prepare(
+            &provider,
+            OfflineTxType::Withdraw,
+            address,
+            recipient,
+            "ETH",
+            1000u32.into(),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(tx.nonce, 1);
+        assert_eq!(tx.fee, BigUint::from(MockProvider::DEFAULT_TX_FEE));
+        assert_eq!(tx.pub_key_hash, *wallet.signer.pubkey_hash());
+        let tx: UnsignedTx = serde_json::from_str(&serde_json::to_string(&tx).unwrap()).unwrap();
+
+        // Only the account keys can sign the transaction.
+        let other_signer = PrivateKeySigner::new(H256::from([13; 32]));
+        let other_address =
+            PackedEthSignature::address_from_private_key(&H256::from([13; 32])).unwrap();
+        let other_creds =
+            WalletCredentials::from_eth_signer(other_address, other_signer, Network::Mainnet)
+                .await
+                .unwrap();
+        assert_eq!(
+            tx.sign(other_creds).await.unwrap_err(),
+            ClientError::IncorrectCredentials
+        );
+
+        let creds = WalletCredentials::from_eth_signer(
+            address,
+            PrivateKeySigner::new(private_key),
+            Network::Mainnet,
+        )
+        .await
+        .unwrap();
+        let signed_tx = tx.sign(creds).await.unwrap();
+        let signed_tx: SignedTx =
+            serde_json::from_str(&serde_json::to_string(&signed_tx).unwrap()).unwrap();
+        assert_eq!(
+            signed_tx.ethereum_sign_message(),
+            tx.ethereum_sign_message()
+        );
+
+        // Signed transaction can't be submitted to another network.
+        let err = signed_tx
+            .clone()
+            .submit(&MockProvider::new(Network::Rinkeby))
+            .await
+            .unwrap_err();
+        assert_eq!(err, ClientError::NetworkNotSupported("rinkeby".into()));
+
+        let tx_hash = signed_tx.submit(&provider).await.unwrap();
+        let tx_info = provider.tx_info(tx_hash).await.unwrap();
+        assert_eq!(tx_info.success, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_pool() {
+        let provider = MockProvider::new(Network::Mainnet);
+        let wallets = vec![
+            activated_wallet(&provider, 14).await,
+            activated_wallet(&provider, 15).await,
+        ];
+        let pool = WalletPool::new(wallets).max_batch_size(2);
+        let recipient = Address::repeat_byte(0x77);
+
+        // Concurrent transfers get sequential nonces and are distributed between the accounts.
+        let handles = join_all((0..4).map(|_| pool.transfer("ETH", 1000u32, recipient))).await;
+        for handle in handles {
+            let tx_info = provider.tx_info(handle.unwrap().hash()).await.unwrap();
+            assert_eq!(tx_info.success, Some(true));
+        }
+        for wallet in pool.wallets() {
+            let account_info = wallet.account_info().await.unwrap();
+            assert_eq!(account_info.committed.nonce, 3);
+        }
+
+        // Rejected transfer doesn't consume the nonce, so the following transfers are accepted.
+        let err = pool
+            .transfer("ETH", 1000u32, Address::zero())
+            .await
+            .unwrap_err();
+        assert_rpc_error(err, 103);
+        for _ in 0..2 {
+            let handle = pool.transfer("ETH", 1000u32, recipient).await.unwrap();
+            let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+            assert_eq!(tx_info.success, Some(true));
+        }
+        for wallet in pool.wallets() {
+            let account_info = wallet.account_info().await.unwrap();
+            assert_eq!(account_info.committed.nonce, 4);
+        }
+
+        // Transaction sent outside of the pool makes the local nonce too low,
+        // so it has to be resynced.
+        let wallet = pool.wallets().next().unwrap();
+        wallet
+            .start_transfer()
+            .token("ETH")
+            .unwrap()
+            .amount(1000u32)
+            .to(recipient)
+            .send()
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            let handle = pool.transfer("ETH", 1000u32, recipient).await.unwrap();
+            let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+            assert_eq!(tx_info.success, Some(true));
+        }
+
+        // Queued transfers are sent in batches once the queue is full, the rest is sent by `flush`.
+        let mut batches = Vec::new();
+        for i in 0..5 {
+            let batch = pool
+                .queue_transfer("ETH", 1000u32, recipient)
+                .await
+                .unwrap();
+            assert_eq!(batch.is_some(), i % 2 == 1);
+            batches.extend(batch);
+        }
+        assert_eq!(
+            pool.queue_transfer("UNKNOWN", 1000u32, recipient)
+                .await
+                .unwrap_err(),
+            ClientError::UnknownToken
+        );
+        assert_eq!(pool.queued_transfers(), 1);
+        for batch in pool.flush().await {
+            batches.push(batch.unwrap());
+        }
+        assert_eq!(pool.queued_transfers(), 0);
+        assert_eq!(batches.len(), 3);
+        for batch in batches {
+            for handle in batch {
+                let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+                assert_eq!(tx_info.success, Some(true));
+            }
+        }
+
+        let recipient_info = provider.account_info(recipient).await.unwrap();
+        assert_eq!(
+            recipient_info.committed.balances["ETH"].0,
+            BigUint::from(14u32 * 1000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signing_key_rotation() {
+        const MNEMONIC: &str = "test test test test test test test test test test test junk";
+        let provider = MockProvider::new(Network::Mainnet);
+        let eth_signer = PrivateKeySigner::from_mnemonic(MNEMONIC, "", 0).unwrap();
+        let address = eth_signer.get_address().await.unwrap();
+        provider.deposit(address, "ETH", 1_000_000u32).unwrap();
+        let credentials = |key_index| {
+            WalletCredentials::from_eth_signer_with_key_index(
+                address,
+                eth_signer.clone(),
+                Network::Mainnet,
+                key_index,
+            )
+        };
+
+        // Key with index 0 is the default one.
+        let mut wallet = Wallet::new(provider.clone(), credentials(0).await.unwrap())
+            .await
+            .unwrap();
+        let default_credentials =
+            WalletCredentials::from_eth_signer(address, eth_signer.clone(), Network::Mainnet)
+                .await
+                .unwrap();
+        let old_wallet = Wallet::new(provider.clone(), default_credentials)
+            .await
+            .unwrap();
+        assert_eq!(wallet.signer.pubkey_hash(), old_wallet.signer.pubkey_hash());
+
+        wallet
+            .rotate_signing_key(credentials(0).await.unwrap(), "ETH")
+            .await
+            .unwrap();
+        assert!(old_wallet.is_signing_key_set().await.unwrap());
+
+        // Credentials of another account can't be used.
+        let other_signer = PrivateKeySigner::from_mnemonic(MNEMONIC, "", 1).unwrap();
+        let other_address = other_signer.get_address().await.unwrap();
+        let other_credentials =
+            WalletCredentials::from_eth_signer(other_address, other_signer, Network::Mainnet)
+                .await
+                .unwrap();
+        let err = wallet
+            .rotate_signing_key(other_credentials, "ETH")
+            .await
+            .unwrap_err();
+        assert_eq!(err, ClientError::IncorrectCredentials);
+
+        let tx_info = wallet
+            .rotate_signing_key(credentials(1).await.unwrap(), "ETH")
+            .await
+            .unwrap();
+        assert_eq!(tx_info.success, Some(true));
+        assert_ne!(wallet.signer.pubkey_hash(), old_wallet.signer.pubkey_hash());
+        assert!(wallet.is_signing_key_set().await.unwrap());
+        assert!(!old_wallet.is_signing_key_set().await.unwrap());
+
+        // Only the transactions signed with the new key are executed.
+        let recipient = Address::repeat_byte(0x88);
+        for (wallet, expected_success) in &[(&wallet, true), (&old_wallet, false)] {
+            let handle = wallet
+                .start_transfer()
+                .token("ETH")
+                .unwrap()
+                .amount(1000u32)
+                .to(recipient)
+                .send()
+                .await
+                .unwrap();
+            let tx_info = provider.tx_info(handle.hash()).await.unwrap();
+            assert_eq!(tx_info.success, Some(*expected_success));
+        }
+
+        // Wallet keeps the current key if the key change fails because of the insufficient balance.
+        let balance = wallet
+            .get_balance(BlockStatus::Committed, "ETH")
+            .await
+            .unwrap();
+        wallet
+            .start_transfer()
+            .token("ETH")
+            .unwrap()
+            .amount(balance - MockProvider::DEFAULT_TX_FEE)
+            .to(recipient)
+            .send()
+            .await
+            .unwrap();
+        let old_pubkey_hash = *wallet.signer.pubkey_hash();
+        let err = wallet
+            .rotate_signing_key(credentials(2).await.unwrap(), "ETH")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClientError::TransactionFailed(_)));
+        assert_eq!(*wallet.signer.pubkey_hash(), old_pubkey_hash);
+        assert!(wallet.is_signing_key_set().await.unwrap());
+    }
 }
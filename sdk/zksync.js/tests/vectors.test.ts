@@ -156,7 +156,7 @@ describe(txVectors['description'], function () {
                 const { signature } = await signer.signSyncChangePubKey(changePubKeyData);
 
                 const { signature: ethSignature } = await ethMessageSigner.ethSignChangePubKey(ethSignData);
-                const ethSignMessage = ethMessageSigner.getChangePubKeyEthSignMessage(ethSignData);
+                const ethSignMessage = utils.hexlify(ethMessageSigner.getChangePubKeyEthSignMessage(ethSignData));
 
                 expect(utils.hexlify(signBytes)).to.eql(expected.signBytes, 'Sign bytes do not match');
                 expect(signature).to.eql(expected.signature, 'Signature does not match');